@@ -0,0 +1,82 @@
+use crate::status::StatusApplication;
+use serde::Deserialize;
+
+/// Action choisie par un ennemi à son tour
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum EnemyMove {
+    /// Attaque normale
+    Attack,
+    /// Attaque dont les dégâts sont multipliés (`damage_percent` = 250 pour ×2,5)
+    HeavyAttack { damage_percent: i32 },
+    /// Prend son élan : l'ennemi n'attaque pas ce tour (annonce souvent une HeavyAttack)
+    Charge,
+    /// L'ennemi s'applique un effet à lui-même (hurlement du Loup), la chance est ignorée
+    Buff(StatusApplication),
+    /// L'ennemi quitte le combat et reste sur la map
+    Flee,
+}
+
+/// Comportement d'un ennemi en combat (champ `behaviour` de enemies.ron)
+#[derive(Debug, Clone, Default, Deserialize)]
+pub enum Behaviour {
+    /// Attaque à chaque tour
+    #[default]
+    Aggressive,
+    /// Enchaîne les actions dans l'ordre, puis recommence
+    Pattern(Vec<EnemyMove>),
+    /// Attaque, mais fuit le combat une fois ses PV sous le seuil
+    Cowardly { flee_below_percent: i32 },
+}
+
+impl Behaviour {
+    /// Action du tour, selon le nombre de tours déjà joués dans la phase et les PV restants
+    pub fn next_move(&self, turns_played: u32, hp: i32, max_hp: i32) -> EnemyMove {
+        match self {
+            Behaviour::Aggressive => EnemyMove::Attack,
+            Behaviour::Pattern(moves) => moves[turns_played as usize % moves.len()],
+            Behaviour::Cowardly { flee_below_percent } => {
+                if hp * 100 <= max_hp * flee_below_percent {
+                    EnemyMove::Flee
+                } else {
+                    EnemyMove::Attack
+                }
+            }
+        }
+    }
+}
+
+/// Phase d'un boss, active quand ses PV passent sous `below_percent` % (champ `phases`)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Phase {
+    pub below_percent: i32,
+    /// Message affiché au changement de phase
+    pub message: String,
+    pub behaviour: Behaviour,
+}
+
+/// Intelligence d'un ennemi vue par les règles : comportement de base et phases éventuelles
+#[derive(Debug, Clone, Default)]
+pub struct EnemyAi {
+    pub behaviour: Behaviour,
+    /// Triées par seuil décroissant (vérifié au chargement)
+    pub phases: Vec<Phase>,
+}
+
+impl EnemyAi {
+    /// Phase correspondant aux PV restants : 0 = comportement de base, n = `phases[n - 1]`
+    pub fn phase_for(&self, hp: i32, max_hp: i32) -> usize {
+        self.phases
+            .iter()
+            .take_while(|phase| hp * 100 <= max_hp * phase.below_percent)
+            .count()
+    }
+
+    /// Comportement de la phase `phase`
+    pub fn behaviour_in(&self, phase: usize) -> &Behaviour {
+        match phase {
+            0 => &self.behaviour,
+            _ => &self.phases[phase - 1].behaviour,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::combat::Combat;
+use std::cmp::Reverse;
+
+/// Initiative gagnée à chaque pas en plus de la vitesse
+/// Atténue les écarts : 20 de vitesse contre 5 donne 30 contre 15, soit deux tours pour un
+const INITIATIVE_BASE: i32 = 10;
+
+/// Seuil d'initiative à atteindre pour jouer, retiré de la jauge à chaque tour joué
+const TURN_THRESHOLD: i32 = 100;
+
+/// Combattant dont c'est le tour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Actor {
+    Player,
+    /// Index de l'ennemi dans `Combat::enemies`
+    Enemy(usize),
+}
+
+/// Avance les jauges jusqu'à ce qu'un combattant atteigne le seuil et lui retire le coût d'un tour
+/// `meters[0]` est le joueur, puis un par ennemi ; `speeds` vaut None pour un ennemi vaincu
+/// Si plusieurs l'atteignent, la jauge la plus remplie joue ; à égalité, le premier (le joueur)
+fn advance(meters: &mut [i32], speeds: &[Option<i32>]) -> usize {
+    loop {
+        let ready = (0..meters.len())
+            .filter(|&index| speeds[index].is_some() && meters[index] >= TURN_THRESHOLD)
+            .max_by_key(|&index| (meters[index], Reverse(index)));
+        if let Some(index) = ready {
+            meters[index] -= TURN_THRESHOLD;
+            return index;
+        }
+
+        for (meter, speed) in meters.iter_mut().zip(speeds) {
+            if let Some(speed) = speed {
+                *meter += (speed + INITIATIVE_BASE).max(1);
+            }
+        }
+    }
+}
+
+/// Jauges et vitesses de tous les combattants, dans l'ordre attendu par `advance`
+fn meters_and_speeds(combat: &Combat) -> (Vec<i32>, Vec<Option<i32>>) {
+    let meters = std::iter::once(combat.player.initiative)
+        .chain(combat.enemies.iter().map(|enemy| enemy.initiative))
+        .collect();
+    let speeds = std::iter::once(Some(combat.player.stats.speed))
+        .chain(
+            combat
+                .enemies
+                .iter()
+                .map(|enemy| enemy.is_fighting().then_some(enemy.stats.speed)),
+        )
+        .collect();
+    (meters, speeds)
+}
+
+fn actor_at(index: usize) -> Actor {
+    match index {
+        0 => Actor::Player,
+        index => Actor::Enemy(index - 1),
+    }
+}
+
+/// Détermine le prochain combattant à jouer selon les vitesses
+/// Un combattant nettement plus rapide peut jouer plusieurs tours d'affilée
+pub(crate) fn next_actor(combat: &mut Combat) -> Actor {
+    let (mut meters, speeds) = meters_and_speeds(combat);
+    let index = advance(&mut meters, &speeds);
+
+    combat.player.initiative = meters[0];
+    for (enemy, meter) in combat.enemies.iter_mut().zip(&meters[1..]) {
+        enemy.initiative = *meter;
+    }
+    actor_at(index)
+}
+
+/// Prévisualise les `count` prochains tours sans modifier l'état du combat
+pub(crate) fn turn_order(combat: &Combat, count: usize) -> Vec<Actor> {
+    let (mut meters, speeds) = meters_and_speeds(combat);
+    (0..count)
+        .map(|_| actor_at(advance(&mut meters, &speeds)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn faster_fighter_plays_more_often() {
+        // 20 de vitesse contre 5 : 30 contre 15 d'initiative par pas
+        let mut meters = vec![0, 0];
+        let speeds = [Some(20), Some(5)];
+        let order: Vec<usize> = (0..6).map(|_| advance(&mut meters, &speeds)).collect();
+        assert_eq!(order.iter().filter(|&&index| index == 0).count(), 4);
+        assert_eq!(order.iter().filter(|&&index| index == 1).count(), 2);
+    }
+
+    #[test]
+    fn player_wins_ties() {
+        let mut meters = vec![0, 0];
+        assert_eq!(advance(&mut meters, &[Some(10), Some(10)]), 0);
+        assert_eq!(advance(&mut meters, &[Some(10), Some(10)]), 1);
+    }
+
+    #[test]
+    fn defeated_enemies_never_play() {
+        let mut meters = vec![0, 500, 0];
+        let speeds = [Some(0), None, Some(0)];
+        assert!((0..10).all(|_| advance(&mut meters, &speeds) != 1));
+    }
+}
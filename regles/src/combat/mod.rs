@@ -0,0 +1,374 @@
+//! Moteur de combat tour par tour : un joueur contre un ou plusieurs ennemis
+//!
+//! `Combat` contient tout l'état du combat et ses propres tirages aléatoires (graine fixée
+//! à la création). Chaque tour est résolu par `Combat::apply`, qui retourne les événements
+//! du tour dans l'ordre où ils se sont produits
+
+mod initiative;
+mod rolls;
+mod turn;
+
+#[cfg(test)]
+mod tests;
+
+pub use initiative::Actor;
+pub use rolls::{
+    AttackResult, DEFEND_DAMAGE_PERCENT, DamageResult, calculate_damage, check_dodge, check_flee,
+    flee_chance, resolve_attack,
+};
+
+use crate::behaviour::EnemyAi;
+use crate::consumable::ConsumableEffect;
+use crate::skill::SkillDef;
+use crate::stats::Stats;
+use crate::status::{StatusApplication, StatusEffects, StatusKind};
+use initiative::{next_actor, turn_order};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::fmt;
+
+/// Protection obtenue par une action défensive du joueur
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Guard {
+    /// Dégâts reçus réduits à ce pourcentage (Défendre, Mur de boucliers)
+    Reduce(i32),
+    /// Attaques ennemies esquivées à coup sûr (Pas de l'ombre)
+    Evade,
+}
+
+/// Joueur engagé dans le combat : stats et compétence de sa classe, puis son état de combat
+#[derive(Debug, Clone)]
+pub struct PlayerFighter {
+    /// Stats du joueur équipement compris, `stats.hp` est le maximum de PV
+    pub stats: Stats,
+    /// Effets que les attaques du joueur peuvent infliger
+    pub on_hit: Vec<StatusApplication>,
+    pub skill: SkillDef,
+    /// Niveau du joueur, pour le déblocage de la compétence
+    pub level: u32,
+    pub hp: i32,
+    pub mana: i32,
+    pub max_mana: i32,
+    /// Mana regagné à la fin de chaque tour du joueur
+    pub mana_regen: i32,
+    /// Tours restants avant de pouvoir relancer la compétence (0 = prête)
+    pub skill_cooldown: u32,
+    pub effects: StatusEffects,
+    /// Protection, active jusqu'au prochain tour du joueur
+    pub guard: Option<Guard>,
+    /// Jauge d'initiative : le combattant qui atteint le seuil en premier joue
+    pub initiative: i32,
+}
+
+impl PlayerFighter {
+    /// Joueur sans mana, avec `hp` PV restants (les PV perdus avant le combat ne sont pas rendus)
+    pub fn new(
+        stats: Stats,
+        hp: i32,
+        level: u32,
+        skill: SkillDef,
+        on_hit: Vec<StatusApplication>,
+    ) -> Self {
+        PlayerFighter {
+            stats,
+            on_hit,
+            skill,
+            level,
+            hp,
+            mana: 0,
+            max_mana: 0,
+            mana_regen: 0,
+            skill_cooldown: 0,
+            effects: StatusEffects::default(),
+            guard: None,
+            initiative: 0,
+        }
+    }
+
+    /// Réserve de mana, pleine au début du combat, et régénération par tour
+    pub fn with_mana(mut self, max_mana: i32, mana_regen: i32) -> Self {
+        self.mana = max_mana;
+        self.max_mana = max_mana;
+        self.mana_regen = mana_regen;
+        self
+    }
+
+    /// Indique si la compétence peut être lancée : débloquée, recharge terminée et mana suffisant
+    pub fn is_skill_ready(&self) -> bool {
+        self.level >= self.skill.unlock_level
+            && self.skill_cooldown == 0
+            && self.mana >= self.skill.mana_cost
+    }
+}
+
+/// Ennemi engagé dans le combat : stats, effets `on_hit` et comportement,
+/// puis son état de combat (PV, effets, initiative, phase et fuite)
+#[derive(Debug, Clone)]
+pub struct EnemyFighter {
+    pub stats: Stats,
+    pub on_hit: Vec<StatusApplication>,
+    pub ai: EnemyAi,
+    pub hp: i32,
+    pub effects: StatusEffects,
+    /// Jauge d'initiative (voir `PlayerFighter::initiative`)
+    pub initiative: i32,
+    /// Phase de boss en cours (0 = comportement de base, voir `EnemyAi::phase_for`)
+    pub phase: usize,
+    /// Tours joués dans la phase en cours, pour suivre le motif d'actions
+    pub turns_played: u32,
+    /// L'ennemi a quitté le combat (comportement Cowardly)
+    pub fled: bool,
+}
+
+impl EnemyFighter {
+    pub fn new(stats: Stats, on_hit: Vec<StatusApplication>, ai: EnemyAi) -> Self {
+        EnemyFighter {
+            hp: stats.hp,
+            stats,
+            on_hit,
+            ai,
+            effects: StatusEffects::default(),
+            initiative: 0,
+            phase: 0,
+            turns_played: 0,
+            fled: false,
+        }
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.hp > 0
+    }
+
+    /// Encore en jeu : ni vaincu ni enfui
+    pub fn is_fighting(&self) -> bool {
+        self.is_alive() && !self.fled
+    }
+}
+
+/// Action choisie par le joueur pour son tour
+#[derive(Debug, Clone, Copy)]
+pub enum PlayerAction {
+    /// Attaque l'ennemi ciblé (`Combat::target`)
+    Attack,
+    /// Réduit les dégâts reçus jusqu'au prochain tour du joueur
+    Defend,
+    /// Lance la compétence de classe, refusée si elle n'est pas prête
+    Skill,
+    /// Tente de fuir ; en cas d'échec le tour est perdu
+    Flee,
+    /// Utilise un consommable (l'appelant le retire de l'inventaire)
+    UseItem(ConsumableEffect),
+}
+
+/// Tour à résoudre par `Combat::apply`, qui doit être celui du combattant actif
+#[derive(Debug, Clone, Copy)]
+pub enum TurnAction {
+    Player(PlayerAction),
+    /// Tour de l'ennemi actif, dont l'action est choisie par son comportement
+    Enemy,
+}
+
+/// Ce qu'a produit l'action du joueur
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerOutcome {
+    Attack(AttackResult),
+    Defend,
+    /// Compétence lancée, avec le résultat de son attaque si elle en comporte une
+    Skill(Option<AttackResult>),
+    Fled,
+    FleeFailed,
+    /// Consommable de soin : PV réellement rendus
+    Healed(i32),
+    /// Consommable qui applique un effet au joueur
+    Buffed(StatusKind),
+    /// Le joueur était étourdi et n'a pas pu agir
+    Stunned,
+}
+
+/// Ce qu'a fait l'ennemi à son tour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnemyOutcome {
+    Attack(AttackResult),
+    /// Attaque puissante (EnemyMove::HeavyAttack), dégâts déjà multipliés
+    HeavyAttack(AttackResult),
+    /// Attaque évitée grâce à la protection Guard::Evade du joueur
+    Evaded,
+    /// L'ennemi prend son élan et n'attaque pas
+    Charge,
+    /// L'ennemi s'est appliqué cet effet
+    Buff(StatusKind),
+    /// L'ennemi a quitté le combat
+    Fled,
+    Stunned,
+}
+
+/// Événement d'un tour, dans l'ordre où il s'est produit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombatEvent {
+    /// Dégâts d'un effet au début du tour du combattant (poison, saignement, brûlure)
+    StatusDamage {
+        actor: Actor,
+        kind: StatusKind,
+        damage: i32,
+    },
+    /// Le boss passe à une nouvelle phase : n = `ai.phases[n - 1]`
+    PhaseChanged { enemy: usize, phase: usize },
+    /// Action du joueur, les attaques visant l'ennemi `target`
+    /// (absente s'il a succombé à ses effets en début de tour)
+    PlayerActed {
+        target: usize,
+        outcome: PlayerOutcome,
+    },
+    /// Action de l'ennemi (absente s'il a succombé à ses effets en début de tour)
+    EnemyActed { enemy: usize, outcome: EnemyOutcome },
+    /// Effet infligé par une attaque réussie
+    StatusInflicted { target: Actor, kind: StatusKind },
+    /// L'ennemi n'a plus de PV
+    EnemyDefeated(usize),
+    /// Le combat continue avec le tour de ce combattant
+    NextTurn(Actor),
+    /// Plus aucun ennemi en jeu (vaincus ou enfuis)
+    Victory,
+    /// Le joueur n'a plus de PV
+    Defeat,
+    /// Le joueur a fui le combat
+    Escaped,
+}
+
+/// Tour refusé par `Combat::apply`, l'état du combat n'est pas modifié
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombatError {
+    /// Le combat est terminé
+    Finished,
+    /// Ce n'est pas le tour de ce combattant
+    NotYourTurn,
+    /// Compétence pas encore débloquée, en recharge ou mana insuffisant
+    SkillNotReady,
+}
+
+impl fmt::Display for CombatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CombatError::Finished => write!(f, "le combat est termine"),
+            CombatError::NotYourTurn => write!(f, "ce n'est pas le tour de ce combattant"),
+            CombatError::SkillNotReady => write!(f, "la competence n'est pas prete"),
+        }
+    }
+}
+
+/// Combat en cours : combattants, cible du joueur, tour actif et tirages aléatoires
+/// Deux combats créés avec la même graine et joués avec les mêmes actions
+/// se déroulent à l'identique
+#[derive(Debug, Clone)]
+pub struct Combat {
+    pub player: PlayerFighter,
+    pub enemies: Vec<EnemyFighter>,
+    /// Index de l'ennemi ciblé par les attaques du joueur (toujours un ennemi en jeu)
+    pub target: usize,
+    /// Combattant dont c'est le tour, None une fois le combat terminé
+    current: Option<Actor>,
+    rng: StdRng,
+}
+
+impl Combat {
+    /// Engage le combat contre le premier ennemi ciblé ; le plus rapide joue en premier
+    pub fn new(player: PlayerFighter, enemies: Vec<EnemyFighter>, seed: u64) -> Self {
+        let mut combat = Combat {
+            player,
+            enemies,
+            target: 0,
+            current: None,
+            rng: StdRng::seed_from_u64(seed),
+        };
+        combat.current = Some(next_actor(&mut combat));
+        combat
+    }
+
+    /// Combattant dont c'est le tour, None une fois le combat terminé
+    pub fn current_actor(&self) -> Option<Actor> {
+        self.current
+    }
+
+    /// Victoire quand plus aucun ennemi engagé ne combat (vaincus ou enfuis)
+    pub fn is_won(&self) -> bool {
+        self.enemies.iter().all(|enemy| !enemy.is_fighting())
+    }
+
+    pub fn is_lost(&self) -> bool {
+        self.player.hp <= 0
+    }
+
+    /// Change de cible vers l'ennemi en jeu suivant (`step` = 1) ou précédent (`step` = -1)
+    pub fn cycle_target(&mut self, step: isize) {
+        let count = self.enemies.len() as isize;
+        for offset in 1..=count {
+            let index = (self.target as isize + step * offset).rem_euclid(count) as usize;
+            if self.enemies[index].is_fighting() {
+                self.target = index;
+                return;
+            }
+        }
+    }
+
+    /// Si la cible est vaincue ou enfuie, vise le prochain ennemi encore en jeu
+    pub fn retarget(&mut self) {
+        if !self.enemies[self.target].is_fighting() {
+            self.cycle_target(1);
+        }
+    }
+
+    /// Prévisualise les `count` tours suivant le tour actif, sans modifier le combat
+    pub fn turn_order(&self, count: usize) -> Vec<Actor> {
+        turn_order(self, count)
+    }
+
+    /// Résout le tour du combattant actif : effets de début de tour, action, puis fin du
+    /// combat (`Victory`, `Defeat`, `Escaped`) ou tour suivant désigné par l'initiative
+    /// (`NextTurn`) ; la cible du joueur est déplacée si elle a été vaincue
+    pub fn apply(&mut self, action: TurnAction) -> Result<Vec<CombatEvent>, CombatError> {
+        let actor = self.current.ok_or(CombatError::Finished)?;
+        let mut events = match (action, actor) {
+            (TurnAction::Player(PlayerAction::Skill), Actor::Player)
+                if !self.player.is_skill_ready() =>
+            {
+                return Err(CombatError::SkillNotReady);
+            }
+            (TurnAction::Player(action), Actor::Player) => self.play_player_turn(action),
+            (TurnAction::Enemy, Actor::Enemy(index)) => self.play_enemy_turn(index),
+            _ => return Err(CombatError::NotYourTurn),
+        };
+
+        let escaped = events.iter().any(|event| {
+            matches!(
+                event,
+                CombatEvent::PlayerActed {
+                    outcome: PlayerOutcome::Fled,
+                    ..
+                }
+            )
+        });
+        let end = if self.is_lost() {
+            Some(CombatEvent::Defeat)
+        } else if escaped {
+            Some(CombatEvent::Escaped)
+        } else if self.is_won() {
+            Some(CombatEvent::Victory)
+        } else {
+            None
+        };
+
+        match end {
+            Some(event) => {
+                self.current = None;
+                events.push(event);
+            }
+            None => {
+                self.retarget();
+                let next = next_actor(self);
+                self.current = Some(next);
+                events.push(CombatEvent::NextTurn(next));
+            }
+        }
+        Ok(events)
+    }
+}
@@ -0,0 +1,129 @@
+use crate::stats::Stats;
+use rand::Rng;
+
+/// Résultat d'un calcul de dégâts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DamageResult {
+    pub damage: i32,
+    pub is_critical: bool,
+}
+
+/// Calcule les dégâts infligés par un attaquant
+/// Prend en compte la chance de coup critique qui double les dégâts
+pub fn calculate_damage(attacker_stats: &Stats, rng: &mut impl Rng) -> DamageResult {
+    let mut damage = attacker_stats.attack;
+    let crit_roll = rng.gen_range(1..=100);
+    let is_critical = crit_roll <= attacker_stats.critical_chance;
+
+    if is_critical {
+        damage *= 2;
+    }
+
+    DamageResult {
+        damage,
+        is_critical,
+    }
+}
+
+/// Vérifie si une attaque est esquivée
+/// La chance d'esquive est basée sur la vitesse du défenseur (1-100)
+pub fn check_dodge(defender_speed: i32, rng: &mut impl Rng) -> bool {
+    let dodge_roll = rng.gen_range(1..=100);
+    dodge_roll <= defender_speed
+}
+
+/// Résultat d'une attaque : esquivée par le défenseur ou touchée
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttackResult {
+    Dodged,
+    Hit(DamageResult),
+}
+
+/// Résout une attaque : jet d'esquive du défenseur puis calcul des dégâts de l'attaquant
+/// Ne modifie pas les HP, c'est à l'appelant d'appliquer les dégâts
+pub fn resolve_attack(attacker: &Stats, defender: &Stats, rng: &mut impl Rng) -> AttackResult {
+    if check_dodge(defender.speed, rng) {
+        AttackResult::Dodged
+    } else {
+        AttackResult::Hit(calculate_damage(attacker, rng))
+    }
+}
+
+/// Pourcentage des dégâts ennemis encaissés quand le joueur se défend
+pub const DEFEND_DAMAGE_PERCENT: i32 = 50;
+
+/// Chance de fuite de base en %, modifiée par l'écart de vitesse
+const FLEE_BASE_CHANCE: i32 = 50;
+/// Points de chance de fuite gagnés (ou perdus) par point de vitesse d'écart avec l'ennemi
+const FLEE_CHANCE_PER_SPEED: i32 = 3;
+/// Bornes de la chance de fuite : fuir n'est jamais garanti ni impossible
+const FLEE_MIN_CHANCE: i32 = 10;
+const FLEE_MAX_CHANCE: i32 = 90;
+
+/// Chance de fuite en % selon la vitesse du joueur comparée à celle de l'ennemi
+pub fn flee_chance(player_speed: i32, enemy_speed: i32) -> i32 {
+    (FLEE_BASE_CHANCE + (player_speed - enemy_speed) * FLEE_CHANCE_PER_SPEED)
+        .clamp(FLEE_MIN_CHANCE, FLEE_MAX_CHANCE)
+}
+
+/// Vérifie si la tentative de fuite réussit
+pub fn check_flee(player_speed: i32, enemy_speed: i32, rng: &mut impl Rng) -> bool {
+    let flee_roll = rng.gen_range(1..=100);
+    flee_roll <= flee_chance(player_speed, enemy_speed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn stats(attack: i32, speed: i32, critical_chance: i32) -> Stats {
+        Stats {
+            hp: 100,
+            attack,
+            speed,
+            critical_chance,
+        }
+    }
+
+    #[test]
+    fn critical_hits_double_damage() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let always = calculate_damage(&stats(12, 0, 100), &mut rng);
+        assert_eq!(
+            always,
+            DamageResult {
+                damage: 24,
+                is_critical: true
+            }
+        );
+        let never = calculate_damage(&stats(12, 0, 0), &mut rng);
+        assert_eq!(
+            never,
+            DamageResult {
+                damage: 12,
+                is_critical: false
+            }
+        );
+    }
+
+    #[test]
+    fn speed_is_the_dodge_chance() {
+        let mut rng = StdRng::seed_from_u64(2);
+        assert!((0..100).all(|_| check_dodge(100, &mut rng)));
+        assert!((0..100).all(|_| !check_dodge(0, &mut rng)));
+        assert_eq!(
+            resolve_attack(&stats(10, 0, 0), &stats(10, 100, 0), &mut rng),
+            AttackResult::Dodged
+        );
+    }
+
+    #[test]
+    fn flee_chance_is_clamped() {
+        assert_eq!(flee_chance(10, 10), 50);
+        assert_eq!(flee_chance(15, 10), 65);
+        assert_eq!(flee_chance(100, 0), 90);
+        assert_eq!(flee_chance(0, 100), 10);
+    }
+}
@@ -0,0 +1,377 @@
+use super::*;
+use crate::behaviour::{Behaviour, EnemyMove, Phase};
+use crate::skill::SkillEffect;
+
+/// Stats sans esquive ni critique : les attaques touchent toujours pour `attack` dégâts
+fn stats(hp: i32, attack: i32) -> Stats {
+    Stats {
+        hp,
+        attack,
+        speed: 0,
+        critical_chance: 0,
+    }
+}
+
+fn skill(effect: SkillEffect) -> SkillDef {
+    SkillDef {
+        name: "Test".to_string(),
+        effect,
+        mana_cost: 0,
+        cooldown: 0,
+        unlock_level: 1,
+    }
+}
+
+fn player(hp: i32, attack: i32) -> PlayerFighter {
+    PlayerFighter::new(
+        stats(hp, attack),
+        hp,
+        1,
+        skill(SkillEffect::ShieldWall { damage_percent: 25 }),
+        Vec::new(),
+    )
+}
+
+fn enemy(hp: i32, attack: i32) -> EnemyFighter {
+    EnemyFighter::new(stats(hp, attack), Vec::new(), EnemyAi::default())
+}
+
+/// Joue le combat jusqu'au bout, le joueur attaquant à chaque tour
+fn play_out(mut combat: Combat) -> Vec<CombatEvent> {
+    let mut events = Vec::new();
+    while let Some(actor) = combat.current_actor() {
+        let action = match actor {
+            Actor::Player => TurnAction::Player(PlayerAction::Attack),
+            Actor::Enemy(_) => TurnAction::Enemy,
+        };
+        events.extend(combat.apply(action).unwrap());
+    }
+    events
+}
+
+#[test]
+fn same_seed_same_fight() {
+    let fighters = || {
+        let player = PlayerFighter::new(
+            Stats {
+                hp: 100,
+                attack: 8,
+                speed: 20,
+                critical_chance: 20,
+            },
+            100,
+            1,
+            skill(SkillEffect::ShadowStep),
+            Vec::new(),
+        );
+        let enemy = EnemyFighter::new(
+            Stats {
+                hp: 80,
+                attack: 9,
+                speed: 15,
+                critical_chance: 10,
+            },
+            Vec::new(),
+            EnemyAi::default(),
+        );
+        (player, vec![enemy])
+    };
+    let fight = |seed| {
+        let (player, enemies) = fighters();
+        play_out(Combat::new(player, enemies, seed))
+    };
+
+    assert_eq!(fight(7), fight(7));
+    assert_ne!(fight(7), fight(8));
+}
+
+#[test]
+fn equal_speeds_alternate_starting_with_player() {
+    let mut combat = Combat::new(player(100, 1), vec![enemy(100, 1)], 0);
+    assert_eq!(combat.current_actor(), Some(Actor::Player));
+    assert_eq!(
+        combat.turn_order(3),
+        vec![Actor::Enemy(0), Actor::Player, Actor::Enemy(0)]
+    );
+
+    let events = combat
+        .apply(TurnAction::Player(PlayerAction::Attack))
+        .unwrap();
+    assert_eq!(events.last(), Some(&CombatEvent::NextTurn(Actor::Enemy(0))));
+}
+
+#[test]
+fn turns_out_of_order_are_refused() {
+    let mut combat = Combat::new(player(100, 1), vec![enemy(100, 1)], 0);
+    assert_eq!(
+        combat.apply(TurnAction::Enemy),
+        Err(CombatError::NotYourTurn)
+    );
+    assert_eq!(combat.enemies[0].hp, 100);
+}
+
+#[test]
+fn skill_must_be_ready() {
+    let mut fighter = player(100, 10);
+    fighter.skill.unlock_level = 3;
+    let mut combat = Combat::new(fighter, vec![enemy(100, 1)], 0);
+    assert_eq!(
+        combat.apply(TurnAction::Player(PlayerAction::Skill)),
+        Err(CombatError::SkillNotReady)
+    );
+    assert_eq!(combat.current_actor(), Some(Actor::Player));
+}
+
+#[test]
+fn killing_the_last_enemy_wins() {
+    let mut combat = Combat::new(player(100, 30), vec![enemy(30, 1)], 0);
+    let events = combat
+        .apply(TurnAction::Player(PlayerAction::Attack))
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![
+            CombatEvent::PlayerActed {
+                target: 0,
+                outcome: PlayerOutcome::Attack(AttackResult::Hit(DamageResult {
+                    damage: 30,
+                    is_critical: false,
+                })),
+            },
+            CombatEvent::EnemyDefeated(0),
+            CombatEvent::Victory,
+        ]
+    );
+    assert!(combat.is_won());
+    assert_eq!(combat.current_actor(), None);
+    assert_eq!(
+        combat.apply(TurnAction::Player(PlayerAction::Attack)),
+        Err(CombatError::Finished)
+    );
+}
+
+#[test]
+fn target_moves_to_the_next_enemy_in_play() {
+    let mut combat = Combat::new(player(100, 10), vec![enemy(10, 1), enemy(50, 1)], 0);
+    combat
+        .apply(TurnAction::Player(PlayerAction::Attack))
+        .unwrap();
+    assert_eq!(combat.target, 1);
+    assert!(!combat.is_won());
+}
+
+#[test]
+fn defending_halves_the_next_hit() {
+    let mut combat = Combat::new(player(100, 1), vec![enemy(100, 20)], 0);
+    combat
+        .apply(TurnAction::Player(PlayerAction::Defend))
+        .unwrap();
+    combat.apply(TurnAction::Enemy).unwrap();
+    assert_eq!(combat.player.hp, 100 - 20 * DEFEND_DAMAGE_PERCENT / 100);
+
+    // La protection prend fin au tour suivant du joueur
+    combat
+        .apply(TurnAction::Player(PlayerAction::Attack))
+        .unwrap();
+    combat.apply(TurnAction::Enemy).unwrap();
+    assert_eq!(combat.player.hp, 90 - 20);
+}
+
+#[test]
+fn shadow_step_evades_the_next_attack() {
+    let mut fighter = player(100, 1);
+    fighter.skill = skill(SkillEffect::ShadowStep);
+    let mut combat = Combat::new(fighter, vec![enemy(100, 20)], 0);
+    combat
+        .apply(TurnAction::Player(PlayerAction::Skill))
+        .unwrap();
+    let events = combat.apply(TurnAction::Enemy).unwrap();
+
+    assert_eq!(
+        events[0],
+        CombatEvent::EnemyActed {
+            enemy: 0,
+            outcome: EnemyOutcome::Evaded,
+        }
+    );
+    assert_eq!(combat.player.hp, 100);
+}
+
+#[test]
+fn skill_cooldown_and_mana() {
+    let mut fighter = player(100, 1).with_mana(30, 5);
+    fighter.skill.mana_cost = 20;
+    fighter.skill.cooldown = 2;
+    let mut combat = Combat::new(fighter, vec![enemy(100, 1)], 0);
+    combat
+        .apply(TurnAction::Player(PlayerAction::Skill))
+        .unwrap();
+
+    assert_eq!(combat.player.mana, 15);
+    assert_eq!(combat.player.skill_cooldown, 2);
+    assert!(!combat.player.is_skill_ready());
+}
+
+#[test]
+fn poison_can_finish_an_enemy_before_it_acts() {
+    let mut fighter = player(100, 5);
+    fighter.on_hit = vec![StatusApplication {
+        effect: StatusKind::Poison,
+        potency: 10,
+        duration: 3,
+        chance: 100,
+    }];
+    let mut combat = Combat::new(fighter, vec![enemy(15, 1)], 0);
+    let events = combat
+        .apply(TurnAction::Player(PlayerAction::Attack))
+        .unwrap();
+    assert!(events.contains(&CombatEvent::StatusInflicted {
+        target: Actor::Enemy(0),
+        kind: StatusKind::Poison,
+    }));
+
+    let events = combat.apply(TurnAction::Enemy).unwrap();
+    assert_eq!(
+        events,
+        vec![
+            CombatEvent::StatusDamage {
+                actor: Actor::Enemy(0),
+                kind: StatusKind::Poison,
+                damage: 10,
+            },
+            CombatEvent::EnemyDefeated(0),
+            CombatEvent::Victory,
+        ]
+    );
+}
+
+#[test]
+fn stunned_player_loses_the_turn() {
+    let mut fighter = player(100, 50);
+    fighter.effects.apply(&StatusApplication {
+        effect: StatusKind::Stun,
+        potency: 0,
+        duration: 1,
+        chance: 100,
+    });
+    let mut combat = Combat::new(fighter, vec![enemy(40, 1)], 0);
+    let events = combat
+        .apply(TurnAction::Player(PlayerAction::Attack))
+        .unwrap();
+
+    assert_eq!(
+        events[0],
+        CombatEvent::PlayerActed {
+            target: 0,
+            outcome: PlayerOutcome::Stunned,
+        }
+    );
+    assert_eq!(combat.enemies[0].hp, 40);
+    assert!(combat.player.effects.0.is_empty());
+}
+
+#[test]
+fn healing_items_do_not_exceed_max_hp() {
+    let mut fighter = player(100, 1);
+    fighter.hp = 90;
+    let mut combat = Combat::new(fighter, vec![enemy(100, 1)], 0);
+    let events = combat
+        .apply(TurnAction::Player(PlayerAction::UseItem(
+            ConsumableEffect::Heal(30),
+        )))
+        .unwrap();
+
+    assert_eq!(
+        events[0],
+        CombatEvent::PlayerActed {
+            target: 0,
+            outcome: PlayerOutcome::Healed(10),
+        }
+    );
+    assert_eq!(combat.player.hp, 100);
+}
+
+#[test]
+fn smoke_bomb_always_escapes() {
+    let mut combat = Combat::new(player(100, 1), vec![enemy(100, 1)], 0);
+    let events = combat
+        .apply(TurnAction::Player(PlayerAction::UseItem(
+            ConsumableEffect::Escape,
+        )))
+        .unwrap();
+    assert_eq!(events.last(), Some(&CombatEvent::Escaped));
+    assert_eq!(combat.current_actor(), None);
+}
+
+#[test]
+fn killing_blow_on_the_player_is_a_defeat() {
+    let mut combat = Combat::new(player(10, 1), vec![enemy(100, 10)], 0);
+    combat
+        .apply(TurnAction::Player(PlayerAction::Attack))
+        .unwrap();
+    let events = combat.apply(TurnAction::Enemy).unwrap();
+    assert_eq!(events.last(), Some(&CombatEvent::Defeat));
+    assert!(combat.is_lost());
+}
+
+#[test]
+fn boss_changes_phase_below_threshold() {
+    let ai = EnemyAi {
+        behaviour: Behaviour::Aggressive,
+        phases: vec![Phase {
+            below_percent: 50,
+            message: "Le boss s'enrage".to_string(),
+            behaviour: Behaviour::Pattern(vec![EnemyMove::Charge]),
+        }],
+    };
+    let mut combat = Combat::new(
+        player(100, 60),
+        vec![EnemyFighter::new(stats(100, 10), Vec::new(), ai)],
+        0,
+    );
+    combat
+        .apply(TurnAction::Player(PlayerAction::Attack))
+        .unwrap();
+    let events = combat.apply(TurnAction::Enemy).unwrap();
+
+    assert_eq!(events[0], CombatEvent::PhaseChanged { enemy: 0, phase: 1 });
+    assert_eq!(
+        events[1],
+        CombatEvent::EnemyActed {
+            enemy: 0,
+            outcome: EnemyOutcome::Charge,
+        }
+    );
+    assert_eq!(combat.player.hp, 100);
+}
+
+#[test]
+fn cowardly_enemy_flee_ends_the_fight() {
+    let ai = EnemyAi {
+        behaviour: Behaviour::Cowardly {
+            flee_below_percent: 50,
+        },
+        phases: Vec::new(),
+    };
+    let mut combat = Combat::new(
+        player(100, 60),
+        vec![EnemyFighter::new(stats(100, 10), Vec::new(), ai)],
+        0,
+    );
+    combat
+        .apply(TurnAction::Player(PlayerAction::Attack))
+        .unwrap();
+    let events = combat.apply(TurnAction::Enemy).unwrap();
+
+    assert_eq!(
+        events,
+        vec![
+            CombatEvent::EnemyActed {
+                enemy: 0,
+                outcome: EnemyOutcome::Fled,
+            },
+            CombatEvent::Victory,
+        ]
+    );
+}
@@ -0,0 +1,275 @@
+use crate::behaviour::EnemyMove;
+use crate::combat::rolls::{
+    AttackResult, DEFEND_DAMAGE_PERCENT, calculate_damage, check_flee, resolve_attack,
+};
+use crate::combat::{Actor, Combat, CombatEvent, EnemyOutcome, Guard, PlayerAction, PlayerOutcome};
+use crate::consumable::ConsumableEffect;
+use crate::progression::healed_amount;
+use crate::skill::{SkillDef, SkillEffect};
+use crate::stats::Stats;
+use crate::status::{StatusApplication, StatusEffects, StatusKind, TurnStart};
+use rand::Rng;
+
+/// Tente d'appliquer chaque effet `on_hit` de l'attaquant sur la cible, selon sa chance
+fn apply_on_hit(
+    on_hit: &[StatusApplication],
+    target: &mut StatusEffects,
+    rng: &mut impl Rng,
+) -> Vec<StatusKind> {
+    on_hit
+        .iter()
+        .filter(|application| rng.gen_range(1..=100) <= application.chance)
+        .map(|application| {
+            target.apply(application);
+            application.effect
+        })
+        .collect()
+}
+
+/// Résout l'effet d'une compétence sur l'ennemi
+/// Les effets défensifs (Mur de boucliers, Pas de l'ombre) deviennent une protection (`Guard`)
+fn resolve_skill(
+    skill: &SkillDef,
+    player: &Stats,
+    enemy: &Stats,
+    enemy_hp: i32,
+    rng: &mut impl Rng,
+) -> Option<AttackResult> {
+    match skill.effect {
+        SkillEffect::ShieldWall { .. } => None,
+        SkillEffect::Fireball { damage_percent } => {
+            let mut result = calculate_damage(player, rng);
+            result.damage = result.damage * damage_percent / 100;
+            Some(AttackResult::Hit(result))
+        }
+        SkillEffect::ShadowStep => Some(resolve_attack(player, enemy, rng)),
+        SkillEffect::Execute {
+            threshold_percent,
+            damage_percent,
+        } => {
+            let mut attack = resolve_attack(player, enemy, rng);
+            if let AttackResult::Hit(result) = &mut attack
+                && enemy_hp * 100 <= enemy.hp * threshold_percent
+            {
+                result.damage = result.damage * damage_percent / 100;
+            }
+            Some(attack)
+        }
+    }
+}
+
+/// Protection obtenue par l'action du joueur, active jusqu'à son prochain tour
+fn guard_for(outcome: &PlayerOutcome, skill: &SkillDef) -> Option<Guard> {
+    match outcome {
+        PlayerOutcome::Defend => Some(Guard::Reduce(DEFEND_DAMAGE_PERCENT)),
+        PlayerOutcome::Skill(_) => match skill.effect {
+            SkillEffect::ShieldWall { damage_percent } => Some(Guard::Reduce(damage_percent)),
+            SkillEffect::ShadowStep => Some(Guard::Evade),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Événements des dégâts sur la durée résolus en début de tour
+/// L'étourdissement est annoncé avec l'action du combattant
+fn status_damage(actor: Actor, turn_start: &TurnStart) -> impl Iterator<Item = CombatEvent> + '_ {
+    turn_start
+        .damage
+        .iter()
+        .map(move |&(kind, damage)| CombatEvent::StatusDamage {
+            actor,
+            kind,
+            damage,
+        })
+}
+
+impl Combat {
+    /// Joue le tour du joueur contre l'ennemi ciblé : effets de début de tour
+    /// (dégâts sur la durée, étourdissement), puis action avec les stats modifiées par les effets actifs
+    /// Les dégâts, le mana, la recharge, la protection et les effets sont appliqués au combat
+    pub(super) fn play_player_turn(&mut self, action: PlayerAction) -> Vec<CombatEvent> {
+        let player = &mut self.player;
+        // La protection du tour précédent prend fin
+        player.guard = None;
+
+        let turn_start = player.effects.start_turn();
+        let mut events: Vec<CombatEvent> = status_damage(Actor::Player, &turn_start).collect();
+        player.hp -= turn_start.total_damage();
+        if player.hp <= 0 {
+            return events;
+        }
+
+        // Fuir dépend de l'ennemi le plus rapide encore en vie
+        let fastest_enemy_speed = self
+            .enemies
+            .iter()
+            .filter(|enemy| enemy.is_fighting())
+            .map(|enemy| enemy.effects.modified_stats(&enemy.stats).speed)
+            .max()
+            .unwrap_or(0);
+
+        let rng = &mut self.rng;
+        let target_index = self.target;
+        let target = &mut self.enemies[target_index];
+        let player_stats = player.effects.modified_stats(&player.stats);
+        let enemy_stats = target.effects.modified_stats(&target.stats);
+        let outcome = match action {
+            _ if turn_start.stunned => PlayerOutcome::Stunned,
+            PlayerAction::Attack => {
+                PlayerOutcome::Attack(resolve_attack(&player_stats, &enemy_stats, rng))
+            }
+            PlayerAction::Defend => PlayerOutcome::Defend,
+            PlayerAction::Skill => {
+                player.mana -= player.skill.mana_cost;
+                PlayerOutcome::Skill(resolve_skill(
+                    &player.skill,
+                    &player_stats,
+                    &enemy_stats,
+                    target.hp,
+                    rng,
+                ))
+            }
+            PlayerAction::Flee if check_flee(player_stats.speed, fastest_enemy_speed, rng) => {
+                PlayerOutcome::Fled
+            }
+            PlayerAction::Flee => PlayerOutcome::FleeFailed,
+            PlayerAction::UseItem(ConsumableEffect::Heal(amount)) => {
+                let healed = healed_amount(player.hp, player.stats.hp, amount);
+                player.hp += healed;
+                PlayerOutcome::Healed(healed)
+            }
+            PlayerAction::UseItem(ConsumableEffect::Buff(application)) => {
+                player.effects.apply(&application);
+                PlayerOutcome::Buffed(application.effect)
+            }
+            // Bombe fumigène : la fuite réussit toujours
+            PlayerAction::UseItem(ConsumableEffect::Escape) => PlayerOutcome::Fled,
+        };
+
+        let mut inflicted = Vec::new();
+        if let PlayerOutcome::Attack(AttackResult::Hit(result))
+        | PlayerOutcome::Skill(Some(AttackResult::Hit(result))) = &outcome
+        {
+            target.hp -= result.damage;
+            inflicted = apply_on_hit(&player.on_hit, &mut target.effects, rng);
+        }
+
+        // Fin du tour : recharge et régénération, la compétence lancée repart pour un cycle complet
+        player.guard = guard_for(&outcome, &player.skill);
+        player.skill_cooldown = match outcome {
+            PlayerOutcome::Skill(_) => player.skill.cooldown,
+            _ => player.skill_cooldown.saturating_sub(1),
+        };
+        player.mana = (player.mana + player.mana_regen).min(player.max_mana);
+        player.effects.end_turn();
+
+        events.push(CombatEvent::PlayerActed {
+            target: target_index,
+            outcome,
+        });
+        events.extend(
+            inflicted
+                .into_iter()
+                .map(|kind| CombatEvent::StatusInflicted {
+                    target: Actor::Enemy(target_index),
+                    kind,
+                }),
+        );
+        if !target.is_alive() {
+            events.push(CombatEvent::EnemyDefeated(target_index));
+        }
+        events
+    }
+
+    /// Joue le tour de l'ennemi `index` : effets de début de tour, changement de phase de boss,
+    /// puis action choisie par son comportement ; les attaques tiennent compte
+    /// de la protection du joueur (Défendre, Mur de boucliers, Pas de l'ombre)
+    pub(super) fn play_enemy_turn(&mut self, index: usize) -> Vec<CombatEvent> {
+        let enemy = &mut self.enemies[index];
+        let turn_start = enemy.effects.start_turn();
+        let mut events: Vec<CombatEvent> =
+            status_damage(Actor::Enemy(index), &turn_start).collect();
+        enemy.hp -= turn_start.total_damage();
+        if enemy.hp <= 0 {
+            events.push(CombatEvent::EnemyDefeated(index));
+            return events;
+        }
+
+        // Nouvelle phase selon les PV restants : le motif d'actions repart du début
+        let phase = enemy.ai.phase_for(enemy.hp, enemy.stats.hp);
+        if phase > enemy.phase {
+            enemy.phase = phase;
+            enemy.turns_played = 0;
+            events.push(CombatEvent::PhaseChanged {
+                enemy: index,
+                phase,
+            });
+        }
+
+        let player = &mut self.player;
+        let rng = &mut self.rng;
+        let mut inflicted = Vec::new();
+        let outcome = if turn_start.stunned {
+            EnemyOutcome::Stunned
+        } else {
+            let next_move = enemy.ai.behaviour_in(enemy.phase).next_move(
+                enemy.turns_played,
+                enemy.hp,
+                enemy.stats.hp,
+            );
+            enemy.turns_played += 1;
+
+            match next_move {
+                EnemyMove::Attack | EnemyMove::HeavyAttack { .. }
+                    if player.guard == Some(Guard::Evade) =>
+                {
+                    EnemyOutcome::Evaded
+                }
+                EnemyMove::Attack | EnemyMove::HeavyAttack { .. } => {
+                    let player_stats = player.effects.modified_stats(&player.stats);
+                    let enemy_stats = enemy.effects.modified_stats(&enemy.stats);
+                    let mut attack = resolve_attack(&enemy_stats, &player_stats, rng);
+                    if let AttackResult::Hit(result) = &mut attack {
+                        if let EnemyMove::HeavyAttack { damage_percent } = next_move {
+                            result.damage = result.damage * damage_percent / 100;
+                        }
+                        if let Some(Guard::Reduce(damage_percent)) = player.guard {
+                            result.damage = result.damage * damage_percent / 100;
+                        }
+                        player.hp -= result.damage;
+                        inflicted = apply_on_hit(&enemy.on_hit, &mut player.effects, rng);
+                    }
+                    match next_move {
+                        EnemyMove::HeavyAttack { .. } => EnemyOutcome::HeavyAttack(attack),
+                        _ => EnemyOutcome::Attack(attack),
+                    }
+                }
+                EnemyMove::Charge => EnemyOutcome::Charge,
+                EnemyMove::Buff(application) => {
+                    enemy.effects.apply(&application);
+                    EnemyOutcome::Buff(application.effect)
+                }
+                EnemyMove::Flee => {
+                    enemy.fled = true;
+                    EnemyOutcome::Fled
+                }
+            }
+        };
+        enemy.effects.end_turn();
+
+        events.push(CombatEvent::EnemyActed {
+            enemy: index,
+            outcome,
+        });
+        events.extend(
+            inflicted
+                .into_iter()
+                .map(|kind| CombatEvent::StatusInflicted {
+                    target: Actor::Player,
+                    kind,
+                }),
+        );
+        events
+    }
+}
@@ -0,0 +1,34 @@
+use crate::status::StatusApplication;
+use serde::{Deserialize, Serialize};
+
+/// Effet d'un consommable (champ `consumable` de items.ron)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum ConsumableEffect {
+    /// Rend des PV sans dépasser le maximum
+    Heal(i32),
+    /// Applique un effet au joueur pour la suite du combat (élixir de force), la chance est ignorée
+    Buff(StatusApplication),
+    /// Fuite garantie
+    Escape,
+}
+
+impl ConsumableEffect {
+    /// Les soins s'utilisent partout, les autres effets seulement en combat
+    pub fn usable_on_map(&self) -> bool {
+        matches!(self, ConsumableEffect::Heal(_))
+    }
+
+    /// Description affichée dans l'inventaire et chez le marchand
+    pub fn description(&self) -> String {
+        match self {
+            ConsumableEffect::Heal(amount) => format!("Soigne {} PV", amount),
+            ConsumableEffect::Buff(application) => format!(
+                "{} {:+} pendant {} tours (combat)",
+                application.effect.label(),
+                application.potency,
+                application.duration
+            ),
+            ConsumableEffect::Escape => "Fuite garantie (combat)".to_string(),
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use crate::grid::{Grid, Tile};
+use rand::Rng;
+use std::collections::HashSet;
+use std::ops::Range;
+
+/// Taille minimale (largeur et hauteur) d'une salle
+const MIN_ROOM_SIZE: usize = 3;
+
+/// Taille minimale d'une zone du découpage : une salle et une rangée de mur qui la sépare
+/// de la zone voisine
+const MIN_LEAF_SIZE: usize = MIN_ROOM_SIZE + 1;
+
+/// Chance d'arrêter le découpage d'une zone qui pourrait encore être coupée, pour varier
+/// la taille des salles (la zone de départ est toujours coupée si possible)
+const STOP_SPLIT_CHANCE: f64 = 0.2;
+
+/// Largeur et hauteur minimales d'un étage : une salle entourée de murs, avec une rangée de
+/// plus pour le découpage
+pub const MIN_FLOOR_SIZE: usize = MIN_ROOM_SIZE + 3;
+
+/// Nombre d'objets posés sur chaque étage
+pub const ITEMS_PER_FLOOR: usize = 2;
+
+/// Case de la grille (x, y)
+pub type Cell = (usize, usize);
+
+/// Rectangle de cases praticables (ou zone du découpage)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Room {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Room {
+    pub fn center(&self) -> Cell {
+        (self.x + self.width / 2, self.y + self.height / 2)
+    }
+
+    pub fn contains(&self, (x, y): Cell) -> bool {
+        (self.x..self.x + self.width).contains(&x) && (self.y..self.y + self.height).contains(&y)
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = Cell> + '_ {
+        (self.y..self.y + self.height)
+            .flat_map(move |y| (self.x..self.x + self.width).map(move |x| (x, y)))
+    }
+
+    fn corners(&self) -> [Cell; 4] {
+        let right = self.x + self.width - 1;
+        let bottom = self.y + self.height - 1;
+        [
+            (self.x, self.y),
+            (right, self.y),
+            (self.x, bottom),
+            (right, bottom),
+        ]
+    }
+}
+
+/// Escalier (tuile Connection) et case d'arrivée à côté, où le joueur est placé en venant
+/// de l'autre étage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stairs {
+    pub at: Cell,
+    pub arrival: Cell,
+}
+
+/// Étage généré : salles reliées par des couloirs, entourées de murs
+#[derive(Debug, Clone, PartialEq)]
+pub struct Floor {
+    pub grid: Grid,
+    pub rooms: Vec<Room>,
+    /// Escalier vers l'étage précédent (ou la map d'entrée), dans la première salle
+    pub up: Stairs,
+    /// Escalier vers l'étage suivant, dans la dernière salle ; None au dernier étage
+    pub down: Option<Stairs>,
+}
+
+impl Floor {
+    /// Cases libres pour un objet ou un ennemi : chemins hors escaliers et cases d'arrivée
+    pub fn free_cells(&self) -> Vec<Cell> {
+        let reserved = [Some(self.up), self.down]
+            .into_iter()
+            .flatten()
+            .flat_map(|stairs| [stairs.at, stairs.arrival]);
+        let reserved: HashSet<Cell> = reserved.collect();
+        self.grid
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, tile)| **tile == Tile::Path)
+                    .map(move |(x, _)| (x, y))
+            })
+            .filter(|cell| !reserved.contains(cell))
+            .collect()
+    }
+}
+
+/// Génère un étage de `width` × `height` cases avec le générateur donné : la même graine
+/// donne toujours le même étage
+///
+/// L'intérieur est découpé récursivement en zones, chacune reçoit une salle, et chaque salle
+/// est reliée à la suivante par un couloir en L : toutes les cases praticables sont donc
+/// reliées. Les escaliers sont placés dans un coin de salle qu'aucun couloir ne traverse,
+/// pour ne jamais couper le passage
+///
+/// La taille doit être d'au moins `MIN_FLOOR_SIZE` × `MIN_FLOOR_SIZE` cases : c'est à
+/// l'appelant de la vérifier (la fonction panique sinon)
+pub fn generate_floor(width: usize, height: usize, down_stairs: bool, rng: &mut impl Rng) -> Floor {
+    assert!(
+        width >= MIN_FLOOR_SIZE && height >= MIN_FLOOR_SIZE,
+        "étage trop petit ({} × {}, minimum {} × {})",
+        width,
+        height,
+        MIN_FLOOR_SIZE,
+        MIN_FLOOR_SIZE
+    );
+
+    let mut grid = vec![vec![Tile::Wall; width]; height];
+    let interior = Room {
+        x: 1,
+        y: 1,
+        width: width - 2,
+        height: height - 2,
+    };
+    let mut leaves = Vec::new();
+    split(interior, true, rng, &mut leaves);
+
+    let rooms: Vec<Room> = leaves.iter().map(|leaf| place_room(leaf, rng)).collect();
+    for room in &rooms {
+        for (x, y) in room.cells() {
+            grid[y][x] = Tile::Path;
+        }
+    }
+
+    let mut corridors = HashSet::new();
+    for pair in rooms.windows(2) {
+        for (x, y) in corridor(pair[0].center(), pair[1].center(), rng.gen_bool(0.5)) {
+            grid[y][x] = Tile::Path;
+            corridors.insert((x, y));
+        }
+    }
+
+    let up = place_stairs(&mut grid, &rooms[0], &corridors);
+    let down = down_stairs.then(|| place_stairs(&mut grid, &rooms[rooms.len() - 1], &corridors));
+
+    Floor {
+        grid,
+        rooms,
+        up,
+        down,
+    }
+}
+
+/// Découpe une zone en deux, perpendiculairement à son plus grand côté, tant que les deux
+/// moitiés peuvent recevoir une salle
+fn split(leaf: Room, first: bool, rng: &mut impl Rng, leaves: &mut Vec<Room>) {
+    let can_cut_x = leaf.width >= 2 * MIN_LEAF_SIZE;
+    let can_cut_y = leaf.height >= 2 * MIN_LEAF_SIZE;
+    let stop = !first && rng.gen_bool(STOP_SPLIT_CHANCE);
+    let cut_x = match (can_cut_x, can_cut_y) {
+        (false, false) => None,
+        _ if stop => None,
+        (true, false) => Some(true),
+        (false, true) => Some(false),
+        (true, true) => Some(leaf.width >= leaf.height),
+    };
+    let Some(cut_x) = cut_x else {
+        leaves.push(leaf);
+        return;
+    };
+
+    let (a, b) = if cut_x {
+        let cut = rng.gen_range(MIN_LEAF_SIZE..=leaf.width - MIN_LEAF_SIZE);
+        (
+            Room { width: cut, ..leaf },
+            Room {
+                x: leaf.x + cut,
+                width: leaf.width - cut,
+                ..leaf
+            },
+        )
+    } else {
+        let cut = rng.gen_range(MIN_LEAF_SIZE..=leaf.height - MIN_LEAF_SIZE);
+        (
+            Room {
+                height: cut,
+                ..leaf
+            },
+            Room {
+                y: leaf.y + cut,
+                height: leaf.height - cut,
+                ..leaf
+            },
+        )
+    };
+    split(a, false, rng, leaves);
+    split(b, false, rng, leaves);
+}
+
+/// Salle de taille aléatoire dans la zone, sans toucher sa dernière colonne ni sa dernière
+/// rangée (le mur qui la sépare des zones voisines)
+fn place_room(leaf: &Room, rng: &mut impl Rng) -> Room {
+    let width = rng.gen_range(MIN_ROOM_SIZE..=leaf.width - 1);
+    let height = rng.gen_range(MIN_ROOM_SIZE..=leaf.height - 1);
+    Room {
+        x: leaf.x + rng.gen_range(0..=leaf.width - 1 - width),
+        y: leaf.y + rng.gen_range(0..=leaf.height - 1 - height),
+        width,
+        height,
+    }
+}
+
+/// Cases d'un couloir en L entre deux cases, horizontal puis vertical ou l'inverse
+fn corridor(from: Cell, to: Cell, horizontal_first: bool) -> Vec<Cell> {
+    let corner = if horizontal_first {
+        (to.0, from.1)
+    } else {
+        (from.0, to.1)
+    };
+    let mut cells = line(from, corner);
+    cells.extend(line(corner, to));
+    cells
+}
+
+/// Cases d'un segment horizontal ou vertical, extrémités comprises
+fn line(from: Cell, to: Cell) -> Vec<Cell> {
+    let xs = from.0.min(to.0)..=from.0.max(to.0);
+    let ys = from.1.min(to.1)..=from.1.max(to.1);
+    ys.flat_map(|y| xs.clone().map(move |x| (x, y))).collect()
+}
+
+/// Pose un escalier dans la salle, de préférence dans un coin, hors des couloirs et sans
+/// couper les autres cases praticables les unes des autres
+fn place_stairs(grid: &mut Grid, room: &Room, corridors: &HashSet<Cell>) -> Stairs {
+    let candidates = room.corners().into_iter().chain(room.cells());
+    for at in candidates {
+        if corridors.contains(&at) || grid[at.1][at.0] != Tile::Path {
+            continue;
+        }
+        let Some(arrival) = neighbours(at)
+            .into_iter()
+            .find(|cell| room.contains(*cell) && grid[cell.1][cell.0] == Tile::Path)
+        else {
+            continue;
+        };
+        grid[at.1][at.0] = Tile::Connection;
+        if is_connected(grid) {
+            return Stairs { at, arrival };
+        }
+        grid[at.1][at.0] = Tile::Path;
+    }
+    unreachable!("une salle d'au moins 3 × 3 cases a toujours un coin libre")
+}
+
+fn neighbours((x, y): Cell) -> [Cell; 4] {
+    [
+        (x + 1, y),
+        (x, y + 1),
+        (x.wrapping_sub(1), y),
+        (x, y.wrapping_sub(1)),
+    ]
+}
+
+/// Vérifie que toutes les cases praticables hors escaliers sont reliées entre elles sans
+/// passer par un escalier (qui ferait changer d'étage)
+pub fn is_connected(grid: &Grid) -> bool {
+    let is_floor = |(x, y): Cell| {
+        grid.get(y)
+            .and_then(|row| row.get(x))
+            .is_some_and(|tile| tile.is_walkable() && *tile != Tile::Connection)
+    };
+    let cells: Vec<Cell> = (0..grid.len())
+        .flat_map(|y| (0..grid[y].len()).map(move |x| (x, y)))
+        .filter(|cell| is_floor(*cell))
+        .collect();
+    let Some(&start) = cells.first() else {
+        return true;
+    };
+
+    let mut seen = HashSet::from([start]);
+    let mut stack = vec![start];
+    while let Some(cell) = stack.pop() {
+        for next in neighbours(cell) {
+            if is_floor(next) && seen.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+    seen.len() == cells.len()
+}
+
+/// Tranche d'une liste classée du plus faible au plus fort utilisée à l'étage `depth`
+/// (1 = premier étage) d'un donjon de `floors` étages : plus l'étage est profond, plus
+/// la tranche est forte ; la tranche n'est jamais vide
+pub fn depth_band(len: usize, depth: usize, floors: usize) -> Range<usize> {
+    let start = (len * (depth - 1) / floors).min(len - 1);
+    let end = (len * depth).div_ceil(floors).max(start + 1);
+    start..end
+}
+
+/// Nombre d'ennemis de l'étage `depth` (1 = premier étage) : un de plus à chaque étage
+pub fn enemy_count(depth: usize) -> usize {
+    depth + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn floor(seed: u64, down_stairs: bool) -> Floor {
+        generate_floor(14, 10, down_stairs, &mut StdRng::seed_from_u64(seed))
+    }
+
+    #[test]
+    fn same_seed_same_floor() {
+        assert_eq!(floor(42, true), floor(42, true));
+        assert!((0..10).any(|seed| floor(seed, true) != floor(42, true)));
+    }
+
+    #[test]
+    fn floors_are_connected_and_walled() {
+        for seed in 0..200 {
+            let floor = floor(seed, true);
+            assert!(floor.rooms.len() >= 2, "graine {}", seed);
+            assert!(is_connected(&floor.grid), "graine {}", seed);
+            let last = floor.grid.len() - 1;
+            assert!(floor.grid[0].iter().all(|tile| *tile == Tile::Wall));
+            assert!(floor.grid[last].iter().all(|tile| *tile == Tile::Wall));
+            assert!(
+                floor
+                    .grid
+                    .iter()
+                    .all(|row| row[0] == Tile::Wall && row[row.len() - 1] == Tile::Wall)
+            );
+        }
+    }
+
+    #[test]
+    fn stairs_are_connections_next_to_their_arrival() {
+        for seed in 0..50 {
+            let floor = floor(seed, true);
+            let down = floor.down.unwrap();
+            assert_ne!(floor.up.at, down.at);
+            for stairs in [floor.up, down] {
+                let (x, y) = stairs.at;
+                assert_eq!(floor.grid[y][x], Tile::Connection);
+                assert!(neighbours(stairs.at).contains(&stairs.arrival));
+                assert_eq!(floor.grid[stairs.arrival.1][stairs.arrival.0], Tile::Path);
+            }
+        }
+    }
+
+    #[test]
+    fn last_floor_has_no_down_stairs() {
+        let floor = floor(7, false);
+        assert_eq!(floor.down, None);
+        let connections = floor.grid.iter().flatten();
+        let count = connections
+            .filter(|tile| **tile == Tile::Connection)
+            .count();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn free_cells_skip_stairs_and_arrivals() {
+        let floor = floor(3, true);
+        let free = floor.free_cells();
+        let down = floor.down.unwrap();
+        for cell in [floor.up.at, floor.up.arrival, down.at, down.arrival] {
+            assert!(!free.contains(&cell));
+        }
+        assert!(!free.is_empty());
+    }
+
+    #[test]
+    fn deeper_floors_use_stronger_bands() {
+        assert_eq!(depth_band(6, 1, 3), 0..2);
+        assert_eq!(depth_band(6, 2, 3), 2..4);
+        assert_eq!(depth_band(6, 3, 3), 4..6);
+        // Moins d'entrées que d'étages : chaque tranche garde au moins une entrée
+        assert_eq!(depth_band(2, 1, 3), 0..1);
+        assert_eq!(depth_band(2, 2, 3), 0..2);
+        assert_eq!(depth_band(2, 3, 3), 1..2);
+        assert_eq!(depth_band(1, 3, 3), 0..1);
+    }
+}
@@ -0,0 +1,61 @@
+/// Les différents types de tuiles qui composent la carte
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tile {
+    Wall,       // Mur infranchissable (bloque le déplacement)
+    Path,       // Chemin praticable (permet le déplacement)
+    Connection, // Point de connexion vers une autre map
+    Rest,       // Feu de camp : chemin qui rend tous les PV du joueur
+    Merchant,   // Marchand : chemin qui ouvre la boutique de la map
+}
+
+impl Tile {
+    /// Tout sauf les murs
+    pub fn is_walkable(&self) -> bool {
+        matches!(
+            self,
+            Tile::Path | Tile::Connection | Tile::Rest | Tile::Merchant
+        )
+    }
+}
+
+/// Grille de tuiles d'une carte, indexée par `grid[y][x]`
+pub type Grid = Vec<Vec<Tile>>;
+
+/// Vérifie si une case est praticable (pas un mur, dans les limites)
+pub fn is_walkable(grid: &[Vec<Tile>], x: usize, y: usize) -> bool {
+    grid.get(y)
+        .and_then(|row| row.get(x))
+        .is_some_and(Tile::is_walkable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid {
+        vec![
+            vec![Tile::Wall, Tile::Path, Tile::Wall],
+            vec![Tile::Rest, Tile::Merchant, Tile::Connection],
+        ]
+    }
+
+    #[test]
+    fn walls_block_the_way() {
+        assert!(!is_walkable(&grid(), 0, 0));
+        assert!(is_walkable(&grid(), 1, 0));
+    }
+
+    #[test]
+    fn special_tiles_are_walkable() {
+        assert!(is_walkable(&grid(), 0, 1));
+        assert!(is_walkable(&grid(), 1, 1));
+        assert!(is_walkable(&grid(), 2, 1));
+    }
+
+    #[test]
+    fn out_of_bounds_is_not_walkable() {
+        assert!(!is_walkable(&grid(), 3, 0));
+        assert!(!is_walkable(&grid(), 0, 2));
+        assert!(!is_walkable(&[], 0, 0));
+    }
+}
@@ -0,0 +1,16 @@
+//! Règles du jeu, indépendantes de Bevy : stats, effets de statut, compétences,
+//! comportements des ennemis, progression du joueur, praticabilité de la grille,
+//! génération des étages du donjon et moteur de combat (`combat::Combat`)
+//!
+//! Le jeu ne fait qu'afficher l'état et traduire les événements produits par ces règles ;
+//! le simulateur (`--simulate`) et les tests les utilisent sans fenêtre ni monde Bevy
+
+pub mod behaviour;
+pub mod combat;
+pub mod consumable;
+pub mod dungeon;
+pub mod grid;
+pub mod progression;
+pub mod skill;
+pub mod stats;
+pub mod status;
@@ -0,0 +1,111 @@
+use crate::stats::Stats;
+
+/// XP nécessaire pour passer du niveau N au niveau N+1 : XP_PER_LEVEL × N
+pub const XP_PER_LEVEL: u32 = 50;
+
+/// Bonus de PV et d'attaque des ennemis, en % par niveau du joueur au-delà du premier
+pub const ENEMY_SCALING_PERCENT_PER_LEVEL: i32 = 5;
+
+/// XP nécessaire pour passer du niveau `level` au suivant
+pub fn xp_to_next_level(level: u32) -> u32 {
+    XP_PER_LEVEL * level
+}
+
+/// Niveaux gagnés avec `xp` accumulée depuis le niveau `level`, et XP restante ensuite
+pub fn level_ups(level: u32, mut xp: u32) -> (u32, u32) {
+    let mut levels = 0;
+    while xp >= xp_to_next_level(level + levels) {
+        xp -= xp_to_next_level(level + levels);
+        levels += 1;
+    }
+    (levels, xp)
+}
+
+/// PV réellement rendus par un soin de `amount`, sans dépasser le maximum
+pub fn healed_amount(current_hp: i32, max_hp: i32, amount: i32) -> i32 {
+    amount.min(max_hp - current_hp).max(0)
+}
+
+/// Stats d'un ennemi adaptées au niveau du joueur : PV et attaque augmentés
+/// de ENEMY_SCALING_PERCENT_PER_LEVEL % par niveau au-delà du premier
+pub fn scaled_stats(base: &Stats, player_level: u32) -> Stats {
+    let percent = 100 + ENEMY_SCALING_PERCENT_PER_LEVEL * (player_level as i32 - 1);
+    Stats {
+        hp: base.hp * percent / 100,
+        attack: base.attack * percent / 100,
+        ..*base
+    }
+}
+
+/// Amélioration de stat choisie après une victoire, une par niveau gagné
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Upgrade {
+    Hp,
+    Attack,
+    Speed,
+    CriticalChance,
+}
+
+impl Upgrade {
+    /// Stats ajoutées aux stats de base du joueur (les PV gagnés sont aussi rendus)
+    pub fn bonus(&self) -> Stats {
+        match self {
+            Upgrade::Hp => Stats {
+                hp: 10,
+                ..Stats::default()
+            },
+            Upgrade::Attack => Stats {
+                attack: 2,
+                ..Stats::default()
+            },
+            Upgrade::Speed => Stats {
+                speed: 1,
+                ..Stats::default()
+            },
+            Upgrade::CriticalChance => Stats {
+                critical_chance: 2,
+                ..Stats::default()
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xp_cost_grows_with_level() {
+        assert_eq!(xp_to_next_level(1), 50);
+        assert_eq!(xp_to_next_level(3), 150);
+    }
+
+    #[test]
+    fn several_levels_at_once() {
+        // 50 pour le niveau 2, 100 pour le niveau 3, reste 30
+        assert_eq!(level_ups(1, 180), (2, 30));
+        assert_eq!(level_ups(1, 49), (0, 49));
+        assert_eq!(level_ups(2, 100), (1, 0));
+    }
+
+    #[test]
+    fn healing_never_exceeds_max() {
+        assert_eq!(healed_amount(80, 100, 50), 20);
+        assert_eq!(healed_amount(10, 100, 50), 50);
+        assert_eq!(healed_amount(120, 100, 50), 0);
+    }
+
+    #[test]
+    fn enemies_scale_with_player_level() {
+        let base = Stats {
+            hp: 100,
+            attack: 20,
+            speed: 10,
+            critical_chance: 5,
+        };
+        assert_eq!(scaled_stats(&base, 1), base);
+        let scaled = scaled_stats(&base, 3);
+        assert_eq!((scaled.hp, scaled.attack), (110, 22));
+        assert_eq!((scaled.speed, scaled.critical_chance), (10, 5));
+    }
+}
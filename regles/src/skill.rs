@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+/// Effet d'une compétence de classe
+/// Les paramètres (pourcentages, seuils) sont réglés dans assets/content/classes.ron
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum SkillEffect {
+    /// Réduit les dégâts de la riposte ennemie de ce tour à `damage_percent` %
+    ShieldWall { damage_percent: i32 },
+    /// Attaque impossible à esquiver infligeant `damage_percent` % de l'attaque
+    Fireball { damage_percent: i32 },
+    /// Attaque normale, puis esquive garantie de la riposte ennemie
+    ShadowStep,
+    /// Attaque normale, dégâts portés à `damage_percent` % si l'ennemi
+    /// a `threshold_percent` % de ses points de vie ou moins
+    Execute {
+        threshold_percent: i32,
+        damage_percent: i32,
+    },
+}
+
+/// Définition de la compétence active d'une classe
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SkillDef {
+    pub name: String,
+    pub effect: SkillEffect,
+    /// Mana consommé à chaque utilisation
+    #[serde(default)]
+    pub mana_cost: i32,
+    /// Nombre de tours d'attente après utilisation avant de pouvoir la relancer
+    #[serde(default)]
+    pub cooldown: u32,
+    /// Niveau du joueur à partir duquel la compétence est disponible
+    #[serde(default = "default_unlock_level")]
+    pub unlock_level: u32,
+}
+
+fn default_unlock_level() -> u32 {
+    1
+}
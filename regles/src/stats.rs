@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use std::ops::AddAssign;
+
+/// Structure de statistiques partagée par le joueur, les ennemis et les objets
+/// Cette centralisation garantit la cohérence des calculs de combat
+///
+/// Statistiques :
+/// - hp: Points de vie (Health Points)
+/// - attack: Dégâts infligés par attaque
+/// - speed: Chance d'esquive en % (1-100)
+/// - critical_chance: Chance de coup critique en % (×2 dégâts)
+///
+/// Dans les fichiers de données, les champs absents valent 0 (ex: `(hp: 50)`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Stats {
+    pub hp: i32,
+    pub attack: i32,
+    pub speed: i32,
+    pub critical_chance: i32,
+}
+
+/// Addition champ par champ (bonus d'objet, croissance de niveau)
+impl AddAssign for Stats {
+    fn add_assign(&mut self, other: Stats) {
+        self.hp += other.hp;
+        self.attack += other.attack;
+        self.speed += other.speed;
+        self.critical_chance += other.critical_chance;
+    }
+}
+
+impl Stats {
+    /// Bonus non nuls au format du log (ex: "HP +50, ATK +20")
+    pub fn bonus_summary(&self) -> String {
+        [
+            (self.hp, "HP", ""),
+            (self.attack, "ATK", ""),
+            (self.speed, "SPD", ""),
+            (self.critical_chance, "CRIT", "%"),
+        ]
+        .iter()
+        .filter(|(value, _, _)| *value != 0)
+        .map(|(value, label, unit)| format!("{} {:+}{}", label, value, unit))
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+}
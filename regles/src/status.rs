@@ -0,0 +1,151 @@
+use crate::stats::Stats;
+use serde::{Deserialize, Serialize};
+
+/// Nombre maximal de cumuls d'un même effet sur un combattant
+pub const MAX_STACKS: u32 = 5;
+
+/// Type d'effet de statut
+/// La puissance (`potency`) est multipliée par le nombre de cumuls
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StatusKind {
+    /// Dégâts au début de chaque tour du combattant
+    Poison,
+    Bleed,
+    Burn,
+    /// Le combattant passe son tour
+    Stun,
+    /// Bonus d'attaque
+    Strength,
+    /// Malus d'attaque
+    Weakness,
+}
+
+impl StatusKind {
+    /// Nom affiché à côté des barres de vie et dans les messages de combat
+    pub fn label(&self) -> &'static str {
+        match self {
+            StatusKind::Poison => "Poison",
+            StatusKind::Bleed => "Saignement",
+            StatusKind::Burn => "Brûlure",
+            StatusKind::Stun => "Étourdi",
+            StatusKind::Strength => "Force",
+            StatusKind::Weakness => "Faiblesse",
+        }
+    }
+
+    /// Indique si l'effet inflige des dégâts à chaque tour
+    pub fn is_damage_over_time(&self) -> bool {
+        matches!(
+            self,
+            StatusKind::Poison | StatusKind::Bleed | StatusKind::Burn
+        )
+    }
+}
+
+fn default_chance() -> i32 {
+    100
+}
+
+/// Effet appliqué par les attaques réussies d'un combattant (champ `on_hit` des définitions)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StatusApplication {
+    pub effect: StatusKind,
+    /// Dégâts par tour ou modificateur d'attaque, par cumul (inutile pour Stun)
+    #[serde(default)]
+    pub potency: i32,
+    /// Nombre de tours du combattant affecté
+    pub duration: u32,
+    /// Chance d'appliquer l'effet en % quand l'attaque touche
+    #[serde(default = "default_chance")]
+    pub chance: i32,
+}
+
+/// Effet actif sur un combattant
+#[derive(Debug, Clone, Copy)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub potency: i32,
+    pub turns_left: u32,
+    pub stacks: u32,
+}
+
+/// Ce qu'ont produit les effets au début du tour d'un combattant
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TurnStart {
+    /// Dégâts subis par effet (poison, saignement, brûlure)
+    pub damage: Vec<(StatusKind, i32)>,
+    pub stunned: bool,
+}
+
+impl TurnStart {
+    pub fn total_damage(&self) -> i32 {
+        self.damage.iter().map(|(_, damage)| damage).sum()
+    }
+}
+
+/// Effets actifs sur un combattant
+#[derive(Debug, Clone, Default)]
+pub struct StatusEffects(pub Vec<StatusEffect>);
+
+impl StatusEffects {
+    /// Ajoute un effet ou cumule avec celui déjà présent (jusqu'à MAX_STACKS)
+    /// La durée est remise au maximum entre l'effet en cours et le nouveau
+    pub fn apply(&mut self, application: &StatusApplication) {
+        match self
+            .0
+            .iter_mut()
+            .find(|effect| effect.kind == application.effect)
+        {
+            Some(effect) => {
+                effect.stacks = (effect.stacks + 1).min(MAX_STACKS);
+                effect.turns_left = effect.turns_left.max(application.duration);
+                effect.potency = effect.potency.max(application.potency);
+            }
+            None => self.0.push(StatusEffect {
+                kind: application.effect,
+                potency: application.potency,
+                turns_left: application.duration,
+                stacks: 1,
+            }),
+        }
+    }
+
+    /// Stats de base modifiées par les effets actifs (Force, Faiblesse)
+    /// L'attaque ne descend jamais sous 0
+    pub fn modified_stats(&self, base: &Stats) -> Stats {
+        let mut stats = *base;
+        for effect in &self.0 {
+            let amount = effect.potency * effect.stacks as i32;
+            match effect.kind {
+                StatusKind::Strength => stats.attack += amount,
+                StatusKind::Weakness => stats.attack -= amount,
+                _ => {}
+            }
+        }
+        stats.attack = stats.attack.max(0);
+        stats
+    }
+
+    /// Résout les effets au début du tour du combattant : dégâts sur la durée et étourdissement
+    /// Les dégâts sont retournés, c'est à l'appelant de les appliquer
+    pub fn start_turn(&self) -> TurnStart {
+        TurnStart {
+            damage: self
+                .0
+                .iter()
+                .filter(|effect| effect.kind.is_damage_over_time())
+                .map(|effect| (effect.kind, effect.potency * effect.stacks as i32))
+                .collect(),
+            stunned: self.0.iter().any(|effect| effect.kind == StatusKind::Stun),
+        }
+    }
+
+    /// Fin du tour du combattant : décompte les durées et retire les effets expirés
+    pub fn end_turn(&mut self) {
+        for effect in &mut self.0 {
+            effect.turns_left = effect.turns_left.saturating_sub(1);
+        }
+        self.0.retain(|effect| effect.turns_left > 0);
+    }
+}
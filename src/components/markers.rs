@@ -1,4 +1,5 @@
 use crate::player::PlayerClass;
+use crate::resources::CombatAction;
 use bevy::prelude::Component;
 
 // ============ Marker Components ============
@@ -28,6 +29,10 @@ pub struct StatsText;
 #[derive(Component)]
 pub struct LogText;
 
+/// Marker component pour le texte des quêtes actives
+#[derive(Component)]
+pub struct QuestText;
+
 /// Marker component pour l'UI de combat
 #[derive(Component)]
 pub struct CombatUI;
@@ -36,21 +41,94 @@ pub struct CombatUI;
 #[derive(Component)]
 pub struct CombatLogText;
 
-/// Marker component pour une barre de vie (joueur ou ennemi)
+/// Marker component pour une barre de vie (joueur ou ennemi n° i du combat)
 #[derive(Component)]
 pub enum HealthBar {
     Player,
-    Enemy,
+    Enemy(usize),
+}
+
+/// Marker component pour le texte des effets de statut actifs, sous une barre de vie
+#[derive(Component)]
+pub enum StatusEffectsText {
+    Player,
+    Enemy(usize),
 }
 
 /// Marker component pour le texte des statistiques du joueur en combat
 #[derive(Component)]
 pub struct PlayerStatsText;
 
-/// Marker component pour le texte des statistiques de l'ennemi en combat
+/// Marker component pour le texte des statistiques d'un ennemi en combat (index dans CombatState)
+#[derive(Component)]
+pub struct EnemyStatsText(pub usize);
+
+/// Marker component pour la carte d'un ennemi en combat, cliquable pour le cibler
 #[derive(Component)]
-pub struct EnemyStatsText;
+pub struct EnemyCard(pub usize);
 
 /// Marker component pour le message d'action au centre (tour actuel)
 #[derive(Component)]
 pub struct ActionMessageText;
+
+/// Marker component pour le texte de l'ordre des tours en combat
+#[derive(Component)]
+pub struct TurnOrderText;
+
+/// Marker component pour le conteneur du menu d'actions de combat
+#[derive(Component)]
+pub struct CombatMenu;
+
+/// Marker component pour un bouton du menu d'actions de combat
+#[derive(Component)]
+pub struct CombatActionButton(pub CombatAction);
+
+/// Marker component pour l'écran d'inventaire
+#[derive(Component)]
+pub struct InventoryUI;
+
+/// Marker component pour la liste des emplacements et des objets du sac
+#[derive(Component)]
+pub struct InventoryListText;
+
+/// Marker component pour l'aperçu des stats après équipement
+#[derive(Component)]
+pub struct InventoryPreviewText;
+
+/// Marker component pour l'écran du marchand
+#[derive(Component)]
+pub struct ShopUI;
+
+/// Marker component pour la liste des objets à acheter et à vendre
+#[derive(Component)]
+pub struct ShopListText;
+
+/// Marker component pour le détail de l'objet sélectionné et l'or du joueur
+#[derive(Component)]
+pub struct ShopDetailText;
+
+/// Marker component pour l'écran des emplacements de sauvegarde
+#[derive(Component)]
+pub struct SaveSlotsUI;
+
+/// Marker component pour la liste des emplacements et le message de la dernière action
+#[derive(Component)]
+pub struct SaveSlotsListText;
+
+/// Marker component pour l'écran des touches
+#[derive(Component)]
+pub struct ControlsUI;
+
+/// Marker component pour une colonne de la liste des actions et de leurs touches
+/// (0 = première moitié de `Action::ALL`, 1 = seconde moitié)
+#[derive(Component)]
+pub struct ControlsListText(pub usize);
+
+/// Marker component pour la disposition de départ et le résultat de la dernière modification
+/// sur l'écran des touches
+#[derive(Component)]
+pub struct ControlsInfoText;
+
+/// Marker component pour l'écran de bilan de fin de partie
+#[derive(Component)]
+pub struct SummaryUI;
@@ -1,7 +1,5 @@
 mod markers;
 mod position;
-mod stats;
 
 pub use markers::*;
-pub use position::Position;
-pub use stats::Stats;
+pub use position::{Position, PreviousPosition};
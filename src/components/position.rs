@@ -1,12 +1,18 @@
 use bevy::prelude::Component;
+use serde::{Deserialize, Serialize};
 
 /// Position logique sur la grille de la carte (coordonnées x, y)
 /// Utilisée par tous les éléments positionnables (joueur, ennemis, objets, tuiles)
 ///
 /// Implémente Hash et Eq pour pouvoir être utilisée comme clé dans des HashSet/HashMap
 /// (nécessaire pour tracker les objets collectés et ennemis vaincus)
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub struct Position {
     pub x: usize,
     pub y: usize,
 }
+
+/// Dernière case occupée par le joueur avant son déplacement actuel
+/// Permet de le replacer hors de la case de l'ennemi après une fuite réussie
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PreviousPosition(pub Position);
@@ -3,3 +3,18 @@ pub const TILE_SIZE: f32 = 64.0;
 
 /// Largeur du terminal d'information à droite
 pub const TERMINAL_WIDTH: f32 = 300.0;
+
+/// Dossier des définitions de classes, ennemis et objets, relatif au dossier `assets/`
+pub const CONTENT_DIR: &str = "content";
+
+/// Dossier des fichiers de map, relatif au dossier `assets/`
+pub const MAPS_DIR: &str = "maps";
+
+/// Durée de la pause affichée avant l'action de l'ennemi en combat (secondes)
+pub const ENEMY_TURN_DELAY_SECS: f32 = 1.0;
+
+/// Prix de revente d'un objet au marchand, en % de sa valeur
+pub const SELL_PRICE_PERCENT: u32 = 50;
+
+/// Nombre d'emplacements de sauvegarde proposés sur l'écran des sauvegardes
+pub const SAVE_SLOTS: usize = 3;
@@ -0,0 +1,532 @@
+mod parse;
+
+pub use parse::{DataLoadError, list_ron_files, parse_ron, read_file};
+
+use crate::controls::Action;
+use crate::dungeon::DungeonDef;
+use crate::enemy::EnemyType;
+use crate::inventory::EquipmentSlot;
+use crate::item::ItemType;
+use crate::loot::LootEntry;
+use crate::player::PlayerClass;
+use crate::quest::{Objective, QuestDef, VictoryCondition};
+use bevy::prelude::Resource;
+use regles::behaviour::{Behaviour, EnemyAi, Phase};
+use regles::combat::{EnemyFighter, PlayerFighter};
+use regles::consumable::ConsumableEffect;
+use regles::skill::SkillDef;
+use regles::stats::Stats;
+use regles::status::StatusApplication;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Définition d'une classe jouable (assets/content/classes.ron)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClassDef {
+    pub id: PlayerClass,
+    pub name: String,
+    pub sprite: String,
+    pub stats: Stats,
+    /// Réserve de mana maximale, remplie au début de chaque combat (0 = pas de mana)
+    #[serde(default)]
+    pub max_mana: i32,
+    /// Mana regagné à la fin de chaque tour de combat
+    #[serde(default)]
+    pub mana_regen: i32,
+    pub skill: SkillDef,
+    /// Effets que les attaques de la classe peuvent infliger
+    #[serde(default)]
+    pub on_hit: Vec<StatusApplication>,
+    /// Stats gagnées à chaque niveau
+    #[serde(default)]
+    pub growth: Stats,
+}
+
+impl ClassDef {
+    /// Combattant des règles pour un joueur de cette classe : `stats` équipement compris,
+    /// `hp` PV restants, mana plein
+    pub fn fighter(&self, stats: Stats, hp: i32, level: u32) -> PlayerFighter {
+        PlayerFighter::new(stats, hp, level, self.skill.clone(), self.on_hit.clone())
+            .with_mana(self.max_mana, self.mana_regen)
+    }
+}
+
+/// Définition d'un type d'ennemi (assets/content/enemies.ron)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EnemyDef {
+    pub id: EnemyType,
+    pub name: String,
+    pub sprite: String,
+    pub stats: Stats,
+    /// XP rapportée quand l'ennemi est vaincu
+    #[serde(default)]
+    pub xp: u32,
+    /// Or rapporté quand l'ennemi est vaincu
+    #[serde(default)]
+    pub gold: u32,
+    /// Effets que les attaques de l'ennemi peuvent infliger (poison du Serpent, etc.)
+    #[serde(default)]
+    pub on_hit: Vec<StatusApplication>,
+    /// Comportement en combat (attaque à chaque tour par défaut)
+    #[serde(default)]
+    pub behaviour: Behaviour,
+    /// Phases de boss, par seuil de PV décroissant
+    #[serde(default)]
+    pub phases: Vec<Phase>,
+    /// Objets que l'ennemi peut lâcher à sa défaite
+    #[serde(default)]
+    pub loot: Vec<LootEntry>,
+}
+
+impl EnemyDef {
+    pub fn ai(&self) -> EnemyAi {
+        EnemyAi {
+            behaviour: self.behaviour.clone(),
+            phases: self.phases.clone(),
+        }
+    }
+
+    /// Combattant des règles avec ces stats (adaptées au niveau du joueur en jeu)
+    pub fn fighter(&self, stats: Stats) -> EnemyFighter {
+        EnemyFighter::new(stats, self.on_hit.clone(), self.ai())
+    }
+}
+
+/// Définition d'un type d'objet (assets/content/items.ron)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ItemDef {
+    pub id: ItemType,
+    pub name: String,
+    pub sprite: String,
+    /// Emplacement d'équipement
+    #[serde(default)]
+    pub slot: Option<EquipmentSlot>,
+    /// Bonus apportés tant que l'objet est équipé
+    #[serde(default)]
+    pub stats: Stats,
+    /// Effet d'un consommable ; exclusif avec `slot`
+    #[serde(default)]
+    pub consumable: Option<ConsumableEffect>,
+    /// Prix d'achat chez un marchand (0 = invendable)
+    #[serde(default)]
+    pub price: u32,
+}
+
+/// Resource Bevy regroupant toutes les définitions de contenu du jeu
+/// Ajouter un ennemi, un objet ou une classe se fait dans les fichiers de données, sans recompiler
+///
+/// Les ids sont validés au chargement (et les maps ne peuvent référencer que des ids connus) :
+/// les accesseurs paniquent donc sur un id inconnu, ce qui serait une erreur de programmation
+#[derive(Resource, Debug, Clone)]
+pub struct ContentRegistry {
+    /// Classes dans l'ordre de l'écran de sélection
+    pub classes: Vec<ClassDef>,
+    pub enemies: Vec<EnemyDef>,
+    pub items: Vec<ItemDef>,
+    /// Quêtes dans l'ordre d'affichage du terminal
+    pub quests: Vec<QuestDef>,
+    /// Condition de fin de partie
+    pub victory: VictoryCondition,
+    pub dungeon: DungeonDef,
+}
+
+impl ContentRegistry {
+    /// Charge classes.ron, enemies.ron, items.ron, quests.ron, victory.ron et dungeon.ron
+    /// depuis `dir`
+    /// Vérifie que les ids sont uniques et que les sprites existent dans `assets_root`
+    pub fn load(dir: &Path, assets_root: &Path) -> Result<Self, DataLoadError> {
+        let classes: Vec<ClassDef> = load_defs(&dir.join("classes.ron"), assets_root)?;
+        let enemies: Vec<EnemyDef> = load_defs(&dir.join("enemies.ron"), assets_root)?;
+        let items: Vec<ItemDef> = load_defs(&dir.join("items.ron"), assets_root)?;
+        let quests: Vec<QuestDef> = load_defs(&dir.join("quests.ron"), assets_root)?;
+
+        if classes.is_empty() {
+            return Err(DataLoadError::Invalid {
+                path: dir.join("classes.ron"),
+                message: "au moins une classe est nécessaire".to_string(),
+            });
+        }
+        // Chaque classe se choisit avec sa propre action Classe N
+        if classes.len() > Action::CLASS_SLOTS.len() {
+            return Err(DataLoadError::Invalid {
+                path: dir.join("classes.ron"),
+                message: format!(
+                    "{} classes pour {} touches de choix de classe",
+                    classes.len(),
+                    Action::CLASS_SLOTS.len()
+                ),
+            });
+        }
+
+        // Une compétence plus chère que la réserve de mana ne pourrait jamais être lancée
+        if let Some(class_def) = classes
+            .iter()
+            .find(|class_def| class_def.skill.mana_cost > class_def.max_mana)
+        {
+            let path = dir.join("classes.ron");
+            let source = read_file(&path)?;
+            return Err(DataLoadError::at(
+                &path,
+                &source,
+                &format!("\"{}\"", class_def.skill.name),
+                format!(
+                    "la compétence \"{}\" coûte {} mana mais la classe n'en a que {}",
+                    class_def.skill.name, class_def.skill.mana_cost, class_def.max_mana
+                ),
+            ));
+        }
+
+        // Un motif vide ne donnerait aucune action, des phases mal triées ne seraient jamais atteintes
+        // et le butin doit référencer des objets connus
+        for enemy_def in &enemies {
+            if let Some(message) =
+                enemy_ai_error(enemy_def).or_else(|| loot_error(enemy_def, &items))
+            {
+                let path = dir.join("enemies.ron");
+                let source = read_file(&path)?;
+                return Err(DataLoadError::at(
+                    &path,
+                    &source,
+                    &format!("\"{}\"", enemy_def.id.0),
+                    format!("ennemi \"{}\" : {}", enemy_def.id.0, message),
+                ));
+            }
+        }
+
+        // Un objet est soit un équipement, soit un consommable ;
+        // ses bonus ne s'appliquent que s'il est équipé
+        for item_def in &items {
+            if let Some(message) = item_kind_error(item_def) {
+                let path = dir.join("items.ron");
+                let source = read_file(&path)?;
+                return Err(DataLoadError::at(
+                    &path,
+                    &source,
+                    &format!("\"{}\"", item_def.id.0),
+                    format!("objet \"{}\" : {}", item_def.id.0, message),
+                ));
+            }
+        }
+
+        // Les objectifs et récompenses référencent des ennemis et objets connus
+        // (les maps des objectifs ReachMap sont vérifiées au chargement des maps)
+        for quest in &quests {
+            if let Some(message) = quest_error(quest, &enemies, &items) {
+                let path = dir.join("quests.ron");
+                let source = read_file(&path)?;
+                return Err(DataLoadError::at(
+                    &path,
+                    &source,
+                    &format!("\"{}\"", quest.id),
+                    format!("quête \"{}\" : {}", quest.id, message),
+                ));
+            }
+        }
+
+        // La condition de victoire doit pouvoir être remplie
+        let victory_path = dir.join("victory.ron");
+        let victory_source = read_file(&victory_path)?;
+        let victory: VictoryCondition = parse_ron(&victory_path, &victory_source)?;
+        if let Some((needle, message)) = victory_error(&victory, &enemies, &quests) {
+            return Err(DataLoadError::at(
+                &victory_path,
+                &victory_source,
+                &needle,
+                message,
+            ));
+        }
+
+        // Le donjon ne peut puiser que dans des ennemis et objets connus
+        // (sa map d'entrée est vérifiée au chargement des maps)
+        let dungeon_path = dir.join("dungeon.ron");
+        let dungeon_source = read_file(&dungeon_path)?;
+        let dungeon: DungeonDef = parse_ron(&dungeon_path, &dungeon_source)?;
+        if let Some((needle, message)) = dungeon_error(&dungeon, &enemies, &items) {
+            return Err(DataLoadError::at(
+                &dungeon_path,
+                &dungeon_source,
+                &needle,
+                message,
+            ));
+        }
+
+        Ok(ContentRegistry {
+            classes,
+            enemies,
+            items,
+            quests,
+            victory,
+            dungeon,
+        })
+    }
+
+    pub fn class(&self, id: &PlayerClass) -> &ClassDef {
+        self.classes
+            .iter()
+            .find(|def| def.id == *id)
+            .unwrap_or_else(|| panic!("classe inconnue: {}", id.0))
+    }
+
+    pub fn enemy(&self, id: &EnemyType) -> &EnemyDef {
+        self.enemies
+            .iter()
+            .find(|def| def.id == *id)
+            .unwrap_or_else(|| panic!("ennemi inconnu: {}", id.0))
+    }
+
+    pub fn item(&self, id: &ItemType) -> &ItemDef {
+        self.items
+            .iter()
+            .find(|def| def.id == *id)
+            .unwrap_or_else(|| panic!("objet inconnu: {}", id.0))
+    }
+
+    pub fn has_enemy(&self, id: &EnemyType) -> bool {
+        self.enemies.iter().any(|def| def.id == *id)
+    }
+
+    pub fn has_item(&self, id: &ItemType) -> bool {
+        self.items.iter().any(|def| def.id == *id)
+    }
+}
+
+/// Vérifie le comportement et les phases d'un ennemi
+fn enemy_ai_error(enemy_def: &EnemyDef) -> Option<String> {
+    let behaviours =
+        std::iter::once(&enemy_def.behaviour).chain(enemy_def.phases.iter().map(|p| &p.behaviour));
+    for behaviour in behaviours {
+        if let Behaviour::Pattern(moves) = behaviour
+            && moves.is_empty()
+        {
+            return Some("le motif d'actions est vide".to_string());
+        }
+    }
+
+    let mut previous = 100;
+    for phase in &enemy_def.phases {
+        if phase.below_percent <= 0 || phase.below_percent >= previous {
+            return Some(format!(
+                "seuil de phase {}% invalide (attendu entre 1 et {}, par ordre décroissant)",
+                phase.below_percent,
+                previous - 1
+            ));
+        }
+        previous = phase.below_percent;
+    }
+    None
+}
+
+/// Vérifie qu'un objet est soit un équipement, soit un consommable
+fn item_kind_error(item_def: &ItemDef) -> Option<String> {
+    match (item_def.slot, item_def.consumable) {
+        (Some(_), Some(_)) => {
+            Some("un équipement (slot) ne peut pas être consommable (consumable)".to_string())
+        }
+        (None, None) => Some("ni emplacement (slot) ni effet (consumable)".to_string()),
+        (None, Some(_)) if item_def.stats != Stats::default() => {
+            Some("donne des stats mais n'a pas d'emplacement (slot)".to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Vérifie la table de butin d'un ennemi
+fn loot_error(enemy_def: &EnemyDef, items: &[ItemDef]) -> Option<String> {
+    enemy_def.loot.iter().find_map(|entry| {
+        if !items.iter().any(|item_def| item_def.id == entry.item) {
+            Some(format!("objet de butin inconnu \"{}\"", entry.item.0))
+        } else if !(1..=100).contains(&entry.chance) {
+            Some(format!(
+                "chance de butin {}% invalide pour \"{}\" (attendu entre 1 et 100)",
+                entry.chance, entry.item.0
+            ))
+        } else {
+            None
+        }
+    })
+}
+
+/// Vérifie l'objectif et la récompense d'une quête
+fn quest_error(quest: &QuestDef, enemies: &[EnemyDef], items: &[ItemDef]) -> Option<String> {
+    let unknown_item = |item: &ItemType| !items.iter().any(|item_def| item_def.id == *item);
+    match &quest.objective {
+        Objective::Defeat { enemy, .. } if !enemies.iter().any(|def| def.id == *enemy) => {
+            return Some(format!("ennemi inconnu \"{}\"", enemy.0));
+        }
+        Objective::Defeat { count: 0, .. } => {
+            return Some("le nombre d'ennemis à vaincre doit être d'au moins 1".to_string());
+        }
+        Objective::Collect(collect) if collect.is_empty() => {
+            return Some("aucun objet à collecter".to_string());
+        }
+        Objective::Collect(collect) => {
+            if let Some(item) = collect.iter().find(|item| unknown_item(item)) {
+                return Some(format!("objet à collecter inconnu \"{}\"", item.0));
+            }
+        }
+        _ => {}
+    }
+    quest
+        .reward
+        .items
+        .iter()
+        .find(|item| unknown_item(item))
+        .map(|item| format!("objet de récompense inconnu \"{}\"", item.0))
+}
+
+/// Vérifie que la condition de victoire référence des ennemis et quêtes connus
+/// Retourne le texte à localiser dans le fichier et le message d'erreur
+fn victory_error(
+    victory: &VictoryCondition,
+    enemies: &[EnemyDef],
+    quests: &[QuestDef],
+) -> Option<(String, String)> {
+    match victory {
+        VictoryCondition::DefeatAll(list) if list.is_empty() => Some((
+            "DefeatAll".to_string(),
+            "aucun ennemi à vaincre".to_string(),
+        )),
+        VictoryCondition::CompleteQuests(list) if list.is_empty() => Some((
+            "CompleteQuests".to_string(),
+            "aucune quête à terminer".to_string(),
+        )),
+        VictoryCondition::DefeatAll(list) => list
+            .iter()
+            .find(|enemy| !enemies.iter().any(|def| def.id == **enemy))
+            .map(|enemy| {
+                (
+                    format!("\"{}\"", enemy.0),
+                    format!("ennemi inconnu \"{}\"", enemy.0),
+                )
+            }),
+        VictoryCondition::CompleteQuests(list) => list
+            .iter()
+            .find(|id| !quests.iter().any(|quest| quest.id == **id))
+            .map(|id| (format!("\"{}\"", id), format!("quête inconnue \"{}\"", id))),
+    }
+}
+
+/// Vérifie le donjon : au moins un étage et des listes non vides d'ennemis et d'objets connus
+/// Retourne le texte à localiser dans le fichier et le message d'erreur
+fn dungeon_error(
+    dungeon: &DungeonDef,
+    enemies: &[EnemyDef],
+    items: &[ItemDef],
+) -> Option<(String, String)> {
+    if dungeon.floors == 0 {
+        return Some((
+            "floors:".to_string(),
+            "le donjon n'a aucun étage".to_string(),
+        ));
+    }
+    if dungeon.enemies.is_empty() {
+        return Some((
+            "enemies:".to_string(),
+            "aucun ennemi dans le donjon".to_string(),
+        ));
+    }
+    if dungeon.items.is_empty() {
+        return Some((
+            "items:".to_string(),
+            "aucun objet dans le donjon".to_string(),
+        ));
+    }
+    let unknown_enemy = dungeon
+        .enemies
+        .iter()
+        .find(|enemy| !enemies.iter().any(|def| def.id == **enemy))
+        .map(|enemy| (enemy.0.as_str(), "ennemi"));
+    let unknown_item = dungeon
+        .items
+        .iter()
+        .find(|item| !items.iter().any(|def| def.id == **item))
+        .map(|item| (item.0.as_str(), "objet"));
+    unknown_enemy.or(unknown_item).map(|(id, kind)| {
+        (
+            format!("\"{}\"", id),
+            format!("{} inconnu \"{}\"", kind, id),
+        )
+    })
+}
+
+/// Champs communs à toutes les définitions, vérifiés au chargement
+trait ContentDef {
+    fn id(&self) -> &str;
+    /// Sprite à vérifier, None pour les définitions sans image (quêtes)
+    fn sprite(&self) -> Option<&str>;
+}
+
+impl ContentDef for ClassDef {
+    fn id(&self) -> &str {
+        &self.id.0
+    }
+    fn sprite(&self) -> Option<&str> {
+        Some(&self.sprite)
+    }
+}
+
+impl ContentDef for EnemyDef {
+    fn id(&self) -> &str {
+        &self.id.0
+    }
+    fn sprite(&self) -> Option<&str> {
+        Some(&self.sprite)
+    }
+}
+
+impl ContentDef for ItemDef {
+    fn id(&self) -> &str {
+        &self.id.0
+    }
+    fn sprite(&self) -> Option<&str> {
+        Some(&self.sprite)
+    }
+}
+
+impl ContentDef for QuestDef {
+    fn id(&self) -> &str {
+        &self.id
+    }
+    fn sprite(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Charge une liste de définitions et vérifie l'unicité des ids et l'existence des sprites
+fn load_defs<T: ContentDef + DeserializeOwned>(
+    path: &Path,
+    assets_root: &Path,
+) -> Result<Vec<T>, DataLoadError> {
+    let source = read_file(path)?;
+    let defs: Vec<T> = parse_ron(path, &source)?;
+
+    let mut seen = HashSet::new();
+    for def in &defs {
+        let (id, sprite) = (def.id(), def.sprite());
+        if !seen.insert(id) {
+            return Err(DataLoadError::at(
+                path,
+                &source,
+                &format!("\"{}\"", id),
+                format!("id \"{}\" défini plusieurs fois", id),
+            ));
+        }
+        if let Some(sprite) = sprite
+            && !assets_root.join(sprite).is_file()
+        {
+            return Err(DataLoadError::at(
+                path,
+                &source,
+                &format!("\"{}\"", sprite),
+                format!("sprite introuvable: {}", sprite),
+            ));
+        }
+    }
+
+    Ok(defs)
+}
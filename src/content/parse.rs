@@ -0,0 +1,123 @@
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Erreur de chargement d'un fichier de données (maps, ennemis, objets, classes)
+/// Chaque variante nomme le fichier fautif pour que les designers puissent le corriger
+#[derive(Debug)]
+pub enum DataLoadError {
+    /// Le dossier ou un fichier n'a pas pu être lu
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// Erreur de syntaxe ou de contenu, avec sa position dans le fichier
+    Parse {
+        path: PathBuf,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// Le fichier est valide mais son contenu est incohérent (connexion vers une map inconnue, etc.)
+    Invalid { path: PathBuf, message: String },
+    /// Aucun fichier de données trouvé dans le dossier
+    Empty { dir: PathBuf },
+}
+
+impl fmt::Display for DataLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataLoadError::Io { path, source } => {
+                write!(f, "{}: lecture impossible ({})", path.display(), source)
+            }
+            DataLoadError::Parse {
+                path,
+                line,
+                column,
+                message,
+            } => write!(f, "{}:{}:{}: {}", path.display(), line, column, message),
+            DataLoadError::Invalid { path, message } => {
+                write!(f, "{}: {}", path.display(), message)
+            }
+            DataLoadError::Empty { dir } => {
+                write!(f, "{}: aucun fichier .ron trouvé", dir.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for DataLoadError {}
+
+impl DataLoadError {
+    /// Erreur de contenu positionnée sur la première occurrence de `needle` dans le fichier
+    /// Si `needle` est introuvable, l'erreur nomme seulement le fichier
+    pub fn at(path: &Path, source: &str, needle: &str, message: String) -> Self {
+        match locate(source, needle) {
+            Some((line, column)) => DataLoadError::Parse {
+                path: path.to_path_buf(),
+                line,
+                column,
+                message,
+            },
+            None => DataLoadError::Invalid {
+                path: path.to_path_buf(),
+                message,
+            },
+        }
+    }
+}
+
+/// Lit un fichier texte en convertissant l'erreur d'E/S
+pub fn read_file(path: &Path) -> Result<String, DataLoadError> {
+    fs::read_to_string(path).map_err(|source| DataLoadError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Désérialise le contenu RON d'un fichier
+/// Les erreurs de syntaxe sont rapportées avec la ligne et la colonne
+pub fn parse_ron<T: DeserializeOwned>(path: &Path, source: &str) -> Result<T, DataLoadError> {
+    ron::Options::default()
+        .from_str(source)
+        .map_err(|err| DataLoadError::Parse {
+            path: path.to_path_buf(),
+            line: err.position.line,
+            column: err.position.col,
+            message: err.code.to_string(),
+        })
+}
+
+/// Liste les fichiers `.ron` d'un dossier, triés par nom
+pub fn list_ron_files(dir: &Path) -> Result<Vec<PathBuf>, DataLoadError> {
+    let io_error = |source| DataLoadError::Io {
+        path: dir.to_path_buf(),
+        source,
+    };
+
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if path.extension().is_some_and(|ext| ext == "ron") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    if paths.is_empty() {
+        return Err(DataLoadError::Empty {
+            dir: dir.to_path_buf(),
+        });
+    }
+    Ok(paths)
+}
+
+/// Retourne la position (ligne, colonne) de la première occurrence de `needle`, en ignorant les commentaires
+fn locate(source: &str, needle: &str) -> Option<(usize, usize)> {
+    source.lines().enumerate().find_map(|(line_index, line)| {
+        let code = line.split("//").next().unwrap_or("");
+        code.find(needle)
+            .map(|byte_index| (line_index + 1, code[..byte_index].chars().count() + 1))
+    })
+}
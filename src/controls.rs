@@ -0,0 +1,419 @@
+use crate::content::{DataLoadError, parse_ron, read_file};
+use crate::save::{SaveError, save_dir, write_save};
+use crate::states::GameState;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Nom du fichier des touches dans le dossier de données de l'utilisateur
+const CONTROLS_FILE_NAME: &str = "touches.ron";
+
+/// Action du jeu, associée à une ou plusieurs touches par `KeyBindings`
+/// Une même touche peut servir à plusieurs actions d'écrans différents (S : déplacement
+/// sur la map, +1 SPD après un combat), jamais à deux actions d'un même écran (voir `states`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Confirm,
+    Cancel,
+    Inventory,
+    UsePotion,
+    QuickSave,
+    QuickLoad,
+    Attack,
+    Defend,
+    Skill,
+    UseItem,
+    /// Choix du N-ième consommable après l'action Objet
+    UseItem1,
+    UseItem2,
+    UseItem3,
+    UseItem4,
+    UseItem5,
+    UseItem6,
+    Flee,
+    PreviousTarget,
+    NextTarget,
+    ChooseHp,
+    ChooseAtk,
+    ChooseSpd,
+    ChooseCrit,
+    Restart,
+    NewGame,
+    /// Choix de la N-ième classe du registre sur l'écran de sélection
+    ChooseClass1,
+    ChooseClass2,
+    ChooseClass3,
+    ChooseClass4,
+    ChooseClass5,
+    ChooseClass6,
+    DeleteSave,
+    Controls,
+}
+
+impl Action {
+    /// Toutes les actions, dans l'ordre de l'écran des touches
+    pub const ALL: [Action; 37] = [
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::Confirm,
+        Action::Cancel,
+        Action::Inventory,
+        Action::UsePotion,
+        Action::QuickSave,
+        Action::QuickLoad,
+        Action::Attack,
+        Action::Defend,
+        Action::Skill,
+        Action::UseItem,
+        Action::UseItem1,
+        Action::UseItem2,
+        Action::UseItem3,
+        Action::UseItem4,
+        Action::UseItem5,
+        Action::UseItem6,
+        Action::Flee,
+        Action::PreviousTarget,
+        Action::NextTarget,
+        Action::ChooseHp,
+        Action::ChooseAtk,
+        Action::ChooseSpd,
+        Action::ChooseCrit,
+        Action::Restart,
+        Action::NewGame,
+        Action::ChooseClass1,
+        Action::ChooseClass2,
+        Action::ChooseClass3,
+        Action::ChooseClass4,
+        Action::ChooseClass5,
+        Action::ChooseClass6,
+        Action::DeleteSave,
+        Action::Controls,
+    ];
+
+    /// Choix des consommables en combat, dans l'ordre de l'inventaire
+    pub const ITEM_SLOTS: [Action; 6] = [
+        Action::UseItem1,
+        Action::UseItem2,
+        Action::UseItem3,
+        Action::UseItem4,
+        Action::UseItem5,
+        Action::UseItem6,
+    ];
+
+    /// Choix des classes sur l'écran de sélection, dans l'ordre du registre
+    pub const CLASS_SLOTS: [Action; 6] = [
+        Action::ChooseClass1,
+        Action::ChooseClass2,
+        Action::ChooseClass3,
+        Action::ChooseClass4,
+        Action::ChooseClass5,
+        Action::ChooseClass6,
+    ];
+
+    /// Libellé affiché sur l'écran des touches
+    pub fn label(&self) -> &'static str {
+        match self {
+            Action::MoveUp => "Haut",
+            Action::MoveDown => "Bas",
+            Action::MoveLeft => "Gauche",
+            Action::MoveRight => "Droite",
+            Action::Confirm => "Valider",
+            Action::Cancel => "Retour",
+            Action::Inventory => "Inventaire",
+            Action::UsePotion => "Boire une potion",
+            Action::QuickSave => "Sauvegarder",
+            Action::QuickLoad => "Charger",
+            Action::Attack => "Attaquer",
+            Action::Defend => "Defendre",
+            Action::Skill => "Competence",
+            Action::UseItem => "Objet",
+            Action::UseItem1 => "Objet 1",
+            Action::UseItem2 => "Objet 2",
+            Action::UseItem3 => "Objet 3",
+            Action::UseItem4 => "Objet 4",
+            Action::UseItem5 => "Objet 5",
+            Action::UseItem6 => "Objet 6",
+            Action::Flee => "Fuir",
+            Action::PreviousTarget => "Cible precedente",
+            Action::NextTarget => "Cible suivante",
+            Action::ChooseHp => "Amelioration HP",
+            Action::ChooseAtk => "Amelioration ATK",
+            Action::ChooseSpd => "Amelioration SPD",
+            Action::ChooseCrit => "Amelioration CRIT",
+            Action::Restart => "Recommencer",
+            Action::NewGame => "Nouvelle partie",
+            Action::ChooseClass1 => "Classe 1",
+            Action::ChooseClass2 => "Classe 2",
+            Action::ChooseClass3 => "Classe 3",
+            Action::ChooseClass4 => "Classe 4",
+            Action::ChooseClass5 => "Classe 5",
+            Action::ChooseClass6 => "Classe 6",
+            Action::DeleteSave => "Effacer la sauvegarde",
+            Action::Controls => "Ecran des touches",
+        }
+    }
+
+    /// États du jeu dans lesquels l'action est lue
+    pub fn states(&self) -> &'static [GameState] {
+        use GameState::*;
+        match self {
+            Action::MoveUp | Action::MoveDown => &[SaveSlots, Map, Inventory, Shop],
+            Action::MoveLeft | Action::MoveRight | Action::UsePotion => &[Map],
+            Action::QuickSave | Action::QuickLoad => &[Map],
+            Action::Confirm => &[SaveSlots, Inventory, Shop, Combat],
+            Action::Cancel => &[ClassSelection, Inventory, Shop, Combat, Victory, Defeat],
+            Action::Inventory => &[Map, Inventory],
+            Action::Attack
+            | Action::Defend
+            | Action::Skill
+            | Action::UseItem
+            | Action::UseItem1
+            | Action::UseItem2
+            | Action::UseItem3
+            | Action::UseItem4
+            | Action::UseItem5
+            | Action::UseItem6
+            | Action::Flee
+            | Action::PreviousTarget
+            | Action::NextTarget
+            | Action::ChooseHp
+            | Action::ChooseAtk
+            | Action::ChooseSpd
+            | Action::ChooseCrit => &[Combat],
+            Action::Restart => &[Victory, Defeat],
+            Action::NewGame | Action::DeleteSave | Action::Controls => &[SaveSlots],
+            Action::ChooseClass1
+            | Action::ChooseClass2
+            | Action::ChooseClass3
+            | Action::ChooseClass4
+            | Action::ChooseClass5
+            | Action::ChooseClass6 => &[ClassSelection],
+        }
+    }
+
+    /// Les deux actions sont lues dans un même état du jeu
+    pub fn shares_state_with(&self, other: Action) -> bool {
+        self.states()
+            .iter()
+            .any(|state| other.states().contains(state))
+    }
+}
+
+/// Disposition de clavier de départ : seules les touches de déplacement changent
+/// Les consommables se choisissent au pavé numérique, dont les chiffres ne demandent pas Maj
+/// en AZERTY et ne servent à aucune autre action du combat
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeyPreset {
+    /// Déplacement Z/Q/S/D
+    #[default]
+    Azerty,
+    /// Déplacement W/A/S/D
+    Qwerty,
+}
+
+impl KeyPreset {
+    pub fn label(&self) -> &'static str {
+        match self {
+            KeyPreset::Azerty => "AZERTY",
+            KeyPreset::Qwerty => "QWERTY",
+        }
+    }
+
+    /// Touches par défaut de la disposition
+    pub fn keys(&self, action: Action) -> Vec<KeyCode> {
+        let (up, left) = match self {
+            KeyPreset::Azerty => (KeyCode::Z, KeyCode::Q),
+            KeyPreset::Qwerty => (KeyCode::W, KeyCode::A),
+        };
+        match action {
+            Action::MoveUp => vec![up, KeyCode::Up],
+            Action::MoveDown => vec![KeyCode::S, KeyCode::Down],
+            Action::MoveLeft => vec![left, KeyCode::Left],
+            Action::MoveRight => vec![KeyCode::D, KeyCode::Right],
+            Action::Confirm => vec![KeyCode::Return],
+            Action::Cancel => vec![KeyCode::Escape],
+            Action::Inventory => vec![KeyCode::I],
+            Action::UsePotion => vec![KeyCode::P],
+            Action::QuickSave => vec![KeyCode::F5],
+            Action::QuickLoad => vec![KeyCode::F9],
+            Action::Attack => vec![KeyCode::Key1, KeyCode::Space],
+            Action::Defend => vec![KeyCode::Key2],
+            Action::Skill => vec![KeyCode::Key3],
+            Action::UseItem => vec![KeyCode::Key4],
+            Action::UseItem1 => vec![KeyCode::Numpad1],
+            Action::UseItem2 => vec![KeyCode::Numpad2],
+            Action::UseItem3 => vec![KeyCode::Numpad3],
+            Action::UseItem4 => vec![KeyCode::Numpad4],
+            Action::UseItem5 => vec![KeyCode::Numpad5],
+            Action::UseItem6 => vec![KeyCode::Numpad6],
+            Action::Flee => vec![KeyCode::Key5],
+            Action::PreviousTarget => vec![KeyCode::Up],
+            Action::NextTarget => vec![KeyCode::Down, KeyCode::Tab],
+            Action::ChooseHp => vec![KeyCode::H],
+            Action::ChooseAtk => vec![KeyCode::A],
+            Action::ChooseSpd => vec![KeyCode::S],
+            Action::ChooseCrit => vec![KeyCode::C],
+            Action::Restart => vec![KeyCode::R],
+            Action::NewGame => vec![KeyCode::N],
+            Action::ChooseClass1 => vec![KeyCode::Key1, KeyCode::Numpad1],
+            Action::ChooseClass2 => vec![KeyCode::Key2, KeyCode::Numpad2],
+            Action::ChooseClass3 => vec![KeyCode::Key3, KeyCode::Numpad3],
+            Action::ChooseClass4 => vec![KeyCode::Key4, KeyCode::Numpad4],
+            Action::ChooseClass5 => vec![KeyCode::Key5, KeyCode::Numpad5],
+            Action::ChooseClass6 => vec![KeyCode::Key6, KeyCode::Numpad6],
+            Action::DeleteSave => vec![KeyCode::Delete],
+            Action::Controls => vec![KeyCode::T],
+        }
+    }
+}
+
+/// Resource des touches associées à chaque action
+/// Chargée au lancement depuis le fichier des touches (ou la disposition AZERTY par défaut)
+/// et modifiée par l'écran des touches
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeyBindings {
+    /// Disposition de départ, qui complète les actions absentes de `bindings` au chargement
+    pub preset: KeyPreset,
+    #[serde(default)]
+    pub bindings: HashMap<Action, Vec<KeyCode>>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings::from_preset(KeyPreset::default())
+    }
+}
+
+impl KeyBindings {
+    pub fn from_preset(preset: KeyPreset) -> Self {
+        KeyBindings {
+            preset,
+            bindings: Action::ALL
+                .into_iter()
+                .map(|action| (action, preset.keys(action)))
+                .collect(),
+        }
+    }
+
+    pub fn keys(&self, action: Action) -> &[KeyCode] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Remplace les touches de l'action par une seule touche
+    pub fn rebind(&mut self, action: Action, key: KeyCode) {
+        self.bindings.insert(action, vec![key]);
+    }
+
+    /// Touches de l'action, au format des indications à l'écran (ex: "Z/HAUT")
+    pub fn hint(&self, action: Action) -> String {
+        let names: Vec<String> = self.keys(action).iter().map(|key| key_name(*key)).collect();
+        names.join("/")
+    }
+
+    /// Donne les touches de la disposition aux actions absentes du fichier
+    fn fill_missing(&mut self) {
+        for action in Action::ALL {
+            self.bindings
+                .entry(action)
+                .or_insert_with(|| self.preset.keys(action));
+        }
+    }
+
+    /// Une action sans touche serait inaccessible
+    fn unbound_error(&self) -> Option<String> {
+        Action::ALL
+            .into_iter()
+            .find(|action| self.keys(*action).is_empty())
+            .map(|action| format!("aucune touche pour l'action {:?}", action))
+    }
+}
+
+/// Nom d'une touche affiché à l'écran
+pub fn key_name(key: KeyCode) -> String {
+    let name = match key {
+        KeyCode::Up => "HAUT",
+        KeyCode::Down => "BAS",
+        KeyCode::Left => "GAUCHE",
+        KeyCode::Right => "DROITE",
+        KeyCode::Return => "ENTREE",
+        KeyCode::Escape => "ECHAP",
+        KeyCode::Space => "ESPACE",
+        KeyCode::Tab => "TAB",
+        KeyCode::Delete => "SUPPR",
+        KeyCode::Back => "RETOUR",
+        KeyCode::Key1 => "1",
+        KeyCode::Key2 => "2",
+        KeyCode::Key3 => "3",
+        KeyCode::Key4 => "4",
+        KeyCode::Key5 => "5",
+        KeyCode::Key6 => "6",
+        KeyCode::Key7 => "7",
+        KeyCode::Key8 => "8",
+        KeyCode::Key9 => "9",
+        KeyCode::Key0 => "0",
+        KeyCode::Numpad1 => "PAVE 1",
+        KeyCode::Numpad2 => "PAVE 2",
+        KeyCode::Numpad3 => "PAVE 3",
+        KeyCode::Numpad4 => "PAVE 4",
+        KeyCode::Numpad5 => "PAVE 5",
+        KeyCode::Numpad6 => "PAVE 6",
+        other => return format!("{:?}", other).to_uppercase(),
+    };
+    name.to_string()
+}
+
+/// Chemin du fichier des touches, à côté des sauvegardes
+pub fn controls_path() -> Result<PathBuf, SaveError> {
+    save_dir()
+        .map(|dir| dir.join(CONTROLS_FILE_NAME))
+        .ok_or(SaveError::NoSaveDir)
+}
+
+/// Lit le fichier des touches ; sans fichier, la disposition AZERTY est utilisée
+/// Le fichier peut ne redéfinir que certaines actions
+pub fn load_bindings() -> Result<KeyBindings, DataLoadError> {
+    let path = match controls_path() {
+        Ok(path) if path.is_file() => path,
+        _ => return Ok(KeyBindings::default()),
+    };
+    let source = read_file(&path)?;
+    let mut bindings: KeyBindings = parse_ron(&path, &source)?;
+    bindings.fill_missing();
+    match bindings.unbound_error() {
+        Some(message) => Err(DataLoadError::Invalid { path, message }),
+        None => Ok(bindings),
+    }
+}
+
+/// Écrit le fichier des touches
+pub fn save_bindings(bindings: &KeyBindings) -> Result<(), SaveError> {
+    write_save(&controls_path()?, bindings)
+}
+
+/// Clavier lu à travers les touches configurées
+#[derive(SystemParam)]
+pub struct Controls<'w> {
+    pub keyboard_input: Res<'w, Input<KeyCode>>,
+    pub bindings: Res<'w, KeyBindings>,
+}
+
+impl Controls<'_> {
+    /// Une des touches de l'action vient d'être pressée
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.keyboard_input
+            .any_just_pressed(self.bindings.keys(action).iter().copied())
+    }
+
+    pub fn hint(&self, action: Action) -> String {
+        self.bindings.hint(action)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::components::Position;
+use crate::enemy::EnemyType;
+use crate::item::ItemType;
+use bevy::prelude::Resource;
+use serde::Deserialize;
+
+/// Option de la ligne de commande qui impose la graine du donjon
+pub const DUNGEON_SEED_FLAG: &str = "--dungeon-seed";
+
+/// Donjon généré (assets/content/dungeon.ron)
+/// Ses étages sont ajoutés après les maps de assets/maps/ (voir `systems::map::Dungeon`) ;
+/// ils dépendent de la graine de la partie : la même graine et les mêmes listes donnent
+/// toujours le même donjon
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DungeonDef {
+    /// Nombre d'étages, reliés par des escaliers
+    pub floors: usize,
+    pub entrance: DungeonEntrance,
+    /// Ennemis du plus faible au plus fort : chaque étage puise dans une tranche plus forte
+    pub enemies: Vec<EnemyType>,
+    /// Objets du moins au plus précieux, répartis de la même façon
+    pub items: Vec<ItemType>,
+}
+
+/// Tuile C d'une map de assets/maps/ qui mène au premier étage
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DungeonEntrance {
+    /// Nom de la map (nom du fichier sans extension)
+    pub map: String,
+    pub at: Position,
+}
+
+/// Resource pour la graine du donjon des nouvelles parties : imposée par `--dungeon-seed`
+/// pour rejouer un donjon partagé, tirée au hasard à chaque nouvelle partie sinon
+/// La graine de la partie en cours est dans `GameData::dungeon` et dans la sauvegarde
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct DungeonSeedChoice(pub Option<u64>);
+
+impl DungeonSeedChoice {
+    /// Lit `--dungeon-seed N` dans les arguments du programme
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let Some(index) = args.iter().position(|arg| arg == DUNGEON_SEED_FLAG) else {
+            return Ok(DungeonSeedChoice(None));
+        };
+        args.get(index + 1)
+            .and_then(|value| value.parse().ok())
+            .map(|seed| DungeonSeedChoice(Some(seed)))
+            .ok_or_else(|| format!("{} attend un nombre", DUNGEON_SEED_FLAG))
+    }
+
+    /// Graine d'une nouvelle partie
+    pub fn new_game_seed(&self) -> u64 {
+        self.0.unwrap_or_else(rand::random)
+    }
+}
@@ -1,47 +1,24 @@
 use crate::assets::ImageAssets;
-use crate::components::Stats;
+use crate::content::ContentRegistry;
 use bevy::prelude::{Component, Handle, Image};
+use regles::stats::Stats;
+use serde::{Deserialize, Serialize};
 
-/// Types d'ennemis avec difficulté progressive
+/// Identifiant d'un type d'ennemi dans le ContentRegistry (ex: "SmallGoblin", "Wolf")
+/// Les stats, le nom et le sprite de chaque type sont définis dans assets/content/enemies.ron
+///
 /// Distribution sur les maps :
 /// - Map 1 : 3 Petits Gobelins + 1 Gobelin Moyen (facile)
-/// - Map 2 : 3 Gobelins Moyens + 1 Gros Gobelin + 1 Loup Boss (difficile)
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum EnemyType {
-    SmallGoblin,  // Petit Gobelin - Le plus faible, rapide
-    MediumGoblin, // Gobelin Moyen - Équilibré
-    LargeGoblin,  // Gros Gobelin - Tanky et fort
-    Wolf,         // Loup - Boss final, très dangereux
-    Snake,
-    Drake,
-    DeathBird,
-}
+/// - Map 2 : 2 Gobelins Moyens + 1 Gros Gobelin + 1 Loup Boss, et une meute de gobelins (difficile)
+/// - Map 3 : Soldat Draconide, Oiseau Funeraire et Serpent (boss)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EnemyType(pub String);
 
 impl EnemyType {
     /// Retourne le handle de l'image correspondant au type d'ennemi
     pub fn get_image_handle(&self, image_assets: &ImageAssets) -> Handle<Image> {
-        match self {
-            EnemyType::SmallGoblin => image_assets.small_goblin.clone(),
-            EnemyType::MediumGoblin => image_assets.medium_goblin.clone(),
-            EnemyType::LargeGoblin => image_assets.large_goblin.clone(),
-            EnemyType::Wolf => image_assets.wolf.clone(),
-            EnemyType::Snake => image_assets.snake.clone(),
-            EnemyType::Drake => image_assets.drake.clone(),
-            EnemyType::DeathBird => image_assets.death_bird.clone(),
-        }
-    }
-
-    /// Retourne le nom français de l'ennemi pour l'affichage
-    pub fn get_name(&self) -> &'static str {
-        match self {
-            EnemyType::SmallGoblin => "Petit Gobelin",
-            EnemyType::MediumGoblin => "Gobelin Moyen",
-            EnemyType::LargeGoblin => "Gros Gobelin",
-            EnemyType::Wolf => "Loup",
-            EnemyType::Snake => "Serpent",
-            EnemyType::Drake => "Soldat Draconide",
-            EnemyType::DeathBird => "Oiseau Funeraire",
-        }
+        image_assets.enemies[self].clone()
     }
 }
 
@@ -54,67 +31,9 @@ pub struct Enemy {
 }
 
 impl Enemy {
-    /// Crée un nouvel ennemi avec les stats prédéfinies pour son type
-    pub fn new(enemy_type: EnemyType) -> Self {
-        let stats = get_stats_for_enemy(enemy_type);
+    /// Crée un nouvel ennemi avec les stats définies pour son type dans le registre
+    pub fn new(enemy_type: EnemyType, registry: &ContentRegistry) -> Self {
+        let stats = registry.enemy(&enemy_type).stats;
         Enemy { enemy_type, stats }
     }
 }
-
-/// Retourne les stats équilibrées et prédéfinies pour chaque type d'ennemi
-///
-/// Équilibrage des ennemis :
-/// - Petit Gobelin: HP 30, ATK 5, SPD 8, CRIT 5% (rapide mais faible)
-/// - Gobelin Moyen: HP 50, ATK 8, SPD 5, CRIT 10% (équilibré)
-/// - Gros Gobelin: HP 80, ATK 12, SPD 3, CRIT 8% (tanky et fort)
-/// - Loup (Boss): HP 100, ATK 15, SPD 12, CRIT 20% (très dangereux)
-pub fn get_stats_for_enemy(enemy_type: EnemyType) -> Stats {
-    match enemy_type {
-        // Petit Gobelin : faible, rapide, peu de dégâts
-        EnemyType::SmallGoblin => Stats {
-            hp: 30,
-            attack: 5,
-            speed: 8,
-            critical_chance: 5,
-        },
-        // Gobelin Moyen : équilibré
-        EnemyType::MediumGoblin => Stats {
-            hp: 50,
-            attack: 8,
-            speed: 5,
-            critical_chance: 10,
-        },
-        // Gros Gobelin : tanky, lent, fort
-        EnemyType::LargeGoblin => Stats {
-            hp: 80,
-            attack: 12,
-            speed: 3,
-            critical_chance: 8,
-        },
-        // Loup : Boss - très fort, rapide, dangereux
-        EnemyType::Wolf => Stats {
-            hp: 100,
-            attack: 15,
-            speed: 12,
-            critical_chance: 20,
-        },
-        EnemyType::Snake => Stats {
-            hp: 200,
-            attack: 30,
-            speed: 20,
-            critical_chance: 20,
-        },
-        EnemyType::Drake => Stats {
-            hp: 150,
-            attack: 27,
-            speed: 8,
-            critical_chance: 20,
-        },
-        EnemyType::DeathBird => Stats {
-            hp: 180,
-            attack: 30,
-            speed: 9,
-            critical_chance: 13,
-        },
-    }
-}
@@ -0,0 +1,120 @@
+//! Événements de jeu émis par l'exploration et le combat
+//!
+//! Le log, les objets collectés, les ennemis vaincus, les quêtes et le bilan sont mis à jour
+//! par des systèmes qui les écoutent (voir `systems::progress`) ; une nouvelle fonctionnalité
+//! peut s'y abonner avec un `EventReader` sans modifier les systèmes qui les émettent
+//!
+//! Les écouteurs tournent dans `PostUpdate`, après les systèmes qui émettent les événements
+//! dans `Update` et avant le changement d'état de la frame suivante
+
+use crate::components::Position;
+use crate::item::ItemType;
+use crate::loot::Rarity;
+use crate::resources::CombatEnemy;
+use bevy::prelude::Event;
+use regles::stats::Stats;
+
+/// Le joueur s'est déplacé avec les touches de déplacement, éventuellement vers une autre map
+/// (émis par `move_player`) ; les retours en arrière du combat (fuite) ou du chargement ne
+/// sont pas des déplacements
+#[derive(Event, Debug, Clone)]
+pub struct PlayerMoved {
+    pub map_index: usize,
+    pub position: Position,
+    /// Le déplacement a emprunté une connexion vers la map `map_index`
+    pub changed_map: bool,
+}
+
+/// Le joueur a ramassé un objet de la map (émis par `check_item_pickup`)
+#[derive(Event, Debug, Clone)]
+pub struct ItemPickedUp {
+    pub map_index: usize,
+    pub position: Position,
+    pub item_type: ItemType,
+}
+
+/// Le joueur a ouvert un coffre de la map et reçu son or (émis par `check_chest`)
+#[derive(Event, Debug, Clone)]
+pub struct ChestOpened {
+    pub map_index: usize,
+    pub position: Position,
+    pub gold: u32,
+    /// Or du joueur après l'ouverture
+    pub total_gold: u32,
+}
+
+/// Un combat commence contre les ennemis engagés (émis par `check_enemy_encounter`)
+#[derive(Event, Debug, Clone)]
+pub struct CombatStarted {
+    pub enemies: Vec<CombatEnemy>,
+}
+
+/// Un ennemi a été vaincu, à la fin d'un combat gagné ou lors d'une fuite
+/// (émis par `handle_combat`)
+#[derive(Event, Debug, Clone)]
+pub struct EnemyDefeated {
+    pub map_index: usize,
+    pub enemy: CombatEnemy,
+}
+
+/// Le combat se termine, par une victoire, une fuite ou une défaite (émis par `handle_combat`
+/// quand le joueur quitte le combat)
+#[derive(Event, Debug, Clone)]
+pub struct CombatEnded {
+    pub player_turns: u32,
+    /// Dégâts infligés à l'ensemble des ennemis
+    pub damage_dealt: i32,
+}
+
+/// Le joueur a perdu un combat face aux ennemis encore debout (émis par `handle_combat`
+/// quand le joueur quitte le combat perdu)
+#[derive(Event, Debug, Clone)]
+pub struct PlayerDied {
+    pub enemies: Vec<CombatEnemy>,
+}
+
+/// Le joueur a fui le combat face aux ennemis encore debout (émis par `handle_combat`)
+#[derive(Event, Debug, Clone)]
+pub struct PlayerFled {
+    pub enemies: Vec<CombatEnemy>,
+}
+
+/// Un ennemi s'est enfui d'un combat gagné : il reste sur la map (émis par `handle_combat`)
+#[derive(Event, Debug, Clone)]
+pub struct EnemyFled {
+    pub enemy: CombatEnemy,
+}
+
+/// XP et or gagnés à la fin d'un combat, déjà ajoutés au joueur
+#[derive(Event, Debug, Clone)]
+pub struct CombatRewards {
+    pub xp: u32,
+    pub gold: u32,
+    /// Or du joueur après la récompense
+    pub total_gold: u32,
+}
+
+/// Le joueur a gagné un ou plusieurs niveaux à la fin d'un combat (PV restaurés)
+#[derive(Event, Debug, Clone)]
+pub struct LevelUp {
+    pub level: u32,
+    pub hp: i32,
+    pub max_hp: i32,
+    /// Compétence de classe débloquée par ce niveau
+    pub unlocked_skill: Option<String>,
+}
+
+/// Amélioration de stat choisie après une montée de niveau, déjà appliquée au joueur
+#[derive(Event, Debug, Clone)]
+pub struct UpgradeChosen {
+    pub bonus: Stats,
+}
+
+/// Objet lâché par un ennemi vaincu ; il est donné au joueur par `receive_loot`
+#[derive(Event, Debug, Clone)]
+pub struct LootDropped {
+    pub enemy_name: String,
+    pub item_type: ItemType,
+    pub rarity: Rarity,
+    pub affixes: Stats,
+}
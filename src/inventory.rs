@@ -0,0 +1,202 @@
+use crate::content::ItemDef;
+use crate::item::ItemType;
+use crate::loot::Rarity;
+use bevy::prelude::Component;
+use regles::consumable::ConsumableEffect;
+use regles::stats::Stats;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+/// Emplacement d'équipement d'un objet (champ `slot` de items.ron)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EquipmentSlot {
+    Weapon,
+    Armor,
+    Gloves,
+    Amulet,
+}
+
+impl EquipmentSlot {
+    /// Emplacements dans l'ordre d'affichage de l'inventaire
+    pub const ALL: [EquipmentSlot; 4] = [
+        EquipmentSlot::Weapon,
+        EquipmentSlot::Armor,
+        EquipmentSlot::Gloves,
+        EquipmentSlot::Amulet,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EquipmentSlot::Weapon => "Arme",
+            EquipmentSlot::Armor => "Armure",
+            EquipmentSlot::Gloves => "Gants",
+            EquipmentSlot::Amulet => "Amulette",
+        }
+    }
+}
+
+/// Pile de consommables identiques possédés par le joueur
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsumableStack {
+    pub item_type: ItemType,
+    /// Nom affiché
+    pub name: String,
+    pub effect: ConsumableEffect,
+    pub count: u32,
+}
+
+/// Objet d'équipement possédé par le joueur
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryItem {
+    /// Définition d'origine dans le ContentRegistry
+    pub item_type: ItemType,
+    /// Nom affiché
+    pub name: String,
+    pub slot: EquipmentSlot,
+    pub rarity: Rarity,
+    /// Bonus de stats apportés quand l'objet est équipé (stats de base + affixes)
+    pub stats: Stats,
+}
+
+impl InventoryItem {
+    /// Crée l'objet d'inventaire d'une définition, None pour un objet sans emplacement
+    pub fn from_def(item_def: &ItemDef, rarity: Rarity, affixes: Stats) -> Option<Self> {
+        let mut stats = item_def.stats;
+        stats += affixes;
+        Some(InventoryItem {
+            item_type: item_def.id.clone(),
+            name: item_def.name.clone(),
+            slot: item_def.slot?,
+            rarity,
+            stats,
+        })
+    }
+
+    /// Nom suivi de la rareté
+    pub fn display_name(&self) -> String {
+        self.rarity.item_name(&self.name)
+    }
+}
+
+/// Ligne de l'écran d'inventaire : un emplacement d'équipement, un objet du sac ou une pile de consommables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InventoryEntry {
+    Equipped(EquipmentSlot),
+    Bag(usize),
+    Consumable(usize),
+}
+
+/// Component Bevy : objets possédés par le joueur, équipés ou rangés dans le sac, et consommables
+/// Les stats finales du joueur sont ses stats de base plus `bonus()` (voir `Player::apply_equipment`)
+#[derive(Component, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Inventory {
+    /// Objets non équipés
+    pub bag: Vec<InventoryItem>,
+    pub equipped: HashMap<EquipmentSlot, InventoryItem>,
+    /// Consommables empilés par type, dans l'ordre d'obtention
+    pub consumables: Vec<ConsumableStack>,
+}
+
+impl Inventory {
+    /// Ajoute un objet : il est équipé directement si son emplacement est libre,
+    /// sinon rangé dans le sac. Retourne true si l'objet a été équipé
+    pub fn add(&mut self, item: InventoryItem) -> bool {
+        match self.equipped.entry(item.slot) {
+            Entry::Vacant(slot) => {
+                slot.insert(item);
+                true
+            }
+            Entry::Occupied(_) => {
+                self.bag.push(item);
+                false
+            }
+        }
+    }
+
+    /// Ajoute un consommable à sa pile (créée si besoin), retourne le nombre possédé
+    pub fn add_consumable(&mut self, item_def: &ItemDef, effect: ConsumableEffect) -> u32 {
+        match self
+            .consumables
+            .iter_mut()
+            .find(|stack| stack.item_type == item_def.id)
+        {
+            Some(stack) => {
+                stack.count += 1;
+                stack.count
+            }
+            None => {
+                self.consumables.push(ConsumableStack {
+                    item_type: item_def.id.clone(),
+                    name: item_def.name.clone(),
+                    effect,
+                    count: 1,
+                });
+                1
+            }
+        }
+    }
+
+    /// Retire un consommable de la pile `index` (la pile disparaît quand elle est vide)
+    /// Retourne le nom de l'objet et le nombre restant
+    pub fn consume(&mut self, index: usize) -> (String, u32) {
+        let stack = &mut self.consumables[index];
+        stack.count -= 1;
+        let used = (stack.name.clone(), stack.count);
+        if stack.count == 0 {
+            self.consumables.remove(index);
+        }
+        used
+    }
+
+    /// Somme des bonus des objets équipés
+    pub fn bonus(&self) -> Stats {
+        let mut bonus = Stats::default();
+        for item in self.equipped.values() {
+            bonus += item.stats;
+        }
+        bonus
+    }
+
+    /// Lignes de l'écran d'inventaire : les emplacements d'équipement, le sac puis les consommables
+    pub fn entries(&self) -> Vec<InventoryEntry> {
+        EquipmentSlot::ALL
+            .into_iter()
+            .map(InventoryEntry::Equipped)
+            .chain((0..self.bag.len()).map(InventoryEntry::Bag))
+            .chain((0..self.consumables.len()).map(InventoryEntry::Consumable))
+            .collect()
+    }
+
+    /// Équipement affiché sur une ligne de l'inventaire
+    /// (None pour un emplacement vide ou une ligne de consommable)
+    pub fn item(&self, entry: InventoryEntry) -> Option<&InventoryItem> {
+        match entry {
+            InventoryEntry::Equipped(slot) => self.equipped.get(&slot),
+            InventoryEntry::Bag(index) => self.bag.get(index),
+            InventoryEntry::Consumable(_) => None,
+        }
+    }
+
+    /// Équipe l'objet du sac (l'objet qu'il remplace retourne dans le sac)
+    /// ou déséquipe l'objet de l'emplacement. Retourne false si la ligne n'a pas d'équipement
+    pub fn toggle(&mut self, entry: InventoryEntry) -> bool {
+        match entry {
+            InventoryEntry::Equipped(slot) => match self.equipped.remove(&slot) {
+                Some(item) => {
+                    self.bag.push(item);
+                    true
+                }
+                None => false,
+            },
+            InventoryEntry::Bag(index) if index < self.bag.len() => {
+                let item = self.bag.remove(index);
+                if let Some(previous) = self.equipped.insert(item.slot, item) {
+                    self.bag.insert(index, previous);
+                }
+                true
+            }
+            InventoryEntry::Bag(_) | InventoryEntry::Consumable(_) => false,
+        }
+    }
+}
@@ -1,26 +1,22 @@
 use crate::assets::ImageAssets;
-use crate::components::Stats;
 use bevy::prelude::{Component, Handle, Image};
+use serde::{Deserialize, Serialize};
 
-/// Types d'objets collectables avec leurs bonus spécifiques
-/// Chaque objet améliore une statistique particulière
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum ItemType {
-    Armure,    // +50 HP (survie)
-    Katana,    // +5 SPD (esquive)
-    Gants,     // +10 ATK (dégâts)
-    Pendentif, // +10% CRIT (coups critiques)
-}
+/// Identifiant d'un type d'objet dans le ContentRegistry (ex: "Katana", "Armure")
+/// L'emplacement, les bonus de stats, le nom et le sprite sont définis dans assets/content/items.ron
+///
+/// Distribution sur les maps :
+/// - Map 1 : Katana (arme, +10 SPD), Armure (armure, +50 HP)
+/// - Map 2 : Gants (gants, +20 ATK), Pendentif (amulette, +15% CRIT), Potion
+/// - Map 3 : Potion
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ItemType(pub String);
 
 impl ItemType {
     /// Retourne le handle de l'image correspondant au type d'objet
     pub fn get_image_handle(&self, image_assets: &ImageAssets) -> Handle<Image> {
-        match self {
-            ItemType::Armure => image_assets.armor_item.clone(),
-            ItemType::Katana => image_assets.katana_item.clone(),
-            ItemType::Gants => image_assets.gloves_item.clone(),
-            ItemType::Pendentif => image_assets.pendant_item.clone(),
-        }
+        image_assets.items[self].clone()
     }
 }
 
@@ -30,37 +26,9 @@ pub struct Item {
     pub item_type: ItemType,
 }
 
-/// Retourne les bonus de stats pour chaque type d'objet
-/// Les bonus sont appliqués de manière permanente au joueur lors de la collecte
-///
-/// Distribution sur les maps :
-/// - Map 1 : Katana (+5 SPD), Armure (+50 HP)
-/// - Map 2 : Gants (+10 ATK), Pendentif (+10% CRIT)
-pub fn get_stats_for_item(item_type: ItemType) -> Stats {
-    match item_type {
-        ItemType::Armure => Stats {
-            hp: 50,
-            attack: 0,
-            speed: 0,
-            critical_chance: 0,
-        },
-        ItemType::Katana => Stats {
-            hp: 0,
-            attack: 0,
-            speed: 10,
-            critical_chance: 0,
-        },
-        ItemType::Gants => Stats {
-            hp: 0,
-            attack: 20,
-            speed: 0,
-            critical_chance: 0,
-        },
-        ItemType::Pendentif => Stats {
-            hp: 0,
-            attack: 0,
-            speed: 0,
-            critical_chance: 15,
-        },
-    }
+/// Component Bevy représentant un coffre sur la map, ouvert en marchant dessus
+#[derive(Component, Debug)]
+pub struct Chest {
+    /// Or contenu dans le coffre
+    pub gold: u32,
 }
@@ -0,0 +1,84 @@
+//! Jeu de rôle en vue de dessus (Bevy) : exploration de maps, combats tour par tour,
+//! inventaire et quêtes
+//!
+//! Le binaire `jeu_projet` ajoute la fenêtre ; les tests d'intégration (`tests/`) construisent
+//! la même application (`GamePlugin`) sans fenêtre
+
+use bevy::prelude::*;
+use std::path::Path;
+
+pub mod assets;
+pub mod components;
+pub mod config;
+pub mod content;
+pub mod controls;
+pub mod dungeon;
+pub mod enemy;
+pub mod events;
+pub mod inventory;
+pub mod item;
+pub mod loot;
+pub mod player;
+pub mod quest;
+pub mod resources;
+pub mod save;
+pub mod shop;
+pub mod simulation;
+pub mod states;
+pub mod systems;
+
+use config::{CONTENT_DIR, MAPS_DIR};
+use content::{ContentRegistry, DataLoadError};
+use dungeon::DungeonSeedChoice;
+use resources::{SaveDir, SaveSlot};
+use states::GameState;
+use systems::camera::CameraPlugin;
+use systems::class_selection::ClassSelectionPlugin;
+use systems::combat::CombatPlugin;
+use systems::controls::ControlsPlugin;
+use systems::inventory::InventoryPlugin;
+use systems::map::{self, MapPlugin};
+use systems::player::PlayerPlugin;
+use systems::progress::ProgressPlugin;
+use systems::save::SavePlugin;
+use systems::save_slots::SaveSlotsPlugin;
+use systems::shop::ShopPlugin;
+use systems::summary::SummaryPlugin;
+use systems::ui::UiPlugin;
+
+/// Charge le registre de contenu puis les maps qui le référencent
+pub fn load_data(assets_root: &Path) -> Result<(ContentRegistry, map::GameData), DataLoadError> {
+    let registry = ContentRegistry::load(&assets_root.join(CONTENT_DIR), assets_root)?;
+    let game_data = map::GameData::load(&assets_root.join(MAPS_DIR), &registry)?;
+    Ok((registry, game_data))
+}
+
+/// Jeu complet : état du jeu, emplacement de sauvegarde actif et plugins de chaque sous-système
+/// Les données (`ContentRegistry`, `GameData`, `KeyBindings`) et les plugins de Bevy sont
+/// fournis par l'application ; les tests ou d'autres binaires peuvent n'ajouter que certains
+/// des plugins de `systems`
+pub struct GamePlugin;
+
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SaveSlot>()
+            .init_resource::<SaveDir>()
+            .init_resource::<DungeonSeedChoice>()
+            .add_state::<GameState>()
+            .add_plugins((
+                CameraPlugin,
+                ProgressPlugin,
+                SaveSlotsPlugin,
+                ControlsPlugin,
+                ClassSelectionPlugin,
+                MapPlugin,
+                UiPlugin,
+                PlayerPlugin,
+                InventoryPlugin,
+                SavePlugin,
+                ShopPlugin,
+                CombatPlugin,
+                SummaryPlugin,
+            ));
+    }
+}
@@ -0,0 +1,117 @@
+use crate::item::ItemType;
+use bevy::prelude::Color;
+use rand::Rng;
+use regles::stats::Stats;
+use serde::{Deserialize, Serialize};
+
+/// Entrée de la table de butin d'un ennemi (champ `loot` de enemies.ron)
+/// Chaque entrée est tirée indépendamment à la victoire
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LootEntry {
+    pub item: ItemType,
+    /// Chance de lâcher l'objet en % (1-100)
+    pub chance: i32,
+}
+
+/// Rareté d'un équipement obtenu en butin
+/// Chaque rang au-dessus de Commun ajoute un bonus aléatoire (affixe) aux stats de base de l'objet
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Rarity {
+    /// Stats de base uniquement (objets posés sur les maps)
+    #[default]
+    Common,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+impl Rarity {
+    /// Raretés et poids de tirage (sur 100)
+    const WEIGHTS: [(Rarity, i32); 4] = [
+        (Rarity::Common, 60),
+        (Rarity::Rare, 25),
+        (Rarity::Epic, 12),
+        (Rarity::Legendary, 3),
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Rarity::Common => "Commun",
+            Rarity::Rare => "Rare",
+            Rarity::Epic => "Épique",
+            Rarity::Legendary => "Légendaire",
+        }
+    }
+
+    /// Nom d'objet suivi de la rareté, sauf pour les objets communs (ex: "Katana (Épique)")
+    pub fn item_name(&self, name: &str) -> String {
+        match self {
+            Rarity::Common => name.to_string(),
+            rarity => format!("{} ({})", name, rarity.label()),
+        }
+    }
+
+    /// Couleur du nom de l'objet dans l'inventaire
+    pub fn color(&self) -> Color {
+        match self {
+            Rarity::Common => Color::WHITE,
+            Rarity::Rare => Color::rgb(0.3, 0.6, 1.0),
+            Rarity::Epic => Color::rgb(0.7, 0.3, 0.9),
+            Rarity::Legendary => Color::rgb(1.0, 0.6, 0.1),
+        }
+    }
+
+    /// Nombre d'affixes aléatoires ajoutés aux stats de base
+    pub fn affix_count(&self) -> usize {
+        match self {
+            Rarity::Common => 0,
+            Rarity::Rare => 1,
+            Rarity::Epic => 2,
+            Rarity::Legendary => 3,
+        }
+    }
+
+    /// Valeur d'un objet de cette rareté, en % du prix de base de l'objet
+    pub fn value_percent(&self) -> u32 {
+        match self {
+            Rarity::Common => 100,
+            Rarity::Rare => 150,
+            Rarity::Epic => 200,
+            Rarity::Legendary => 300,
+        }
+    }
+
+    pub fn roll(rng: &mut impl Rng) -> Rarity {
+        let mut roll = rng.gen_range(0..100);
+        for (rarity, weight) in Rarity::WEIGHTS {
+            if roll < weight {
+                return rarity;
+            }
+            roll -= weight;
+        }
+        Rarity::Common
+    }
+}
+
+/// Tire les objets lâchés selon une table de butin
+pub fn roll_drops(loot: &[LootEntry], rng: &mut impl Rng) -> Vec<ItemType> {
+    loot.iter()
+        .filter(|entry| rng.gen_range(1..=100) <= entry.chance)
+        .map(|entry| entry.item.clone())
+        .collect()
+}
+
+/// Tire `count` affixes : chacun ajoute un bonus aléatoire à une stat au hasard
+pub fn roll_affixes(count: usize, rng: &mut impl Rng) -> Stats {
+    let mut affixes = Stats::default();
+    for _ in 0..count {
+        match rng.gen_range(0..4) {
+            0 => affixes.hp += rng.gen_range(10..=25),
+            1 => affixes.attack += rng.gen_range(3..=8),
+            2 => affixes.speed += rng.gen_range(2..=5),
+            _ => affixes.critical_chance += rng.gen_range(2..=5),
+        }
+    }
+    affixes
+}
@@ -1,22 +1,47 @@
-use bevy::{prelude::*, window::PresentMode};
+use bevy::{asset::io::file::FileAssetReader, prelude::*, window::PresentMode};
+use jeu_projet::config::{TERMINAL_WIDTH, TILE_SIZE};
+use jeu_projet::dungeon::{DUNGEON_SEED_FLAG, DungeonSeedChoice};
+use jeu_projet::{GamePlugin, controls, load_data, simulation};
 
-mod assets;
-mod components;
-mod config;
-mod enemy;
-mod item;
-mod player;
-mod resources;
-mod states;
-mod systems;
+fn main() {
+    // Les données sont lues depuis le même dossier assets/ que celui utilisé par l'AssetServer
+    let assets_root = FileAssetReader::get_base_path().join("assets");
+    let (registry, game_data) = match load_data(&assets_root) {
+        Ok(data) => data,
+        Err(err) => {
+            eprintln!("Erreur de chargement des données : {}", err);
+            std::process::exit(1);
+        }
+    };
 
-use config::{TERMINAL_WIDTH, TILE_SIZE};
-use resources::*;
-use states::GameState;
-use systems::{camera, class_selection, combat, map, player as player_systems, ui};
+    // Mode headless : simulation des combats pour l'équilibrage, sans ouvrir de fenêtre
+    let args: Vec<String> = std::env::args().collect();
+    match simulation::SimulationOptions::from_args(&args) {
+        Ok(Some(options)) => {
+            simulation::run(&registry, options);
+            return;
+        }
+        Ok(None) => {}
+        Err(err) => {
+            eprintln!("{}", err);
+            eprintln!("Usage : jeu_projet --simulate [--fights N] [--seed S]");
+            std::process::exit(2);
+        }
+    }
+
+    // Graine imposée pour rejouer un donjon partagé
+    let seed_choice = DungeonSeedChoice::from_args(&args).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        eprintln!("Usage : jeu_projet [{} N]", DUNGEON_SEED_FLAG);
+        std::process::exit(2);
+    });
+
+    // Touches de l'utilisateur ; un fichier invalide n'empêche pas de jouer
+    let bindings = controls::load_bindings().unwrap_or_else(|err| {
+        eprintln!("Touches par défaut utilisées : {}", err);
+        controls::KeyBindings::default()
+    });
 
-fn main() {
-    let game_data = map::GameData::new();
     let first_map = game_data.get_current_map();
     let window_width = first_map.width as f32 * TILE_SIZE;
     let window_height = first_map.height as f32 * TILE_SIZE;
@@ -33,53 +58,10 @@ fn main() {
             ..default()
         }))
         .insert_resource(ClearColor(Color::BLACK))
+        .insert_resource(registry)
         .insert_resource(game_data)
-        .insert_resource(CollectedItems::default())
-        .insert_resource(DefeatedEnemies::default())
-        .insert_resource(GameLog::default())
-        .add_state::<GameState>()
-        .add_systems(Startup, camera::setup_camera)
-        .add_systems(
-            OnEnter(GameState::ClassSelection),
-            (
-                assets::load_assets,
-                class_selection::setup_ui,
-                class_selection::reset_game_state,
-            ),
-        )
-        .add_systems(
-            Update,
-            class_selection::handle_input.run_if(in_state(GameState::ClassSelection)),
-        )
-        .add_systems(
-            OnExit(GameState::ClassSelection),
-            (class_selection::cleanup_ui, class_selection::spawn_player),
-        )
-        .add_systems(
-            OnEnter(GameState::Map),
-            (map::spawn_map, ui::setup_info_terminal),
-        )
-        .add_systems(
-            OnExit(GameState::Map),
-            (map::despawn_map, ui::cleanup_info_terminal),
-        )
-        .add_systems(OnEnter(GameState::MapTransition), map::map_transition)
-        .add_systems(
-            Update,
-            (
-                player_systems::move_player,
-                player_systems::update_transform,
-                player_systems::check_item_pickup,
-                player_systems::check_enemy_encounter,
-                ui::update_info_terminal,
-            )
-                .run_if(in_state(GameState::Map)),
-        )
-        .add_systems(OnEnter(GameState::Combat), combat::setup_combat)
-        .add_systems(
-            Update,
-            (combat::handle_combat, combat::update_health_bars).run_if(in_state(GameState::Combat)),
-        )
-        .add_systems(OnExit(GameState::Combat), combat::cleanup_combat)
+        .insert_resource(bindings)
+        .insert_resource(seed_choice)
+        .add_plugins(GamePlugin)
         .run();
 }
@@ -1,94 +1,102 @@
 use crate::assets::ImageAssets;
-use crate::components::{Position, Stats};
+use crate::components::Position;
+use crate::content::ContentRegistry;
+use crate::inventory::Inventory;
 use bevy::prelude::{Component, Handle, Image};
+use regles::progression::{healed_amount, level_ups, xp_to_next_level};
+use regles::stats::Stats;
+use serde::{Deserialize, Serialize};
 
-/// Les différentes classes jouables avec des profils de stats équilibrés
-/// Chaque classe a des forces et faiblesses uniques pour varier le gameplay
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum PlayerClass {
-    Warrior,     // Tanky avec HP élevés
-    Mage,        // Forte attaque mais fragile
-    Assassin,    // Très rapide avec esquive élevée
-    Executioner, // Spécialisé dans les coups critiques
-}
+/// Identifiant d'une classe jouable dans le ContentRegistry (ex: "Warrior", "Mage")
+/// Les stats de base, le nom et le sprite sont définis dans assets/content/classes.ron
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PlayerClass(pub String);
 
 impl PlayerClass {
     /// Retourne le handle de l'image correspondant à la classe
     /// Utilisé pour afficher le sprite du joueur
     pub fn get_image_handle(&self, image_assets: &ImageAssets) -> Handle<Image> {
-        match self {
-            PlayerClass::Warrior => image_assets.warrior_class.clone(),
-            PlayerClass::Mage => image_assets.mage_class.clone(),
-            PlayerClass::Assassin => image_assets.assassin_class.clone(),
-            PlayerClass::Executioner => image_assets.executioner_class.clone(),
-        }
+        image_assets.classes[self].clone()
     }
 }
 
 /// Component Bevy représentant le joueur
 /// Contient la classe choisie, les stats actuelles et la position logique
-#[derive(Component, Debug, Clone)]
+/// `stats.hp` est le maximum de PV, `current_hp` les PV restants, conservés entre les combats
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Player {
     pub class: PlayerClass,
+    /// Stats de la classe, niveaux et améliorations compris, sans l'équipement
+    pub base_stats: Stats,
+    /// Stats finales utilisées en jeu : `base_stats` + bonus de l'équipement
     pub stats: Stats,
+    pub current_hp: i32,
+    pub level: u32,
+    /// XP accumulée depuis le dernier niveau
+    pub xp: u32,
+    pub gold: u32,
     #[allow(dead_code)]
     pub position: Position,
 }
 
 impl Player {
-    /// Crée un nouveau joueur basé sur la classe choisie
-    ///
-    /// Stats de base par classe :
-    /// - Guerrier: HP 120, ATK 10, SPD 5, CRIT 10%
-    /// - Magicien: HP 90, ATK 15, SPD 3, CRIT 15%
-    /// - Assassin: HP 100, ATK 8, SPD 12, CRIT 15%
-    /// - Bourreau: HP 120, ATK 7, SPD 2, CRIT 25%
-    pub fn new(class: PlayerClass, start_position: Position) -> Self {
-        let stats = match class {
-            PlayerClass::Warrior => Stats {
-                hp: 120,
-                attack: 10,
-                speed: 5,
-                critical_chance: 10,
-            },
-            PlayerClass::Mage => Stats {
-                hp: 90,
-                attack: 15,
-                speed: 3,
-                critical_chance: 15,
-            },
-            PlayerClass::Assassin => Stats {
-                hp: 100,
-                attack: 8,
-                speed: 12,
-                critical_chance: 15,
-            },
-            PlayerClass::Executioner => Stats {
-                hp: 120,
-                attack: 7,
-                speed: 2,
-                critical_chance: 25,
-            },
-        };
+    /// Crée un nouveau joueur avec les stats de base de sa classe dans le registre
+    pub fn new(class: PlayerClass, registry: &ContentRegistry, start_position: Position) -> Self {
+        let stats = registry.class(&class).stats;
 
         Player {
             class,
+            current_hp: stats.hp,
+            base_stats: stats,
             stats,
+            level: 1,
+            xp: 0,
+            gold: 0,
             position: start_position,
         }
     }
 
-    /// Applique les bonus d'un objet collecté aux statistiques du joueur
-    /// Les bonus sont additifs et permanents
-    /// Affiche les nouvelles stats dans la console pour débogage
-    pub fn apply_item_stats(&mut self, item_stats: &Stats) {
-        self.stats.hp += item_stats.hp;
-        self.stats.attack += item_stats.attack;
-        self.stats.speed += item_stats.speed;
-        self.stats.critical_chance += item_stats.critical_chance;
-        println!(
-            "Stats mises à jour ! HP: {}, ATK: {}, SPD: {}, CRIT: {}%",
-            self.stats.hp, self.stats.attack, self.stats.speed, self.stats.critical_chance
-        );
+    /// Rend des PV sans dépasser le maximum, retourne les PV réellement rendus
+    pub fn heal(&mut self, amount: i32) -> i32 {
+        let healed = healed_amount(self.current_hp, self.stats.hp, amount);
+        self.current_hp += healed;
+        healed
+    }
+
+    /// XP nécessaire pour atteindre le niveau suivant
+    pub fn xp_to_next_level(&self) -> u32 {
+        xp_to_next_level(self.level)
+    }
+
+    /// Ajoute de l'XP et fait monter de niveau autant de fois que possible
+    /// Chaque niveau applique la croissance de la classe (`growth`) ; monter de niveau rend tous les PV
+    /// Retourne le nombre de niveaux gagnés
+    pub fn gain_xp(&mut self, amount: u32, growth: &Stats) -> u32 {
+        let (levels, xp) = level_ups(self.level, self.xp + amount);
+        self.xp = xp;
+        self.level += levels;
+        for _ in 0..levels {
+            self.add_base_stats(*growth);
+        }
+        if levels > 0 {
+            self.current_hp = self.stats.hp;
+        }
+        levels
+    }
+
+    /// Ajoute des stats de base (croissance, amélioration), reportées sur les stats finales
+    pub fn add_base_stats(&mut self, stats: Stats) {
+        self.base_stats += stats;
+        self.stats += stats;
+    }
+
+    /// Recalcule les stats finales à partir de l'équipement porté
+    /// Équiper ne soigne pas : les PV restants sont seulement ramenés sous le nouveau maximum
+    pub fn apply_equipment(&mut self, inventory: &Inventory) {
+        self.stats = self.base_stats;
+        self.stats += inventory.bonus();
+        self.current_hp = self.current_hp.min(self.stats.hp);
     }
 }
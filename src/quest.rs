@@ -0,0 +1,62 @@
+use crate::enemy::EnemyType;
+use crate::item::ItemType;
+use crate::resources::QuestLog;
+use serde::Deserialize;
+
+/// Objectif d'une quête (champ `objective` de quests.ron)
+#[derive(Debug, Clone, Deserialize)]
+pub enum Objective {
+    /// Vaincre `count` ennemis du type donné
+    Defeat { enemy: EnemyType, count: u32 },
+    /// Ramasser sur les maps au moins un exemplaire de chacun de ces objets
+    Collect(Vec<ItemType>),
+    /// Entrer dans la map (nom du fichier sans extension, comme les connexions)
+    ReachMap(String),
+}
+
+/// Récompense versée à la fin d'une quête (champ `reward`)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QuestReward {
+    #[serde(default)]
+    pub xp: u32,
+    #[serde(default)]
+    pub gold: u32,
+    /// Objets donnés au joueur, de rareté commune
+    #[serde(default)]
+    pub items: Vec<ItemType>,
+}
+
+/// Définition d'une quête (assets/content/quests.ron)
+/// Toutes les quêtes sont actives dès le début de la partie
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QuestDef {
+    pub id: String,
+    pub name: String,
+    pub objective: Objective,
+    #[serde(default)]
+    pub reward: QuestReward,
+}
+
+/// Condition de victoire de la partie (assets/content/victory.ron)
+#[derive(Debug, Clone, Deserialize)]
+pub enum VictoryCondition {
+    /// Vaincre au moins un ennemi de chacun de ces types (les boss)
+    DefeatAll(Vec<EnemyType>),
+    /// Terminer toutes ces quêtes (par id)
+    CompleteQuests(Vec<String>),
+}
+
+impl VictoryCondition {
+    pub fn is_met(&self, quest_log: &QuestLog) -> bool {
+        match self {
+            VictoryCondition::DefeatAll(enemies) => enemies
+                .iter()
+                .all(|enemy| quest_log.defeated.contains_key(enemy)),
+            VictoryCondition::CompleteQuests(quests) => quests
+                .iter()
+                .all(|quest| quest_log.completed.contains(quest)),
+        }
+    }
+}
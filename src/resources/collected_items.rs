@@ -1,12 +1,13 @@
 use crate::components::Position;
 use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
-/// Resource pour stocker les objets collectés par le joueur
+/// Resource pour stocker les objets collectés et les coffres ouverts par le joueur
 /// Utilise un HashSet pour éviter les doublons
-/// Stocke un tuple (index de la map, position de l'objet)
+/// Stocke un tuple (index de la map, position de l'objet ou du coffre)
 /// Permet de ne pas respawner les objets déjà collectés lors des transitions entre maps
-#[derive(Resource, Default)]
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
 pub struct CollectedItems {
     pub items: HashSet<(usize, Position)>,
 }
@@ -1,28 +1,135 @@
-use crate::components::{Position, Stats};
+use crate::components::Position;
+use crate::config::ENEMY_TURN_DELAY_SECS;
+use crate::controls::Action;
 use crate::enemy::EnemyType;
-use bevy::prelude::{Entity, Resource};
+use bevy::prelude::{Entity, Resource, Timer, TimerMode};
+use regles::combat::{Actor, Combat};
 
-/// Resource pour stocker l'ennemi actuellement en combat
-/// Conserve l'entité Bevy, la position, le type et les stats de l'ennemi
-/// Utilisée pour accéder aux données de l'ennemi pendant le combat
-#[derive(Resource)]
-pub struct CurrentEnemy {
+/// Ennemi engagé dans le combat en cours, vu par l'interface
+/// Conserve l'entité Bevy, la position et le type de l'ennemi ; son état de combat
+/// est dans `Combat::enemies`, au même index
+#[derive(Debug, Clone)]
+pub struct CombatEnemy {
     #[allow(dead_code)]
     pub entity: Entity,
     pub position: Position,
     pub enemy_type: EnemyType,
-    #[allow(dead_code)]
-    pub hp: i32,
-    pub stats: Stats,
+    /// Nom affiché, numéroté quand plusieurs ennemis du même type combattent ensemble
+    pub name: String,
 }
 
-/// Resource pour gérer l'état du combat tour par tour
-/// Contient les HP actuels des combattants, le log de combat et le tour actuel
-#[derive(Resource, Default)]
+/// Resource du combat tour par tour
+/// Les règles (`Combat` : PV, mana, effets, initiative, cible) sont résolues par le crate `regles` ;
+/// s'y ajoutent les ennemis vus par l'interface, la pause avant l'action ennemie, le log de combat
+/// et l'état du menu
+#[derive(Resource)]
 pub struct CombatState {
-    pub player_hp: i32,
-    pub enemy_hp: i32,
+    pub combat: Combat,
+    /// Ennemis engagés, un seul ou tout un groupe, dans l'ordre de `combat.enemies`
+    pub enemies: Vec<CombatEnemy>,
+    /// Ennemi en train de jouer et pause avant son action ; Some pendant le tour ennemi
+    pub enemy_turn: Option<(usize, Timer)>,
     #[allow(dead_code)]
     pub combat_log: Vec<String>,
     pub is_player_turn: bool,
+    /// Le joueur choisit le consommable à utiliser (après l'action Objet)
+    pub choosing_item: bool,
+    /// Améliorations de stat restant à choisir après la victoire (une par niveau gagné)
+    pub pending_upgrades: u32,
+    /// Tours joués par le joueur dans ce combat, pour le bilan de la partie
+    pub player_turns: u32,
+}
+
+impl CombatState {
+    /// Début de combat : attend l'action du premier combattant désigné par l'initiative
+    pub fn new(combat: Combat, enemies: Vec<CombatEnemy>) -> Self {
+        let first = combat.current_actor();
+        let mut state = CombatState {
+            combat,
+            enemies,
+            enemy_turn: None,
+            combat_log: Vec::new(),
+            is_player_turn: false,
+            choosing_item: false,
+            pending_upgrades: 0,
+            player_turns: 0,
+        };
+        if let Some(actor) = first {
+            state.begin_turn(actor);
+        }
+        state
+    }
+
+    /// Passe au tour du combattant : le joueur choisit une action, ou un ennemi agit après une pause
+    pub fn begin_turn(&mut self, actor: Actor) {
+        self.is_player_turn = actor == Actor::Player;
+        self.enemy_turn = match actor {
+            Actor::Player => None,
+            Actor::Enemy(index) => Some((
+                index,
+                Timer::from_seconds(ENEMY_TURN_DELAY_SECS, TimerMode::Once),
+            )),
+        };
+    }
+
+    /// Ennemis vaincus, sans ceux qui se sont enfuis
+    pub fn defeated(&self) -> impl Iterator<Item = &CombatEnemy> {
+        self.enemies
+            .iter()
+            .zip(&self.combat.enemies)
+            .filter(|(_, fighter)| !fighter.is_alive())
+            .map(|(enemy, _)| enemy)
+    }
+
+    /// Ennemis encore en jeu
+    pub fn fighting(&self) -> impl Iterator<Item = &CombatEnemy> {
+        self.enemies
+            .iter()
+            .zip(&self.combat.enemies)
+            .filter(|(_, fighter)| fighter.is_fighting())
+            .map(|(enemy, _)| enemy)
+    }
+}
+
+/// Entrées du menu d'actions de combat, dans l'ordre d'affichage
+/// Chaque action est accessible par une touche numérique (1 à 5) ou par un clic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombatAction {
+    Attack,
+    Defend,
+    Skill,
+    UseItem,
+    Flee,
+}
+
+impl CombatAction {
+    pub const ALL: [CombatAction; 5] = [
+        CombatAction::Attack,
+        CombatAction::Defend,
+        CombatAction::Skill,
+        CombatAction::UseItem,
+        CombatAction::Flee,
+    ];
+
+    /// Libellé affiché sur le bouton du menu
+    pub fn label(&self) -> &'static str {
+        match self {
+            CombatAction::Attack => "Attaquer",
+            CombatAction::Defend => "Défendre",
+            CombatAction::Skill => "Compétence",
+            CombatAction::UseItem => "Objet",
+            CombatAction::Flee => "Fuir",
+        }
+    }
+
+    /// Action clavier associée (touches 1 à 5 par défaut)
+    pub fn input_action(&self) -> Action {
+        match self {
+            CombatAction::Attack => Action::Attack,
+            CombatAction::Defend => Action::Defend,
+            CombatAction::Skill => Action::Skill,
+            CombatAction::UseItem => Action::UseItem,
+            CombatAction::Flee => Action::Flee,
+        }
+    }
 }
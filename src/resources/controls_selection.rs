@@ -0,0 +1,12 @@
+use bevy::prelude::Resource;
+
+/// Resource pour l'écran des touches, présente uniquement dans l'état Controls
+/// - index: action sélectionnée (dans `Action::ALL`)
+/// - waiting: la prochaine touche pressée remplace celles de l'action sélectionnée
+/// - message: résultat de la dernière modification
+#[derive(Resource, Default)]
+pub struct ControlsSelection {
+    pub index: usize,
+    pub waiting: bool,
+    pub message: String,
+}
@@ -1,8 +1,9 @@
 use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
 
 /// Resource pour stocker les messages de jeu affichés dans le terminal d'information
 /// Conserve un historique des 10 derniers messages pour éviter de surcharger l'UI
-#[derive(Resource, Default)]
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
 pub struct GameLog {
     pub messages: Vec<String>,
 }
@@ -0,0 +1,6 @@
+use bevy::prelude::Resource;
+
+/// Resource pour la ligne sélectionnée dans l'écran d'inventaire
+/// (index dans `Inventory::entries`), présente uniquement dans l'état Inventory
+#[derive(Resource, Default)]
+pub struct InventorySelection(pub usize);
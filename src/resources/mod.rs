@@ -1,11 +1,23 @@
 mod collected_items;
 mod combat_state;
+mod controls_selection;
 mod defeated_enemies;
 mod game_log;
+mod inventory_selection;
+mod quest_log;
+mod run_stats;
+mod save_slot;
 mod selected_class;
+mod shop_selection;
 
 pub use collected_items::CollectedItems;
-pub use combat_state::{CombatState, CurrentEnemy};
+pub use combat_state::{CombatAction, CombatEnemy, CombatState};
+pub use controls_selection::ControlsSelection;
 pub use defeated_enemies::DefeatedEnemies;
 pub use game_log::GameLog;
+pub use inventory_selection::InventorySelection;
+pub use quest_log::QuestLog;
+pub use run_stats::RunStats;
+pub use save_slot::{SaveDir, SaveSlot, SlotSelection};
 pub use selected_class::SelectedClass;
+pub use shop_selection::ShopSelection;
@@ -0,0 +1,59 @@
+use crate::enemy::EnemyType;
+use crate::item::ItemType;
+use crate::quest::{Objective, QuestDef};
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Resource pour suivre la progression des quêtes
+/// Alimentée par les écouteurs des événements de jeu, comme DefeatedEnemies (ennemis vaincus)
+/// et CollectedItems (objets ramassés), et lors des transitions entre maps
+/// Les quêtes terminées sont récompensées par `update_quests` sur la map
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+pub struct QuestLog {
+    /// Nombre d'ennemis vaincus par type
+    pub defeated: HashMap<EnemyType, u32>,
+    /// Types d'objets ramassés sur les maps
+    pub collected: HashSet<ItemType>,
+    /// Maps visitées (nom du fichier)
+    pub visited_maps: HashSet<String>,
+    /// Ids des quêtes terminées et déjà récompensées
+    pub completed: HashSet<String>,
+}
+
+impl QuestLog {
+    pub fn record_defeat(&mut self, enemy_type: &EnemyType) {
+        *self.defeated.entry(enemy_type.clone()).or_default() += 1;
+    }
+
+    pub fn record_item(&mut self, item_type: &ItemType) {
+        self.collected.insert(item_type.clone());
+    }
+
+    pub fn record_map(&mut self, name: &str) {
+        self.visited_maps.insert(name.to_string());
+    }
+
+    /// Progression d'une quête : (avancement, objectif)
+    pub fn progress(&self, quest: &QuestDef) -> (u32, u32) {
+        match &quest.objective {
+            Objective::Defeat { enemy, count } => {
+                let defeated = self.defeated.get(enemy).copied().unwrap_or(0);
+                (defeated.min(*count), *count)
+            }
+            Objective::Collect(items) => {
+                let collected = items
+                    .iter()
+                    .filter(|item| self.collected.contains(*item))
+                    .count();
+                (collected as u32, items.len() as u32)
+            }
+            Objective::ReachMap(name) => (self.visited_maps.contains(name) as u32, 1),
+        }
+    }
+
+    pub fn is_done(&self, quest: &QuestDef) -> bool {
+        let (current, target) = self.progress(quest);
+        current >= target
+    }
+}
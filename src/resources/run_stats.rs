@@ -0,0 +1,27 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Resource pour le bilan de la partie, affiché à la victoire ou à la défaite
+/// Les ennemis vaincus sont comptés par DefeatedEnemies
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+pub struct RunStats {
+    /// Temps écoulé (horloge Bevy) au début et à la fin de la partie
+    /// Non sauvegardés : le temps de jeu est sauvegardé à part (voir `SaveData`)
+    #[serde(skip)]
+    pub started_at: Duration,
+    #[serde(skip)]
+    pub finished_at: Duration,
+    /// Tours joués par le joueur en combat
+    pub turns: u32,
+    /// Dégâts infligés aux ennemis, effets de statut compris
+    pub damage_dealt: i32,
+    /// Objets ramassés sur les maps
+    pub items_collected: u32,
+}
+
+impl RunStats {
+    pub fn play_time(&self) -> Duration {
+        self.finished_at.saturating_sub(self.started_at)
+    }
+}
@@ -0,0 +1,38 @@
+use crate::save::{SaveError, save_dir};
+use bevy::prelude::Resource;
+use std::path::PathBuf;
+
+/// Resource pour l'emplacement de sauvegarde de la partie en cours (0 = premier emplacement)
+/// Choisi sur l'écran des sauvegardes, utilisé par F5 / F9 et la sauvegarde automatique
+#[derive(Resource, Default)]
+pub struct SaveSlot(pub usize);
+
+/// Resource pour le dossier des emplacements de sauvegarde (`save::save_dir` par défaut)
+/// Les tests d'intégration donnent à chaque application son propre dossier
+#[derive(Resource, Clone)]
+pub struct SaveDir(pub Option<PathBuf>);
+
+impl Default for SaveDir {
+    fn default() -> Self {
+        SaveDir(save_dir())
+    }
+}
+
+impl SaveDir {
+    /// Chemin du fichier de l'emplacement de sauvegarde `slot` (0 = premier emplacement)
+    pub fn slot_path(&self, slot: usize) -> Result<PathBuf, SaveError> {
+        self.0
+            .as_ref()
+            .map(|dir| dir.join(format!("emplacement_{}.ron", slot + 1)))
+            .ok_or(SaveError::NoSaveDir)
+    }
+}
+
+/// Resource pour l'écran des sauvegardes, présente uniquement dans l'état SaveSlots
+/// - index: emplacement sélectionné (< SAVE_SLOTS)
+/// - message: résultat de la dernière action (suppression, chargement raté)
+#[derive(Resource, Default)]
+pub struct SlotSelection {
+    pub index: usize,
+    pub message: String,
+}
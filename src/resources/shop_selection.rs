@@ -0,0 +1,10 @@
+use bevy::prelude::Resource;
+
+/// Resource pour l'écran du marchand, présente uniquement dans l'état Shop
+/// - index: ligne sélectionnée (dans `shop::shop_entries`)
+/// - message: résultat du dernier achat ou de la dernière vente
+#[derive(Resource, Default)]
+pub struct ShopSelection {
+    pub index: usize,
+    pub message: String,
+}
@@ -0,0 +1,254 @@
+use crate::components::Position;
+use crate::content::{ContentRegistry, DataLoadError, parse_ron, read_file};
+use crate::inventory::Inventory;
+use crate::player::Player;
+use crate::resources::{CollectedItems, DefeatedEnemies, GameLog, QuestLog, RunStats};
+use crate::systems::map::GameData;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Version du format de sauvegarde, à incrémenter à chaque changement de `SaveData`
+/// Une sauvegarde d'une autre version est refusée plutôt que mal interprétée
+pub const SAVE_VERSION: u32 = 3;
+
+/// Nom du dossier du jeu dans le dossier de données de l'utilisateur
+const SAVE_DIR_NAME: &str = "jeu_projet";
+
+/// Contenu d'un fichier de sauvegarde (RON)
+/// Les stats finales du joueur sont recalculées au chargement à partir de ses stats de base
+/// et de son équipement
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SaveData {
+    pub version: u32,
+    pub player: Player,
+    pub inventory: Inventory,
+    pub map_index: usize,
+    pub position: Position,
+    /// Graine du donjon de la partie : ses étages sont régénérés au chargement
+    pub dungeon_seed: u64,
+    pub collected_items: CollectedItems,
+    pub defeated_enemies: DefeatedEnemies,
+    pub game_log: GameLog,
+    pub quest_log: QuestLog,
+    pub run_stats: RunStats,
+    /// Temps de jeu au moment de la sauvegarde, en secondes
+    pub play_time_secs: u64,
+    /// Date de la sauvegarde (secondes depuis le 1er janvier 1970, UTC)
+    pub saved_at: u64,
+}
+
+/// En-tête lu avant le reste du fichier, pour refuser proprement une autre version du format
+#[derive(Deserialize)]
+struct SaveHeader {
+    version: u32,
+}
+
+/// Erreur de sauvegarde ou de chargement, affichée au joueur dans le log
+#[derive(Debug)]
+pub enum SaveError {
+    /// Dossier de données utilisateur introuvable (variables d'environnement absentes)
+    NoSaveDir,
+    /// Aucune sauvegarde à charger
+    Missing { path: PathBuf },
+    /// Sauvegarde écrite par une autre version du jeu
+    Version { path: PathBuf, found: u32 },
+    /// Fichier illisible, corrompu ou incompatible avec les données du jeu
+    Data(DataLoadError),
+    /// Écriture impossible
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::NoSaveDir => write!(f, "dossier de sauvegarde introuvable"),
+            SaveError::Missing { path } => {
+                write!(f, "aucune sauvegarde ({})", path.display())
+            }
+            SaveError::Version { path, found } => write!(
+                f,
+                "{}: sauvegarde en version {} non supportée (version attendue : {})",
+                path.display(),
+                found,
+                SAVE_VERSION
+            ),
+            SaveError::Data(err) => write!(f, "sauvegarde corrompue : {}", err),
+            SaveError::Write { path, source } => {
+                write!(f, "{}: écriture impossible ({})", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<DataLoadError> for SaveError {
+    fn from(err: DataLoadError) -> Self {
+        SaveError::Data(err)
+    }
+}
+
+/// Dossier de données de l'utilisateur pour le jeu :
+/// `$XDG_DATA_HOME` ou `~/.local/share` sous Linux, `%APPDATA%` sous Windows,
+/// `~/Library/Application Support` sous macOS
+pub fn save_dir() -> Option<PathBuf> {
+    let env_dir = |name: &str| {
+        std::env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    };
+    let data_dir = if cfg!(target_os = "windows") {
+        env_dir("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        env_dir("HOME").map(|home| home.join("Library/Application Support"))
+    } else {
+        env_dir("XDG_DATA_HOME").or_else(|| env_dir("HOME").map(|home| home.join(".local/share")))
+    };
+    data_dir.map(|dir| dir.join(SAVE_DIR_NAME))
+}
+
+/// Supprime la sauvegarde d'un emplacement (sans erreur s'il est déjà vide)
+pub fn delete_save(path: &Path) -> Result<(), SaveError> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(SaveError::Write {
+            path: path.to_path_buf(),
+            source: err,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Date actuelle pour `SaveData::saved_at`
+pub fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+/// Formate une date `saved_at` en "AAAA-MM-JJ HH:MM" (UTC)
+pub fn format_date(secs: u64) -> String {
+    // Conversion jours -> date du calendrier grégorien (algorithme de H. Hinnant)
+    let days = (secs / 86_400) as i64;
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    let minutes = secs % 86_400 / 60;
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        minutes / 60,
+        minutes % 60
+    )
+}
+
+/// Formate un temps de jeu en "H:MM:SS"
+pub fn format_play_time(secs: u64) -> String {
+    format!("{}:{:02}:{:02}", secs / 3600, secs % 3600 / 60, secs % 60)
+}
+
+/// Écrit un fichier RON du dossier de données (sauvegarde ou touches), en créant le dossier
+/// si besoin
+pub fn write_save<T: Serialize>(path: &Path, save: &T) -> Result<(), SaveError> {
+    let write_error = |source| SaveError::Write {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(write_error)?;
+    }
+    let content = ron::ser::to_string_pretty(save, ron::ser::PrettyConfig::default())
+        .map_err(|err| write_error(std::io::Error::other(err)))?;
+    fs::write(path, content).map_err(write_error)
+}
+
+/// Lit une sauvegarde et vérifie sa version puis sa cohérence avec les données du jeu
+/// (classe, objets, map et position doivent toujours exister)
+pub fn read_save(
+    path: &Path,
+    registry: &ContentRegistry,
+    game_data: &GameData,
+) -> Result<SaveData, SaveError> {
+    if !path.is_file() {
+        return Err(SaveError::Missing {
+            path: path.to_path_buf(),
+        });
+    }
+    let source = read_file(path)?;
+
+    let header: SaveHeader = parse_ron(path, &source)?;
+    if header.version != SAVE_VERSION {
+        return Err(SaveError::Version {
+            path: path.to_path_buf(),
+            found: header.version,
+        });
+    }
+
+    let save: SaveData = parse_ron(path, &source)?;
+    if let Some(message) = save_error(&save, registry, game_data) {
+        return Err(SaveError::Data(DataLoadError::Invalid {
+            path: path.to_path_buf(),
+            message,
+        }));
+    }
+    Ok(save)
+}
+
+/// Vérifie qu'une sauvegarde référence des classes, objets et maps qui existent encore
+fn save_error(save: &SaveData, registry: &ContentRegistry, game_data: &GameData) -> Option<String> {
+    if !registry
+        .classes
+        .iter()
+        .any(|class_def| class_def.id == save.player.class)
+    {
+        return Some(format!("classe inconnue \"{}\"", save.player.class.0));
+    }
+
+    let item_types = save
+        .inventory
+        .bag
+        .iter()
+        .chain(save.inventory.equipped.values())
+        .map(|item| &item.item_type)
+        .chain(
+            save.inventory
+                .consumables
+                .iter()
+                .map(|stack| &stack.item_type),
+        );
+    for item_type in item_types {
+        if !registry.has_item(item_type) {
+            return Some(format!("objet inconnu \"{}\"", item_type.0));
+        }
+    }
+
+    let Some(map) = game_data.map_with_seed(save.map_index, save.dungeon_seed) else {
+        return Some(format!("map n°{} inexistante", save.map_index));
+    };
+    if !map.is_walkable(save.position.x, save.position.y) {
+        return Some(format!(
+            "position ({}, {}) non praticable sur {}",
+            save.position.x, save.position.y, map.name
+        ));
+    }
+    None
+}
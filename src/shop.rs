@@ -0,0 +1,26 @@
+use crate::config::SELL_PRICE_PERCENT;
+use crate::content::ContentRegistry;
+use crate::inventory::{Inventory, InventoryItem};
+use crate::item::ItemType;
+
+/// Ligne de l'écran du marchand : un objet du stock à acheter ou un objet du sac à vendre
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShopEntry {
+    Buy(usize),
+    Sell(usize),
+}
+
+/// Lignes de l'écran du marchand : le stock de la map puis le sac du joueur
+/// Seuls les objets non équipés peuvent être vendus
+pub fn shop_entries(stock: &[ItemType], inventory: &Inventory) -> Vec<ShopEntry> {
+    (0..stock.len())
+        .map(ShopEntry::Buy)
+        .chain((0..inventory.bag.len()).map(ShopEntry::Sell))
+        .collect()
+}
+
+/// Prix de revente d'un objet : une part de sa valeur, plus élevée pour les objets rares
+pub fn sell_price(item: &InventoryItem, registry: &ContentRegistry) -> u32 {
+    let value = registry.item(&item.item_type).price * item.rarity.value_percent() / 100;
+    value * SELL_PRICE_PERCENT / 100
+}
@@ -0,0 +1,179 @@
+use crate::content::{ClassDef, ContentRegistry, EnemyDef};
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use regles::combat::{Actor, Combat, PlayerAction, TurnAction};
+use regles::skill::SkillEffect;
+
+/// Nombre de combats simulés par paire classe × ennemi par défaut
+const DEFAULT_FIGHTS: u32 = 1000;
+
+/// Graine par défaut, pour que deux lancements sans option donnent le même tableau
+const DEFAULT_SEED: u64 = 42;
+
+/// Nombre maximal de tours avant de déclarer un combat nul (ex: deux combattants à 100% d'esquive)
+const MAX_TURNS: u32 = 1000;
+
+/// Options du mode `--simulate`, lues depuis la ligne de commande
+#[derive(Debug, Clone, Copy)]
+pub struct SimulationOptions {
+    pub fights: u32,
+    pub seed: u64,
+}
+
+impl SimulationOptions {
+    /// Lit `--simulate [--fights N] [--seed S]` dans les arguments
+    /// Retourne None si `--simulate` est absent (lancement normal du jeu)
+    pub fn from_args(args: &[String]) -> Result<Option<Self>, String> {
+        if !args.iter().any(|arg| arg == "--simulate") {
+            return Ok(None);
+        }
+
+        let mut options = SimulationOptions {
+            fights: DEFAULT_FIGHTS,
+            seed: DEFAULT_SEED,
+        };
+        let mut iter = args.iter().skip(1);
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--simulate" => {}
+                "--fights" => options.fights = parse_value(arg, iter.next())?,
+                "--seed" => options.seed = parse_value(arg, iter.next())?,
+                other => return Err(format!("option inconnue: {}", other)),
+            }
+        }
+
+        if options.fights == 0 {
+            return Err("--fights doit être supérieur à 0".to_string());
+        }
+        Ok(Some(options))
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: Option<&String>) -> Result<T, String> {
+    value
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| format!("{} attend un nombre", flag))
+}
+
+/// Statistiques agrégées des combats d'une paire classe × ennemi
+#[derive(Debug, Default)]
+struct MatchupResult {
+    wins: u32,
+    total_turns: u32,
+    total_hp_left_on_win: i32,
+}
+
+/// Issue d'un combat simulé
+struct FightOutcome {
+    player_won: bool,
+    turns: u32,
+    player_hp_left: i32,
+}
+
+/// Stratégie du joueur simulé : lance sa compétence dès qu'elle est prête, sinon attaque
+/// Exécution est gardée pour quand l'ennemi passe sous le seuil, où elle fait la différence
+fn choose_action(combat: &Combat) -> PlayerAction {
+    let player = &combat.player;
+    let enemy = &combat.enemies[combat.target];
+    let worth_it = match player.skill.effect {
+        SkillEffect::Execute {
+            threshold_percent, ..
+        } => enemy.hp * 100 <= enemy.stats.hp * threshold_percent,
+        _ => true,
+    };
+
+    if worth_it && player.is_skill_ready() {
+        PlayerAction::Skill
+    } else {
+        PlayerAction::Attack
+    }
+}
+
+/// Simule un combat complet avec les mêmes règles que handle_combat :
+/// l'initiative désigne qui joue, le joueur suit `choose_action` jusqu'à la mort d'un des
+/// combattants ou la fuite de l'ennemi (comptée comme une victoire), sans choix d'amélioration
+/// Le nombre de tours compté est celui des tours du joueur
+fn simulate_fight(class_def: &ClassDef, enemy_def: &EnemyDef, seed: u64) -> FightOutcome {
+    let player = class_def.fighter(class_def.stats, class_def.stats.hp, 1);
+    let mut combat = Combat::new(player, vec![enemy_def.fighter(enemy_def.stats)], seed);
+    let mut turns = 0;
+
+    while let Some(actor) = combat.current_actor()
+        && turns < MAX_TURNS
+    {
+        let action = match actor {
+            Actor::Player => {
+                turns += 1;
+                TurnAction::Player(choose_action(&combat))
+            }
+            Actor::Enemy(_) => TurnAction::Enemy,
+        };
+        // La stratégie ne choisit la compétence que lorsqu'elle est prête
+        if combat.apply(action).is_err() {
+            break;
+        }
+    }
+
+    FightOutcome {
+        player_won: combat.is_won(),
+        turns,
+        player_hp_left: combat.player.hp.max(0),
+    }
+}
+
+/// Lance `fights` combats pour chaque classe × ennemi du registre et affiche le tableau des résultats
+/// Les joueurs combattent avec les stats de base de leur classe (sans objets ni améliorations)
+///
+/// Chaque paire utilise sa propre graine dérivée de `seed`, de sorte qu'ajouter un ennemi
+/// ou une classe ne change pas les résultats des autres paires ; chaque combat de la paire
+/// reçoit ensuite sa graine du générateur de la paire
+pub fn run(registry: &ContentRegistry, options: SimulationOptions) {
+    println!(
+        "Simulation : {} combats par paire, graine {}\n",
+        options.fights, options.seed
+    );
+    println!(
+        "{:<14} {:<20} {:>10} {:>12} {:>20}",
+        "Classe", "Ennemi", "Victoires", "Tours moy.", "PV restants (moy.)"
+    );
+    println!("{}", "-".repeat(80));
+
+    for (class_index, class_def) in registry.classes.iter().enumerate() {
+        for (enemy_index, enemy_def) in registry.enemies.iter().enumerate() {
+            let pair_seed = options
+                .seed
+                .wrapping_add((class_index as u64) << 32)
+                .wrapping_add(enemy_index as u64);
+            let mut rng = StdRng::seed_from_u64(pair_seed);
+
+            let mut result = MatchupResult::default();
+            for _ in 0..options.fights {
+                let outcome = simulate_fight(class_def, enemy_def, rng.next_u64());
+                result.total_turns += outcome.turns;
+                if outcome.player_won {
+                    result.wins += 1;
+                    result.total_hp_left_on_win += outcome.player_hp_left;
+                }
+            }
+
+            let win_rate = result.wins as f32 / options.fights as f32 * 100.0;
+            let avg_turns = result.total_turns as f32 / options.fights as f32;
+            // Moyenne calculée sur les victoires uniquement (0 PV restants après une défaite)
+            let avg_hp_left = if result.wins > 0 {
+                format!(
+                    "{:.1} / {}",
+                    result.total_hp_left_on_win as f32 / result.wins as f32,
+                    class_def.stats.hp
+                )
+            } else {
+                "-".to_string()
+            };
+
+            println!(
+                "{:<14} {:<20} {:>9.1}% {:>12.1} {:>20}",
+                class_def.name, enemy_def.name, win_rate, avg_turns, avg_hp_left
+            );
+        }
+        println!();
+    }
+}
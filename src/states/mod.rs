@@ -1,15 +1,27 @@
 use bevy::prelude::States;
 
 /// États du jeu utilisés pour gérer le flow de l'application
+/// - SaveSlots: Écran des emplacements de sauvegarde, au lancement du jeu
+/// - Controls: Écran des touches, ouvert depuis l'écran des sauvegardes
 /// - ClassSelection: Écran de sélection de la classe du joueur
 /// - Map: Mode exploration où le joueur se déplace sur la carte
 /// - Combat: Mode combat tour par tour contre un ennemi
 /// - MapTransition: État temporaire pour changer de carte
+/// - Inventory: Écran d'inventaire ouvert depuis la map (touche I)
+/// - Shop: Boutique du marchand de la map
+/// - Victory: Bilan de la partie gagnée (condition de victoire.ron remplie)
+/// - Defeat: Bilan de la partie perdue
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
 pub enum GameState {
     #[default]
+    SaveSlots,
+    Controls,
     ClassSelection,
     Map,
     Combat,
     MapTransition,
+    Inventory,
+    Shop,
+    Victory,
+    Defeat,
 }
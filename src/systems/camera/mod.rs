@@ -18,3 +18,12 @@ pub fn setup_camera(mut commands: Commands, game_data: Res<GameData>) {
 
     commands.spawn(camera);
 }
+
+/// Caméra 2D du jeu
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_camera);
+    }
+}
@@ -1,9 +1,12 @@
 use crate::assets::ImageAssets;
-use crate::components::ClassSelectionUI;
+use crate::components::{ClassSelectionUI, Position, PreviousPosition};
 use crate::config::{TERMINAL_WIDTH, TILE_SIZE};
+use crate::content::ContentRegistry;
+use crate::dungeon::DungeonSeedChoice;
+use crate::inventory::Inventory;
 use crate::player::Player;
-use crate::resources::SelectedClass;
-use crate::systems::map::GameData;
+use crate::resources::{RunStats, SelectedClass};
+use crate::systems::map::{GameData, Map};
 use bevy::prelude::*;
 
 pub fn cleanup_ui(mut commands: Commands, ui_query: Query<Entity, With<ClassSelectionUI>>) {
@@ -13,26 +16,61 @@ pub fn cleanup_ui(mut commands: Commands, ui_query: Query<Entity, With<ClassSele
 }
 
 /// Crée l'entité joueur avec la classe sélectionnée et le positionne sur la map
+/// Génère le donjon de la nouvelle partie avec sa graine (voir `DungeonSeedChoice`)
 /// Appelé lors de la sortie de l'état ClassSelection
-/// La ressource SelectedClass est supprimée après utilisation
+/// La ressource SelectedClass est supprimée après utilisation ; sans elle (retour à l'écran
+/// des sauvegardes), aucun joueur n'est créé
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_player(
     mut commands: Commands,
-    selected_class: Res<SelectedClass>,
-    game_data: Res<GameData>,
+    selected_class: Option<Res<SelectedClass>>,
+    mut game_data: ResMut<GameData>,
+    seed_choice: Res<DungeonSeedChoice>,
     image_assets: Res<ImageAssets>,
+    registry: Res<ContentRegistry>,
+    time: Res<Time>,
+    mut run_stats: ResMut<RunStats>,
 ) {
+    let Some(selected_class) = selected_class else {
+        return;
+    };
+
+    // Début du temps de jeu affiché dans le bilan
+    run_stats.started_at = time.elapsed();
+    game_data.set_dungeon_seed(seed_choice.new_game_seed());
+
     let game_map = game_data.get_current_map();
-    let player_class = selected_class.0;
-    let player_data = Player::new(player_class, game_map.player_start);
-    let player_texture = player_data.class.get_image_handle(&image_assets);
+    let player_data = Player::new(selected_class.0.clone(), &registry, game_map.player_start);
+    spawn_player_entity(
+        &mut commands,
+        player_data,
+        Inventory::default(),
+        game_map.player_start,
+        game_map,
+        &image_assets,
+    );
+
+    commands.remove_resource::<SelectedClass>();
+}
+
+/// Crée l'entité joueur (sprite, stats, inventaire) à sa position sur la map
+/// Utilisé pour une nouvelle partie et au chargement d'une sauvegarde
+pub fn spawn_player_entity(
+    commands: &mut Commands,
+    player: Player,
+    inventory: Inventory,
+    position: Position,
+    game_map: &Map,
+    image_assets: &ImageAssets,
+) {
+    let player_texture = player.class.get_image_handle(image_assets);
 
     // Offset pour décaler le joueur vers la gauche et éviter le chevauchement avec le terminal
     let x_offset = -TERMINAL_WIDTH / 2.0;
 
     let player_translation = Vec2::new(
-        game_map.player_start.x as f32 * TILE_SIZE - (game_map.width as f32 * TILE_SIZE / 2.0)
-            + x_offset,
-        game_map.player_start.y as f32 * TILE_SIZE - (game_map.height as f32 * TILE_SIZE / 2.0),
+        position.x as f32 * TILE_SIZE - (game_map.width as f32 * TILE_SIZE / 2.0) + x_offset,
+        position.y as f32 * TILE_SIZE - (game_map.height as f32 * TILE_SIZE / 2.0),
     );
 
     commands.spawn((
@@ -45,9 +83,9 @@ pub fn spawn_player(
             },
             ..default()
         },
-        player_data,
-        game_map.player_start,
+        player,
+        inventory,
+        position,
+        PreviousPosition(position),
     ));
-
-    commands.remove_resource::<SelectedClass>();
 }
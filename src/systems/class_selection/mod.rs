@@ -3,7 +3,27 @@ mod input;
 mod reset;
 mod setup;
 
-pub use cleanup::{cleanup_ui, spawn_player};
+use crate::states::GameState;
+use bevy::prelude::*;
+
+pub use cleanup::{cleanup_ui, spawn_player, spawn_player_entity};
 pub use input::handle_input;
 pub use reset::reset_game_state;
 pub use setup::setup_ui;
+
+/// Écran de sélection de la classe, puis création du joueur en le quittant
+pub struct ClassSelectionPlugin;
+
+impl Plugin for ClassSelectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::ClassSelection), setup_ui)
+            .add_systems(
+                Update,
+                handle_input.run_if(in_state(GameState::ClassSelection)),
+            )
+            .add_systems(
+                OnExit(GameState::ClassSelection),
+                (cleanup_ui, spawn_player),
+            );
+    }
+}
@@ -4,14 +4,17 @@ use crate::player::Player;
 use crate::resources::*;
 use crate::systems::map::GameData;
 
-/// Système exécuté quand on retourne à ClassSelection (typiquement après la mort)
+/// Système exécuté à l'entrée de l'écran des sauvegardes (lancement, fin de partie)
 /// Nettoie complètement l'état de jeu : despawn le joueur + réinitialise les ressources
+#[allow(clippy::too_many_arguments)]
 pub fn reset_game_state(
     mut commands: Commands,
     player_query: Query<Entity, With<Player>>,
     mut game_data: ResMut<GameData>,
     mut collected_items: ResMut<CollectedItems>,
     mut defeated_enemies: ResMut<DefeatedEnemies>,
+    mut quest_log: ResMut<QuestLog>,
+    mut run_stats: ResMut<RunStats>,
     mut game_log: ResMut<GameLog>,
 ) {
     // Despawn toutes les entités Player existantes pour éviter les duplications
@@ -25,6 +28,8 @@ pub fn reset_game_state(
     // Vider les ressources de progression
     *collected_items = CollectedItems::default();
     *defeated_enemies = DefeatedEnemies::default();
+    *quest_log = QuestLog::default();
+    *run_stats = RunStats::default();
     *game_log = GameLog::default();
 
     info!("État de jeu réinitialisé : Player despawn, resources reset");
@@ -1,8 +1,14 @@
 use crate::components::{ClassButton, ClassSelectionUI};
-use crate::player::PlayerClass;
+use crate::content::{ClassDef, ContentRegistry};
+use crate::controls::{Action, KeyBindings};
 use bevy::prelude::*;
 
-pub fn setup_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn setup_ui(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    registry: Res<ContentRegistry>,
+    bindings: Res<KeyBindings>,
+) {
     // Root node
     commands
         .spawn((
@@ -46,37 +52,32 @@ pub fn setup_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ..default()
                 })
                 .with_children(|parent| {
-                    spawn_class_button(
-                        parent,
-                        &asset_server,
-                        PlayerClass::Warrior,
-                        "Guerrier",
-                        "1",
-                    );
-                    spawn_class_button(parent, &asset_server, PlayerClass::Mage, "Magicien", "2");
-                    spawn_class_button(
-                        parent,
-                        &asset_server,
-                        PlayerClass::Assassin,
-                        "Assassin",
-                        "3",
-                    );
-                    spawn_class_button(
-                        parent,
-                        &asset_server,
-                        PlayerClass::Executioner,
-                        "Bourreau",
-                        "4",
-                    );
+                    // Une action Classe N par classe, dans l'ordre du registre
+                    for (action, class_def) in Action::CLASS_SLOTS.iter().zip(&registry.classes) {
+                        spawn_class_button(
+                            parent,
+                            &asset_server,
+                            class_def,
+                            &bindings.hint(*action),
+                        );
+                    }
                 });
+
+            parent.spawn(TextBundle::from_section(
+                format!("{} : retour aux sauvegardes", bindings.hint(Action::Cancel)),
+                TextStyle {
+                    font_size: 20.0,
+                    color: Color::GRAY,
+                    ..default()
+                },
+            ));
         });
 }
 
 fn spawn_class_button(
     parent: &mut ChildBuilder,
     asset_server: &Res<AssetServer>,
-    class_type: PlayerClass,
-    name: &str,
+    class_def: &ClassDef,
     key_hint: &str,
 ) {
     parent
@@ -94,7 +95,7 @@ fn spawn_class_button(
                 background_color: Color::NONE.into(),
                 ..default()
             },
-            ClassButton(class_type),
+            ClassButton(class_def.id.clone()),
         ))
         .with_children(|parent| {
             parent.spawn(ImageBundle {
@@ -103,11 +104,11 @@ fn spawn_class_button(
                     height: Val::Px(100.0),
                     ..default()
                 },
-                image: UiImage::new(asset_server.load(&format!("images/Classe/{}.jpg", name))),
+                image: UiImage::new(asset_server.load(&class_def.sprite)),
                 ..default()
             });
             parent.spawn(TextBundle::from_section(
-                name,
+                &class_def.name,
                 TextStyle {
                     font: asset_server.load("fonts/FiraSans-Bold.ttf"),
                     font_size: 20.0,
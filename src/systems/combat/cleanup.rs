@@ -1,12 +1,13 @@
 use crate::components::CombatUI;
-use crate::resources::{CombatState, CurrentEnemy};
+use crate::resources::CombatState;
 use bevy::prelude::*;
 
+/// Retire l'UI de combat (les tours joués et les dégâts infligés ont été envoyés au bilan
+/// avec `CombatEnded`)
 pub fn cleanup_combat(mut commands: Commands, combat_ui_query: Query<Entity, With<CombatUI>>) {
     for entity in combat_ui_query.iter() {
         commands.entity(entity).despawn_recursive();
     }
 
-    commands.remove_resource::<CurrentEnemy>();
     commands.remove_resource::<CombatState>();
 }
@@ -1,17 +1,17 @@
 use bevy::prelude::*;
 
-use crate::components::HealthBar;
+use crate::components::{HealthBar, StatusEffectsText};
 use crate::player::Player;
-use crate::resources::{CombatState, CurrentEnemy};
+use crate::resources::CombatState;
+use regles::status::{StatusEffects, StatusKind};
 
 /// Système qui met à jour dynamiquement la largeur des barres de vie
-/// en fonction des HP actuels du joueur et de l'ennemi
+/// en fonction des HP actuels du joueur et de chaque ennemi
 /// Appelé en boucle pendant le combat (Update schedule)
 pub fn update_health_bars(
     mut health_bar_query: Query<(&HealthBar, &mut Style)>,
     combat_state: Res<CombatState>,
     player_query: Query<&Player>,
-    current_enemy: Res<CurrentEnemy>,
 ) {
     // Récupérer les stats max
     let player = match player_query.get_single() {
@@ -20,23 +20,90 @@ pub fn update_health_bars(
     };
 
     let player_max_hp = player.stats.hp as f32;
-    let enemy_max_hp = current_enemy.stats.hp as f32;
 
     // Mettre à jour chaque barre
     for (bar_type, mut style) in health_bar_query.iter_mut() {
         match bar_type {
             HealthBar::Player => {
-                let hp_percent = (combat_state.player_hp as f32 / player_max_hp * 100.0)
-                    .max(0.0)
-                    .min(100.0);
+                let hp_percent = (combat_state.combat.player.hp as f32 / player_max_hp * 100.0)
+                    .clamp(0.0, 100.0);
                 style.width = Val::Percent(hp_percent);
             }
-            HealthBar::Enemy => {
-                let hp_percent = (combat_state.enemy_hp as f32 / enemy_max_hp * 100.0)
-                    .max(0.0)
-                    .min(100.0);
+            HealthBar::Enemy(index) => {
+                let enemy = &combat_state.combat.enemies[*index];
+                let hp_percent =
+                    (enemy.hp as f32 / enemy.stats.hp as f32 * 100.0).clamp(0.0, 100.0);
                 style.width = Val::Percent(hp_percent);
             }
         }
     }
 }
+
+/// Système qui affiche les effets de statut actifs sous chaque barre de vie
+/// Une section de texte par effet, colorée selon son type : "Poison x2 (3)" = 2 cumuls, 3 tours restants
+pub fn update_status_effects(
+    mut text_query: Query<(&StatusEffectsText, &mut Text)>,
+    combat_state: Res<CombatState>,
+) {
+    if !combat_state.is_changed() {
+        return;
+    }
+
+    for (target, mut text) in text_query.iter_mut() {
+        let effects = match target {
+            StatusEffectsText::Player => &combat_state.combat.player.effects,
+            StatusEffectsText::Enemy(index) => &combat_state.combat.enemies[*index].effects,
+        };
+        text.sections = status_sections(effects, text.sections[0].style.font_size);
+    }
+}
+
+/// Sections de texte des effets actifs (une section vide si aucun effet, pour garder le style)
+fn status_sections(effects: &StatusEffects, font_size: f32) -> Vec<TextSection> {
+    if effects.0.is_empty() {
+        return vec![TextSection::new(
+            "",
+            TextStyle {
+                font_size,
+                ..default()
+            },
+        )];
+    }
+
+    effects
+        .0
+        .iter()
+        .map(|effect| {
+            let stacks = if effect.stacks > 1 {
+                format!(" x{}", effect.stacks)
+            } else {
+                String::new()
+            };
+            TextSection::new(
+                format!(
+                    "{}{} ({})  ",
+                    effect.kind.label(),
+                    stacks,
+                    effect.turns_left
+                ),
+                TextStyle {
+                    font_size,
+                    color: status_color(effect.kind),
+                    ..default()
+                },
+            )
+        })
+        .collect()
+}
+
+/// Couleur du texte de l'effet dans l'interface de combat
+fn status_color(kind: StatusKind) -> Color {
+    match kind {
+        StatusKind::Poison => Color::rgb(0.4, 0.9, 0.2),
+        StatusKind::Bleed => Color::rgb(0.9, 0.1, 0.1),
+        StatusKind::Burn => Color::rgb(1.0, 0.5, 0.0),
+        StatusKind::Stun => Color::rgb(1.0, 1.0, 0.2),
+        StatusKind::Strength => Color::rgb(0.3, 0.6, 1.0),
+        StatusKind::Weakness => Color::rgb(0.7, 0.3, 0.9),
+    }
+}
@@ -0,0 +1,78 @@
+use crate::components::TurnOrderText;
+use crate::resources::CombatState;
+use bevy::prelude::*;
+use regles::combat::Actor;
+
+/// Nombre de tours à venir affichés dans l'ordre des tours
+const TURN_ORDER_PREVIEW: usize = 6;
+
+/// Affiche l'ordre des tours : le tour en cours puis les suivants prévus par l'initiative
+/// (voir `regles::combat::Combat::turn_order`)
+/// Rien n'est affiché une fois le combat terminé
+pub fn update_turn_order(
+    combat_state: Res<CombatState>,
+    mut text_query: Query<&mut Text, With<TurnOrderText>>,
+) {
+    if !combat_state.is_changed() {
+        return;
+    }
+    let Ok(mut text) = text_query.get_single_mut() else {
+        return;
+    };
+    let font_size = text.sections[0].style.font_size;
+
+    let current = if combat_state.is_player_turn {
+        Some(Actor::Player)
+    } else {
+        combat_state
+            .enemy_turn
+            .as_ref()
+            .map(|(index, _)| Actor::Enemy(*index))
+    };
+
+    let Some(current) = current else {
+        text.sections = vec![TextSection::new(
+            "",
+            TextStyle {
+                font_size,
+                ..default()
+            },
+        )];
+        return;
+    };
+
+    let upcoming = combat_state.combat.turn_order(TURN_ORDER_PREVIEW - 1);
+
+    let mut sections = vec![TextSection::new(
+        "Ordre : ",
+        TextStyle {
+            font_size,
+            color: Color::rgb(0.8, 0.8, 0.8),
+            ..default()
+        },
+    )];
+    for (index, actor) in std::iter::once(current).chain(upcoming).enumerate() {
+        let (name, color) = match actor {
+            Actor::Player => ("Vous", Color::rgb(0.2, 1.0, 0.2)),
+            Actor::Enemy(enemy) => (
+                combat_state.enemies[enemy].name.as_str(),
+                Color::rgb(1.0, 0.2, 0.2),
+            ),
+        };
+        // Le tour en cours est mis entre crochets
+        let label = if index == 0 {
+            format!("[{}]", name)
+        } else {
+            format!(" > {}", name)
+        };
+        sections.push(TextSection::new(
+            label,
+            TextStyle {
+                font_size,
+                color,
+                ..default()
+            },
+        ));
+    }
+    text.sections = sections;
+}
@@ -1,36 +1,76 @@
-use crate::components::{ActionMessageText, CombatLogText, EnemyStatsText, PlayerStatsText};
+use crate::components::{
+    ActionMessageText, CombatActionButton, CombatLogText, EnemyStatsText, PlayerStatsText,
+    Position, PreviousPosition,
+};
+use crate::content::ContentRegistry;
+use crate::controls::{Action, Controls, KeyBindings};
+use crate::events::{
+    CombatEnded, CombatRewards, EnemyDefeated, EnemyFled, LevelUp, LootDropped, PlayerDied,
+    PlayerFled, UpgradeChosen,
+};
+use crate::inventory::Inventory;
+use crate::loot::{Rarity, roll_affixes, roll_drops};
 use crate::player::Player;
-use crate::resources::{CombatState, CurrentEnemy, DefeatedEnemies, GameLog};
+use crate::resources::{CombatAction, CombatState};
 use crate::states::GameState;
-use crate::systems::combat::calculations::{calculate_damage, check_dodge};
+use crate::systems::combat::menu::{is_action_available, read_combat_action};
+use crate::systems::combat::setup::{enemy_stats_text, player_stats_text};
 use crate::systems::map::GameData;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use regles::combat::{
+    Actor, AttackResult, CombatEvent, DEFEND_DAMAGE_PERCENT, EnemyOutcome, PlayerAction,
+    PlayerOutcome, TurnAction,
+};
+use regles::progression::Upgrade;
+use regles::skill::{SkillDef, SkillEffect};
+use regles::stats::Stats;
+use regles::status::StatusKind;
 
-/// Gère la logique du combat tour par tour
-///
-/// Mécanique de combat :
-/// 1. Tour du joueur (ESPACE) :
-///    - Calcul d'esquive de l'ennemi (basé sur sa vitesse)
-///    - Calcul de critique du joueur (basé sur son taux de critique)
-///    - Application des dégâts (×2 si critique)
-///
-/// 2. Tour de l'ennemi (automatique) :
-///    - Même logique mais inversée
-///
-/// 3. Fin de combat :
-///    - Victoire : Choix d'amélioration de stat (H/A/S/C)
-///    - Défaite : Game Over avec option de recommencer (R)
-pub fn handle_combat(
-    keyboard_input: Res<Input<KeyCode>>,
-    mut player_query: Query<&mut Player>,
-    current_enemy: Res<CurrentEnemy>,
-    mut combat_state: ResMut<CombatState>,
-    mut next_state: ResMut<NextState<GameState>>,
-    mut game_log: ResMut<GameLog>,
-    mut action_message_query: Query<&mut Text, (With<ActionMessageText>, Without<CombatLogText>)>,
-    mut combat_log_query: Query<&mut Text, (With<CombatLogText>, Without<ActionMessageText>)>,
-    mut player_stats_query: Query<
-        &mut Text,
+const VICTORY_MESSAGE: &str = "🎉 VICTOIRE ! 🎉";
+
+/// Événements émis pendant et à la fin du combat (voir `events`) : le log, la progression et
+/// l'inventaire sont mis à jour par les systèmes qui les écoutent
+#[derive(SystemParam)]
+pub struct CombatOutcomes<'w> {
+    combat_ended: EventWriter<'w, CombatEnded>,
+    enemy_defeated: EventWriter<'w, EnemyDefeated>,
+    player_died: EventWriter<'w, PlayerDied>,
+    player_fled: EventWriter<'w, PlayerFled>,
+    enemy_fled: EventWriter<'w, EnemyFled>,
+    rewards: EventWriter<'w, CombatRewards>,
+    level_up: EventWriter<'w, LevelUp>,
+    upgrade_chosen: EventWriter<'w, UpgradeChosen>,
+    loot_dropped: EventWriter<'w, LootDropped>,
+}
+
+impl CombatOutcomes<'_> {
+    /// Le joueur quitte le combat : tours joués et dégâts infligés pour le bilan
+    fn end_combat(&mut self, combat_state: &CombatState) {
+        let damage_dealt = combat_state
+            .combat
+            .enemies
+            .iter()
+            .map(|enemy| enemy.stats.hp - enemy.hp.max(0))
+            .sum();
+        self.combat_ended.send(CombatEnded {
+            player_turns: combat_state.player_turns,
+            damage_dealt,
+        });
+    }
+}
+
+/// Textes de l'interface de combat mis à jour après chaque tour
+#[allow(clippy::type_complexity)]
+#[derive(SystemParam)]
+pub struct CombatTexts<'w, 's> {
+    action_message:
+        Query<'w, 's, &'static mut Text, (With<ActionMessageText>, Without<CombatLogText>)>,
+    combat_log: Query<'w, 's, &'static mut Text, (With<CombatLogText>, Without<ActionMessageText>)>,
+    player_stats: Query<
+        'w,
+        's,
+        &'static mut Text,
         (
             With<PlayerStatsText>,
             Without<EnemyStatsText>,
@@ -38,8 +78,10 @@ pub fn handle_combat(
             Without<CombatLogText>,
         ),
     >,
-    mut enemy_stats_query: Query<
-        &mut Text,
+    enemy_stats: Query<
+        'w,
+        's,
+        (&'static EnemyStatsText, &'static mut Text),
         (
             With<EnemyStatsText>,
             Without<PlayerStatsText>,
@@ -47,152 +89,741 @@ pub fn handle_combat(
             Without<CombatLogText>,
         ),
     >,
-    mut defeated_enemies: ResMut<DefeatedEnemies>,
+}
+
+impl CombatTexts<'_, '_> {
+    fn set_action_message(&mut self, message: String) {
+        if let Ok(mut text) = self.action_message.get_single_mut() {
+            text.sections[0].value = message;
+        }
+    }
+
+    /// Affiche le résultat d'un tour : message central, log (5 dernières lignes) et stats
+    fn show_turn(
+        &mut self,
+        action_msg: String,
+        log_lines: Vec<String>,
+        combat_state: &mut CombatState,
+        player: &Player,
+    ) {
+        self.set_action_message(action_msg);
+
+        if let Ok(mut text) = self.combat_log.get_single_mut() {
+            combat_state.combat_log.extend(log_lines);
+            let recent_logs: Vec<String> = combat_state
+                .combat_log
+                .iter()
+                .rev()
+                .take(5)
+                .rev()
+                .cloned()
+                .collect();
+            text.sections[0].value = recent_logs.join("\n");
+        }
+
+        if let Ok(mut text) = self.player_stats.get_single_mut() {
+            text.sections[0].value = player_stats_text(player, &combat_state.combat.player);
+        }
+
+        for (card, mut text) in self.enemy_stats.iter_mut() {
+            text.sections[0].value = enemy_stats_text(&combat_state.combat.enemies[card.0]);
+        }
+    }
+}
+
+/// Gère la logique du combat tour par tour, côté joueur
+///
+/// Mécanique de combat :
+/// 1. L'ordre des tours dépend de la vitesse (voir `initiative`) :
+///    un combattant nettement plus rapide peut jouer plusieurs fois d'affilée
+///
+/// 2. Tour du joueur (menu d'actions, touches 1-5 ou clic) :
+///    - Les attaques visent l'ennemi ciblé (↑/↓/TAB ou clic sur sa carte, voir `menu`)
+///    - Attaquer (ou ESPACE) : esquive de l'ennemi, critique du joueur, dégâts (×2 si critique)
+///    - Défendre : réduit les dégâts reçus jusqu'au prochain tour du joueur
+///    - Compétence : effet propre à la classe, limité par le mana et un temps de recharge
+///    - Fuir : chance basée sur l'écart de vitesse ; en cas de succès retour à la map
+///      sans vaincre les ennemis restants, le joueur est replacé sur sa case précédente
+///
+/// 3. Tour d'un ennemi : joué par `handle_enemy_turn` après une courte pause
+///
+/// Au début du tour de chaque combattant, ses effets de statut sont résolus
+/// (poison, saignement, brûlure, étourdissement)
+///
+/// 4. Fin de combat :
+///    - Victoire quand tous les ennemis sont vaincus ou enfuis : XP des ennemis vaincus,
+///      puis un choix d'amélioration de stat (H/A/S/C par défaut) par niveau gagné ;
+///      `EnemyDefeated` est émis pour chaque ennemi vaincu en retournant sur la map
+///    - Défaite : ENTREE émet `PlayerDied` et mène à l'écran de bilan
+///
+/// La résolution des tours est faite par les règles (`regles::combat::Combat::apply`),
+/// partagées avec le simulateur (`--simulate`) ; ce système traduit les événements du tour
+/// en messages
+#[allow(clippy::too_many_arguments)]
+pub fn handle_combat(
+    controls: Controls,
+    interaction_query: Query<(&Interaction, &CombatActionButton), Changed<Interaction>>,
+    mut player_query: Query<(
+        &mut Player,
+        &mut Inventory,
+        &mut Position,
+        &PreviousPosition,
+    )>,
+    mut combat_state: ResMut<CombatState>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut texts: CombatTexts,
+    mut outcomes: CombatOutcomes,
     game_data: Res<GameData>,
+    registry: Res<ContentRegistry>,
 ) {
-    let mut player = player_query.single_mut();
+    let (mut player, mut inventory, mut player_pos, previous_pos) = player_query.single_mut();
 
-    if keyboard_input.just_pressed(KeyCode::Space) && combat_state.is_player_turn {
-        let mut rng = rand::thread_rng();
-        let mut action_msg = String::new();
-        let mut log_lines = Vec::new();
+    // Choix du consommable après l'action Objet : l'action Objet N utilise la N-ième pile
+    let mut used_item = None;
+    if combat_state.choosing_item && combat_state.is_player_turn {
+        if controls.just_pressed(Action::Cancel) {
+            combat_state.choosing_item = false;
+            texts.set_action_message("A vous de jouer !".to_string());
+            return;
+        }
+        used_item = Action::ITEM_SLOTS
+            .iter()
+            .take(inventory.consumables.len())
+            .position(|action| controls.just_pressed(*action));
+        if used_item.is_none() {
+            return;
+        }
+        combat_state.choosing_item = false;
+    }
 
-        action_msg.push_str("--- VOTRE TOUR ---\n\n");
+    let chosen_action = match used_item {
+        Some(_) => Some(CombatAction::UseItem),
+        None => read_combat_action(&controls, &interaction_query)
+            .filter(|_| combat_state.is_player_turn),
+    };
 
-        // Tour du joueur
-        if check_dodge(current_enemy.stats.speed, &mut rng) {
-            action_msg.push_str("L'ennemi esquive votre attaque !");
-            log_lines.push("Esquive ennemi".to_string());
-        } else {
-            let result = calculate_damage(&player.stats, &mut rng);
-            if result.is_critical {
-                action_msg.push_str(&format!("⚡ COUP CRITIQUE !\nDegats: {}", result.damage));
-                log_lines.push(format!("CRIT {} dmg", result.damage));
-            } else {
-                action_msg.push_str(&format!("Vous attaquez !\nDegats: {}", result.damage));
-                log_lines.push(format!("Atk {} dmg", result.damage));
+    // Action indisponible : on l'indique sans consommer le tour
+    if let Some(action) = chosen_action
+        && !is_action_available(action, &combat_state, &inventory)
+    {
+        let fighter = &combat_state.combat.player;
+        let skill = &fighter.skill;
+        texts.set_action_message(match action {
+            CombatAction::Skill if fighter.level < skill.unlock_level => {
+                format!("{} : debloque au niveau {}", skill.name, skill.unlock_level)
             }
-            combat_state.enemy_hp -= result.damage;
+            CombatAction::Skill if fighter.skill_cooldown > 0 => format!(
+                "{} : encore {} tour(s) de recharge",
+                skill.name, fighter.skill_cooldown
+            ),
+            CombatAction::Skill => format!("{} : mana insuffisant", skill.name),
+            CombatAction::UseItem => "Aucun objet a utiliser".to_string(),
+            _ => format!("{} : indisponible pour le moment", action.label()),
+        });
+        return;
+    }
+
+    // Objet : on affiche les consommables, le tour n'est joué qu'une fois l'objet choisi
+    if chosen_action == Some(CombatAction::UseItem) && used_item.is_none() {
+        combat_state.choosing_item = true;
+        texts.set_action_message(item_choice_prompt(&inventory, &controls.bindings));
+        return;
+    }
+
+    let player_action = chosen_action.and_then(|action| match action {
+        CombatAction::Attack => Some(PlayerAction::Attack),
+        CombatAction::Defend => Some(PlayerAction::Defend),
+        CombatAction::Skill => Some(PlayerAction::Skill),
+        CombatAction::Flee => Some(PlayerAction::Flee),
+        CombatAction::UseItem => {
+            used_item.map(|index| PlayerAction::UseItem(inventory.consumables[index].effect))
         }
+    });
 
-        // Vérifier victoire
-        if combat_state.enemy_hp <= 0 {
-            action_msg = "🎉 VICTOIRE ! 🎉\n\nChoisissez une amelioration:\nH = +10 HP | A = +2 ATK\nS = +1 SPD | C = +2% CRIT".to_string();
-            log_lines.push("VICTOIRE !".to_string());
-            combat_state.is_player_turn = false;
+    if let Some(player_action) = player_action {
+        // La cible peut changer pendant le tour si elle est vaincue
+        let target = combat_state.combat.target;
+        let events = match combat_state.combat.apply(TurnAction::Player(player_action)) {
+            Ok(events) => events,
+            Err(err) => {
+                texts.set_action_message(format!("Action refusee : {}", err));
+                return;
+            }
+        };
+        combat_state.player_turns += 1;
+        let mut action_msg = "--- VOTRE TOUR ---\n\n".to_string();
+        let mut log_lines = Vec::new();
+
+        for event in events {
+            match event {
+                CombatEvent::StatusDamage { kind, damage, .. } => {
+                    push_status_damage(&mut action_msg, &mut log_lines, kind, damage, "Vous");
+                }
+                CombatEvent::PlayerActed { target, outcome } => {
+                    // Le consommable n'est retiré que si le joueur a pu agir
+                    if let Some(index) = used_item
+                        && outcome != PlayerOutcome::Stunned
+                    {
+                        let (name, left) = inventory.consume(index);
+                        action_msg
+                            .push_str(&format!("Vous utilisez {} (reste {}) !\n", name, left));
+                        log_lines.push(format!("Objet : {}", name));
+                    }
+                    push_player_outcome(
+                        &mut action_msg,
+                        &mut log_lines,
+                        outcome,
+                        &combat_state.enemies[target].name,
+                        &combat_state.combat.player.skill,
+                    );
+                }
+                CombatEvent::StatusInflicted {
+                    target: Actor::Enemy(index),
+                    kind,
+                } => {
+                    let name = &combat_state.enemies[index].name;
+                    push_inflicted(&mut action_msg, &mut log_lines, kind, name);
+                }
+                CombatEvent::EnemyDefeated(index) => {
+                    let name = &combat_state.enemies[index].name;
+                    action_msg.push_str(&format!("\n{} est vaincu !", name));
+                    log_lines.push(format!("{} vaincu", name));
+                }
+                CombatEvent::Defeat => {
+                    // Défaite : le joueur a succombé à ses effets en début de tour
+                    action_msg = defeat_message(&controls.bindings);
+                    log_lines.push("DEFAITE".to_string());
+                    combat_state.is_player_turn = false;
+                }
+                CombatEvent::Escaped => {
+                    // Fuite réussie : retour à la map, les ennemis vaincus avant la fuite le restent
+                    combat_state.is_player_turn = false;
+                    player.current_hp = combat_state.combat.player.hp;
+                    *player_pos = previous_pos.0;
+                    outcomes
+                        .enemy_defeated
+                        .send_batch(combat_state.defeated().map(|enemy| EnemyDefeated {
+                            map_index: game_data.current_map_index,
+                            enemy: enemy.clone(),
+                        }));
+                    outcomes.player_fled.send(PlayerFled {
+                        enemies: combat_state.fighting().cloned().collect(),
+                    });
+                    outcomes.end_combat(&combat_state);
+                    next_state.set(GameState::Map);
+                }
+                CombatEvent::Victory => {
+                    action_msg = win_combat(
+                        &mut combat_state,
+                        &mut player,
+                        &registry,
+                        &controls.bindings,
+                        &mut outcomes,
+                    );
+                    log_lines.push("VICTOIRE !".to_string());
+                }
+                CombatEvent::NextTurn(actor) => {
+                    let target_enemy = &combat_state.combat.enemies[target];
+                    if target_enemy.is_fighting() {
+                        action_msg.push_str(&format!(
+                            "\n\nHP {}: {}",
+                            combat_state.enemies[target].name, target_enemy.hp
+                        ));
+                    }
+                    combat_state.begin_turn(actor);
+                    match actor {
+                        Actor::Player => {
+                            action_msg.push_str("\n\nVous etes plus rapide : rejouez !")
+                        }
+                        Actor::Enemy(index) => action_msg.push_str(&format!(
+                            "\n\n--- TOUR ENNEMI ---\n\n{} se prepare...",
+                            combat_state.enemies[index].name
+                        )),
+                    }
+                }
+                // Le reste n'arrive qu'au tour d'un ennemi
+                _ => {}
+            }
+        }
+
+        texts.show_turn(action_msg, log_lines, &mut combat_state, &player);
+    }
+
+    // Gestion de la victoire : une amélioration de stat par niveau gagné, puis retour à la map
+    // (ignorée le frame où l'action a été jouée, pour ne pas enchaîner sur la même touche)
+    if player_action.is_none() && combat_state.combat.is_won() && !combat_state.is_player_turn {
+        let finished = if combat_state.pending_upgrades > 0 {
+            match choose_upgrade(&controls, &mut player) {
+                Some(bonus) => {
+                    outcomes.upgrade_chosen.send(UpgradeChosen { bonus });
+                    combat_state.pending_upgrades -= 1;
+                    if combat_state.pending_upgrades > 0 {
+                        texts.set_action_message(upgrade_prompt(
+                            combat_state.pending_upgrades,
+                            &controls.bindings,
+                        ));
+                    }
+                    combat_state.pending_upgrades == 0
+                }
+                None => false,
+            }
         } else {
-            // Tour de l'ennemi
-            action_msg.push_str(&format!(
-                "\n\nHP ennemi: {}\n\n--- TOUR ENNEMI ---\n\n",
-                combat_state.enemy_hp.max(0)
-            ));
+            controls.just_pressed(Action::Confirm)
+        };
 
-            if check_dodge(player.stats.speed, &mut rng) {
-                action_msg.push_str("Vous esquivez l'attaque !");
-                log_lines.push("Esquive joueur".to_string());
-            } else {
-                let result = calculate_damage(&current_enemy.stats, &mut rng);
-                if result.is_critical {
-                    action_msg
-                        .push_str(&format!("⚡ CRITIQUE ENNEMI !\nDegats: {}", result.damage));
-                    log_lines.push(format!("Ennemi CRIT {} dmg", result.damage));
+        if finished {
+            // Les ennemis enfuis restent sur la map
+            for (enemy, fighter) in combat_state
+                .enemies
+                .iter()
+                .zip(&combat_state.combat.enemies)
+            {
+                if fighter.is_alive() {
+                    outcomes.enemy_fled.send(EnemyFled {
+                        enemy: enemy.clone(),
+                    });
                 } else {
-                    action_msg.push_str(&format!("L'ennemi attaque !\nDegats: {}", result.damage));
-                    log_lines.push(format!("Ennemi {} dmg", result.damage));
+                    outcomes.enemy_defeated.send(EnemyDefeated {
+                        map_index: game_data.current_map_index,
+                        enemy: enemy.clone(),
+                    });
                 }
-                combat_state.player_hp -= result.damage;
             }
+            outcomes.end_combat(&combat_state);
+            next_state.set(GameState::Map);
+        }
+    }
+
+    // Gestion de la défaite
+    if combat_state.combat.is_lost() && controls.just_pressed(Action::Confirm) {
+        outcomes.player_died.send(PlayerDied {
+            enemies: combat_state.fighting().cloned().collect(),
+        });
+        outcomes.end_combat(&combat_state);
+        next_state.set(GameState::Defeat);
+    }
+}
+
+/// Joue le tour de l'ennemi actif une fois la pause écoulée (voir `CombatState::begin_turn`)
+/// puis passe au combattant suivant selon l'initiative
+pub fn handle_enemy_turn(
+    time: Res<Time>,
+    mut player_query: Query<&mut Player>,
+    mut combat_state: ResMut<CombatState>,
+    mut texts: CombatTexts,
+    mut outcomes: CombatOutcomes,
+    registry: Res<ContentRegistry>,
+    bindings: Res<KeyBindings>,
+) {
+    let Some((index, timer)) = combat_state.enemy_turn.as_mut() else {
+        return;
+    };
+    if !timer.tick(time.delta()).finished() {
+        return;
+    }
+    let index = *index;
+    combat_state.enemy_turn = None;
 
-            // Vérifier défaite
-            if combat_state.player_hp <= 0 {
-                action_msg = "💀 DEFAITE... 💀\n\nAppuyez sur R pour recommencer".to_string();
+    let mut player = player_query.single_mut();
+    let enemy_name = combat_state.enemies[index].name.clone();
+    let mut action_msg = format!("--- TOUR DE {} ---\n\n", enemy_name.to_uppercase());
+    let mut log_lines = Vec::new();
+
+    // Le tour ennemi est toujours attendu par les règles quand la pause se termine
+    let Ok(events) = combat_state.combat.apply(TurnAction::Enemy) else {
+        return;
+    };
+
+    for event in events {
+        match event {
+            CombatEvent::StatusDamage { kind, damage, .. } => {
+                push_status_damage(&mut action_msg, &mut log_lines, kind, damage, &enemy_name);
+            }
+            CombatEvent::PhaseChanged { phase, .. } => {
+                let message = &combat_state.combat.enemies[index].ai.phases[phase - 1].message;
+                action_msg.push_str(&format!("{}\n", message));
+                log_lines.push(format!("{} : nouvelle phase", enemy_name));
+            }
+            // L'ennemi a succombé à ses effets avant d'agir
+            CombatEvent::EnemyDefeated(_) => {
+                action_msg.push_str(&format!("{} succombe !", enemy_name));
+                log_lines.push(format!("{} vaincu", enemy_name));
+            }
+            CombatEvent::EnemyActed { outcome, .. } => {
+                push_enemy_outcome(&mut action_msg, &mut log_lines, outcome, &enemy_name);
+            }
+            CombatEvent::StatusInflicted { kind, .. } => {
+                push_inflicted(&mut action_msg, &mut log_lines, kind, "Vous");
+            }
+            CombatEvent::Defeat => {
+                action_msg = defeat_message(&bindings);
                 log_lines.push("DEFAITE".to_string());
                 combat_state.is_player_turn = false;
-            } else {
+            }
+            CombatEvent::Victory => {
+                // Le dernier ennemi a succombé à ses effets en début de tour ou s'est enfui
+                action_msg = win_combat(
+                    &mut combat_state,
+                    &mut player,
+                    &registry,
+                    &bindings,
+                    &mut outcomes,
+                );
+                log_lines.push("VICTOIRE !".to_string());
+            }
+            CombatEvent::NextTurn(actor) => {
                 action_msg.push_str(&format!(
-                    "\n\nVos HP: {}\n\n[ESPACE] pour continuer",
-                    combat_state.player_hp.max(0)
+                    "\n\nVos HP: {}",
+                    combat_state.combat.player.hp.max(0)
                 ));
+                combat_state.begin_turn(actor);
+                match actor {
+                    Actor::Player => action_msg.push_str("\n\nA vous de jouer !"),
+                    Actor::Enemy(next) if next == index => action_msg
+                        .push_str(&format!("\n\n{} est plus rapide et rejoue...", enemy_name)),
+                    Actor::Enemy(next) => action_msg.push_str(&format!(
+                        "\n\n{} se prepare...",
+                        combat_state.enemies[next].name
+                    )),
+                }
             }
+            // Le joueur n'agit pas pendant le tour ennemi
+            _ => {}
         }
+    }
 
-        // Mettre à jour le message d'action au centre
-        if let Ok(mut text) = action_message_query.get_single_mut() {
-            text.sections[0].value = action_msg;
-        }
+    texts.show_turn(action_msg, log_lines, &mut combat_state, &player);
+}
 
-        // Mettre à jour le log d'historique (garder les 5 dernières lignes)
-        if let Ok(mut text) = combat_log_query.get_single_mut() {
-            combat_state.combat_log.extend(log_lines);
-            let recent_logs: Vec<String> = combat_state
-                .combat_log
-                .iter()
-                .rev()
-                .take(5)
-                .rev()
-                .cloned()
-                .collect();
-            text.sections[0].value = recent_logs.join("\n");
+/// Fin de combat gagnée : PV conservés, XP, or et butin des ennemis vaincus (les fuyards ne
+/// rapportent rien) et montées de niveau ; retourne le message de victoire
+/// Les récompenses, le butin et les niveaux gagnés sont émis pour les écouteurs
+fn win_combat(
+    combat_state: &mut CombatState,
+    player: &mut Player,
+    registry: &ContentRegistry,
+    bindings: &KeyBindings,
+    outcomes: &mut CombatOutcomes,
+) -> String {
+    combat_state.is_player_turn = false;
+    player.current_hp = combat_state.combat.player.hp;
+
+    let defeated = || {
+        combat_state
+            .defeated()
+            .map(|enemy| registry.enemy(&enemy.enemy_type))
+    };
+    let xp: u32 = defeated().map(|enemy_def| enemy_def.xp).sum();
+    let gold: u32 = defeated().map(|enemy_def| enemy_def.gold).sum();
+    player.gold += gold;
+    let class_def = registry.class(&player.class);
+    let levels = player.gain_xp(xp, &class_def.growth);
+    combat_state.pending_upgrades = levels;
+
+    let mut message = format!("{}\n\n+{} XP, +{} or", VICTORY_MESSAGE, xp, gold);
+    outcomes.rewards.send(CombatRewards {
+        xp,
+        gold,
+        total_gold: player.gold,
+    });
+
+    let drops = roll_loot(combat_state, registry, outcomes);
+    if !drops.is_empty() {
+        message.push_str(&format!("\nButin : {}", drops.join(", ")));
+    }
+    if levels > 0 {
+        let unlocked = player.level >= class_def.skill.unlock_level
+            && player.level - levels < class_def.skill.unlock_level;
+        outcomes.level_up.send(LevelUp {
+            level: player.level,
+            hp: player.current_hp,
+            max_hp: player.stats.hp,
+            unlocked_skill: unlocked.then(|| class_def.skill.name.clone()),
+        });
+        message.push_str(&format!(
+            "\nNiveau {} atteint !\n\n{}",
+            player.level,
+            upgrade_prompt(levels, bindings)
+        ));
+    } else {
+        message.push_str(&format!(
+            " ({} / {})\n\n{}",
+            player.xp,
+            player.xp_to_next_level(),
+            continue_message(bindings)
+        ));
+    }
+    message
+}
+
+/// Tire le butin de chaque ennemi vaincu, donné au joueur par l'écouteur de `LootDropped`
+/// Un équipement reçoit une rareté et ses affixes ; retourne les noms des objets obtenus
+fn roll_loot(
+    combat_state: &CombatState,
+    registry: &ContentRegistry,
+    outcomes: &mut CombatOutcomes,
+) -> Vec<String> {
+    let mut rng = rand::thread_rng();
+    let mut drops = Vec::new();
+    for enemy in combat_state.defeated() {
+        for item_type in roll_drops(&registry.enemy(&enemy.enemy_type).loot, &mut rng) {
+            let item_def = registry.item(&item_type);
+            let rarity = match item_def.slot {
+                Some(_) => Rarity::roll(&mut rng),
+                None => Rarity::Common,
+            };
+            outcomes.loot_dropped.send(LootDropped {
+                enemy_name: enemy.name.clone(),
+                item_type,
+                rarity,
+                affixes: roll_affixes(rarity.affix_count(), &mut rng),
+            });
+            drops.push(rarity.item_name(&item_def.name));
         }
+    }
+    drops
+}
 
-        // Mettre à jour les textes de stats
-        if let Ok(mut text) = player_stats_query.get_single_mut() {
-            text.sections[0].value = format!(
-                "Points de vie: {} / {}\nAttaque: {}\nVitesse: {}\nCritique: {}%",
-                combat_state.player_hp.max(0),
-                player.stats.hp,
-                player.stats.attack,
-                player.stats.speed,
-                player.stats.critical_chance
-            );
-        }
-
-        if let Ok(mut text) = enemy_stats_query.get_single_mut() {
-            text.sections[0].value = format!(
-                "Points de vie: {} / {}\nAttaque: {}\nVitesse: {}\nCritique: {}%",
-                combat_state.enemy_hp.max(0),
-                current_enemy.stats.hp,
-                current_enemy.stats.attack,
-                current_enemy.stats.speed,
-                current_enemy.stats.critical_chance
-            );
-        }
-    }
-
-    // Gestion de la victoire - choix de stat
-    if combat_state.enemy_hp <= 0 && !combat_state.is_player_turn {
-        let mut stat_chosen = false;
-
-        if keyboard_input.just_pressed(KeyCode::H) {
-            player.stats.hp += 10;
-            game_log.add_message("HP +10 !".to_string());
-            stat_chosen = true;
-        } else if keyboard_input.just_pressed(KeyCode::A) {
-            player.stats.attack += 2;
-            game_log.add_message("ATK +2 !".to_string());
-            stat_chosen = true;
-        } else if keyboard_input.just_pressed(KeyCode::S) {
-            player.stats.speed += 1;
-            game_log.add_message("SPD +1 !".to_string());
-            stat_chosen = true;
-        } else if keyboard_input.just_pressed(KeyCode::C) {
-            player.stats.critical_chance += 2;
-            game_log.add_message("CRIT +2% !".to_string());
-            stat_chosen = true;
-        }
-
-        if stat_chosen {
-            defeated_enemies
-                .enemies
-                .insert((game_data.current_map_index, current_enemy.position));
+/// Liste des consommables utilisables, avec leur touche
+fn item_choice_prompt(inventory: &Inventory, bindings: &KeyBindings) -> String {
+    let lines: Vec<String> = inventory
+        .consumables
+        .iter()
+        .zip(Action::ITEM_SLOTS)
+        .map(|(stack, action)| {
+            format!(
+                "{} : {} x{} ({})",
+                bindings.hint(action),
+                stack.name,
+                stack.count,
+                stack.effect.description()
+            )
+        })
+        .collect();
+    format!(
+        "Choisissez un objet :\n{}\n{} : retour",
+        lines.join("\n"),
+        bindings.hint(Action::Cancel)
+    )
+}
 
-            game_log.add_message(format!("{} vaincu !", current_enemy.enemy_type.get_name()));
-            next_state.set(GameState::Map);
+/// Invite à choisir les améliorations restantes
+fn upgrade_prompt(remaining: u32, bindings: &KeyBindings) -> String {
+    format!(
+        "Choisissez une amelioration ({} restante(s)):\n{} = +10 HP | {} = +2 ATK\n{} = +1 SPD | {} = +2% CRIT",
+        remaining,
+        bindings.hint(Action::ChooseHp),
+        bindings.hint(Action::ChooseAtk),
+        bindings.hint(Action::ChooseSpd),
+        bindings.hint(Action::ChooseCrit)
+    )
+}
+
+fn continue_message(bindings: &KeyBindings) -> String {
+    format!(
+        "Appuyez sur {} pour continuer",
+        bindings.hint(Action::Confirm)
+    )
+}
+
+fn defeat_message(bindings: &KeyBindings) -> String {
+    format!(
+        "💀 DEFAITE... 💀\n\nAppuyez sur {} pour voir le bilan",
+        bindings.hint(Action::Confirm)
+    )
+}
+
+/// Améliorations proposées après une victoire, avec l'action qui les choisit
+const UPGRADES: [(Action, Upgrade); 4] = [
+    (Action::ChooseHp, Upgrade::Hp),
+    (Action::ChooseAtk, Upgrade::Attack),
+    (Action::ChooseSpd, Upgrade::Speed),
+    (Action::ChooseCrit, Upgrade::CriticalChance),
+];
+
+/// Applique l'amélioration choisie ce frame (H/A/S/C par défaut) et retourne son bonus
+fn choose_upgrade(controls: &Controls, player: &mut Player) -> Option<Stats> {
+    let (_, upgrade) = UPGRADES
+        .into_iter()
+        .find(|(action, _)| controls.just_pressed(*action))?;
+    let bonus = upgrade.bonus();
+    player.add_base_stats(bonus);
+    player.current_hp += bonus.hp;
+    Some(bonus)
+}
+
+/// Ajoute au message l'action du joueur contre l'ennemi `target_name`
+fn push_player_outcome(
+    action_msg: &mut String,
+    log_lines: &mut Vec<String>,
+    outcome: PlayerOutcome,
+    target_name: &str,
+    skill: &SkillDef,
+) {
+    match outcome {
+        PlayerOutcome::Stunned => {
+            action_msg.push_str("Vous etes etourdi et passez votre tour !");
+            log_lines.push("Etourdi".to_string());
+        }
+        PlayerOutcome::Attack(AttackResult::Dodged) => {
+            action_msg.push_str(&format!("{} esquive votre attaque !", target_name));
+            log_lines.push("Esquive ennemi".to_string());
+        }
+        PlayerOutcome::Attack(AttackResult::Hit(result)) if result.is_critical => {
+            action_msg.push_str(&format!("⚡ COUP CRITIQUE !\nDegats: {}", result.damage));
+            log_lines.push(format!("CRIT {} dmg", result.damage));
+        }
+        PlayerOutcome::Attack(AttackResult::Hit(result)) => {
+            action_msg.push_str(&format!(
+                "Vous attaquez {} !\nDegats: {}",
+                target_name, result.damage
+            ));
+            log_lines.push(format!("Atk {} dmg", result.damage));
+        }
+        PlayerOutcome::Defend => {
+            action_msg.push_str(&format!(
+                "Vous vous mettez en garde !\nDegats recus: {}%",
+                DEFEND_DAMAGE_PERCENT
+            ));
+            log_lines.push("Defense".to_string());
+        }
+        PlayerOutcome::Skill(None) => {
+            action_msg.push_str(&format!("{} !{}", skill.name, skill_guard_note(skill)));
+            log_lines.push(skill.name.clone());
+        }
+        PlayerOutcome::Skill(Some(AttackResult::Dodged)) => {
+            action_msg.push_str(&format!(
+                "{} !\n{} esquive !{}",
+                skill.name,
+                target_name,
+                skill_guard_note(skill)
+            ));
+            log_lines.push(format!("{} esquive", skill.name));
+        }
+        PlayerOutcome::Skill(Some(AttackResult::Hit(result))) => {
+            let critical = if result.is_critical {
+                "⚡ CRITIQUE ! "
+            } else {
+                ""
+            };
+            action_msg.push_str(&format!(
+                "{}{} !\nDegats: {}{}",
+                critical,
+                skill.name,
+                result.damage,
+                skill_guard_note(skill)
+            ));
+            log_lines.push(format!("{} {} dmg", skill.name, result.damage));
+        }
+        PlayerOutcome::Fled => {
+            action_msg.push_str("Vous prenez la fuite !");
+            log_lines.push("Fuite".to_string());
+        }
+        PlayerOutcome::FleeFailed => {
+            action_msg.push_str("La fuite echoue !");
+            log_lines.push("Fuite ratee".to_string());
+        }
+        PlayerOutcome::Healed(healed) => {
+            action_msg.push_str(&format!("PV +{}", healed));
+            log_lines.push(format!("+{} PV", healed));
+        }
+        PlayerOutcome::Buffed(kind) => {
+            action_msg.push_str(&format!("Vous gagnez : {} !", kind.label()));
+            log_lines.push(kind.label().to_string());
         }
     }
+}
 
-    // Gestion de la défaite
-    if combat_state.player_hp <= 0 && keyboard_input.just_pressed(KeyCode::R) {
-        next_state.set(GameState::ClassSelection);
+/// Protection accordée par la compétence contre la riposte ennemie, à la suite du message
+fn skill_guard_note(skill: &SkillDef) -> String {
+    match skill.effect {
+        SkillEffect::ShieldWall { damage_percent } => {
+            format!("\nDegats recus: {}%", damage_percent)
+        }
+        SkillEffect::ShadowStep => "\nLa prochaine attaque ennemie sera esquivee".to_string(),
+        SkillEffect::Fireball { .. } | SkillEffect::Execute { .. } => String::new(),
     }
 }
+
+/// Ajoute au message l'action de l'ennemi `enemy_name`
+fn push_enemy_outcome(
+    action_msg: &mut String,
+    log_lines: &mut Vec<String>,
+    outcome: EnemyOutcome,
+    enemy_name: &str,
+) {
+    match outcome {
+        EnemyOutcome::Stunned => {
+            action_msg.push_str(&format!("{} est etourdi et passe son tour !", enemy_name));
+            log_lines.push(format!("{} etourdi", enemy_name));
+        }
+        EnemyOutcome::Evaded => {
+            action_msg.push_str("Vous disparaissez dans l'ombre !");
+            log_lines.push("Esquive (ombre)".to_string());
+        }
+        EnemyOutcome::Attack(AttackResult::Dodged)
+        | EnemyOutcome::HeavyAttack(AttackResult::Dodged) => {
+            action_msg.push_str("Vous esquivez l'attaque !");
+            log_lines.push("Esquive joueur".to_string());
+        }
+        EnemyOutcome::HeavyAttack(AttackResult::Hit(result)) => {
+            let critical = if result.is_critical {
+                "⚡ CRITIQUE ! "
+            } else {
+                ""
+            };
+            action_msg.push_str(&format!(
+                "{}{} frappe de toutes ses forces !\nDegats: {}",
+                critical, enemy_name, result.damage
+            ));
+            log_lines.push(format!("Ennemi coup puissant {} dmg", result.damage));
+        }
+        EnemyOutcome::Attack(AttackResult::Hit(result)) if result.is_critical => {
+            action_msg.push_str(&format!("⚡ CRITIQUE ENNEMI !\nDegats: {}", result.damage));
+            log_lines.push(format!("Ennemi CRIT {} dmg", result.damage));
+        }
+        EnemyOutcome::Attack(AttackResult::Hit(result)) => {
+            action_msg.push_str(&format!(
+                "{} attaque !\nDegats: {}",
+                enemy_name, result.damage
+            ));
+            log_lines.push(format!("Ennemi {} dmg", result.damage));
+        }
+        EnemyOutcome::Charge => {
+            action_msg.push_str(&format!("{} prend son elan...", enemy_name));
+            log_lines.push(format!("{} charge", enemy_name));
+        }
+        EnemyOutcome::Buff(kind) => {
+            action_msg.push_str(&format!("{} gagne : {} !", enemy_name, kind.label()));
+            log_lines.push(format!("{} : {}", enemy_name, kind.label()));
+        }
+        EnemyOutcome::Fled => {
+            action_msg.push_str(&format!("{} s'enfuit !", enemy_name));
+            log_lines.push(format!("{} en fuite", enemy_name));
+        }
+    }
+}
+
+/// Ajoute au message les dégâts d'un effet résolu en début de tour
+/// L'étourdissement est annoncé avec l'action du combattant
+fn push_status_damage(
+    action_msg: &mut String,
+    log_lines: &mut Vec<String>,
+    kind: StatusKind,
+    damage: i32,
+    who: &str,
+) {
+    action_msg.push_str(&format!("{} : {} -{} PV\n", who, kind.label(), damage));
+    log_lines.push(format!("{} {} dmg", kind.label(), damage));
+}
+
+/// Ajoute au message un effet infligé par une attaque
+fn push_inflicted(
+    action_msg: &mut String,
+    log_lines: &mut Vec<String>,
+    kind: StatusKind,
+    who: &str,
+) {
+    action_msg.push_str(&format!("\n{} : {} !", who, kind.label()));
+    log_lines.push(format!("{} : {}", who, kind.label()));
+}
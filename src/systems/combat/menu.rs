@@ -0,0 +1,132 @@
+use crate::components::{CombatActionButton, CombatMenu, EnemyCard};
+use crate::controls::{Action, Controls};
+use crate::inventory::Inventory;
+use crate::player::Player;
+use crate::resources::{CombatAction, CombatState};
+use crate::systems::combat::setup::{
+    MENU_BUTTON_COLOR, MENU_BUTTON_DISABLED_COLOR, MENU_BUTTON_HOVER_COLOR,
+};
+use bevy::prelude::*;
+
+/// Bordure de la carte de l'ennemi ciblé
+const TARGET_BORDER_COLOR: Color = Color::rgb(1.0, 1.0, 0.2);
+/// Voile sur la carte d'un ennemi vaincu
+const DEFEATED_CARD_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.6);
+
+/// Indique si une action du menu peut être choisie
+/// La compétence dépend du niveau, de sa recharge et du mana ; Objet reste indisponible
+/// tant que le joueur n'a pas de consommable
+pub fn is_action_available(
+    action: CombatAction,
+    state: &CombatState,
+    inventory: &Inventory,
+) -> bool {
+    match action {
+        CombatAction::Attack | CombatAction::Defend | CombatAction::Flee => true,
+        CombatAction::Skill => state.combat.player.is_skill_ready(),
+        CombatAction::UseItem => !inventory.consumables.is_empty(),
+    }
+}
+
+/// Lit l'action choisie ce frame, au clavier (touches 1-5 et ESPACE pour attaquer par défaut)
+/// ou à la souris
+pub fn read_combat_action(
+    controls: &Controls,
+    interaction_query: &Query<(&Interaction, &CombatActionButton), Changed<Interaction>>,
+) -> Option<CombatAction> {
+    if let Some(action) = CombatAction::ALL
+        .into_iter()
+        .find(|action| controls.just_pressed(action.input_action()))
+    {
+        return Some(action);
+    }
+
+    interaction_query
+        .iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, button)| button.0)
+}
+
+/// Affiche le menu uniquement pendant le tour du joueur
+/// et colore les boutons selon le survol et la disponibilité de l'action
+pub fn update_combat_menu(
+    combat_state: Res<CombatState>,
+    inventory_query: Query<&Inventory, With<Player>>,
+    mut menu_query: Query<&mut Visibility, With<CombatMenu>>,
+    mut button_query: Query<(&Interaction, &CombatActionButton, &mut BackgroundColor)>,
+) {
+    let visibility = if combat_state.is_player_turn {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut menu_visibility in menu_query.iter_mut() {
+        if *menu_visibility != visibility {
+            *menu_visibility = visibility;
+        }
+    }
+
+    let inventory = inventory_query.single();
+    for (interaction, button, mut background) in button_query.iter_mut() {
+        let color = if !is_action_available(button.0, &combat_state, inventory) {
+            MENU_BUTTON_DISABLED_COLOR
+        } else if *interaction == Interaction::Hovered {
+            MENU_BUTTON_HOVER_COLOR
+        } else {
+            MENU_BUTTON_COLOR
+        };
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+/// Change l'ennemi ciblé pendant le tour du joueur :
+/// ↑/↓ ou TAB (par défaut) pour parcourir les ennemis en jeu, ou clic sur la carte d'un ennemi
+pub fn select_target(
+    controls: Controls,
+    card_query: Query<(&Interaction, &EnemyCard), Changed<Interaction>>,
+    mut combat_state: ResMut<CombatState>,
+) {
+    if !combat_state.is_player_turn {
+        return;
+    }
+
+    if controls.just_pressed(Action::PreviousTarget) {
+        combat_state.combat.cycle_target(-1);
+    } else if controls.just_pressed(Action::NextTarget) {
+        combat_state.combat.cycle_target(1);
+    }
+
+    if let Some((_, card)) = card_query
+        .iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+        && combat_state.combat.enemies[card.0].is_fighting()
+    {
+        combat_state.combat.target = card.0;
+    }
+}
+
+/// Encadre la carte de l'ennemi ciblé et assombrit celles des ennemis vaincus ou enfuis
+pub fn update_enemy_cards(
+    combat_state: Res<CombatState>,
+    mut card_query: Query<(&EnemyCard, &mut BorderColor, &mut BackgroundColor)>,
+) {
+    if !combat_state.is_changed() {
+        return;
+    }
+
+    for (card, mut border, mut background) in card_query.iter_mut() {
+        let enemy = &combat_state.combat.enemies[card.0];
+        border.0 = if enemy.is_fighting() && card.0 == combat_state.combat.target {
+            TARGET_BORDER_COLOR
+        } else {
+            Color::NONE
+        };
+        background.0 = if enemy.is_fighting() {
+            Color::NONE
+        } else {
+            DEFEATED_CARD_COLOR
+        };
+    }
+}
@@ -1,14 +1,40 @@
-mod calculations;
 mod cleanup;
 mod health_bars;
+mod initiative;
 mod logic;
+mod menu;
 mod setup;
 
+use crate::states::GameState;
+use bevy::prelude::*;
+
 pub use cleanup::cleanup_combat;
-pub use health_bars::update_health_bars;
-pub use logic::handle_combat;
+pub use health_bars::{update_health_bars, update_status_effects};
+pub use initiative::update_turn_order;
+pub use logic::{handle_combat, handle_enemy_turn};
+pub use menu::{select_target, update_combat_menu, update_enemy_cards};
 pub use setup::setup_combat;
 
-// Réexporter les fonctions de calcul pour usage futur (tests, etc.)
-#[allow(unused_imports)]
-pub use calculations::{DamageResult, calculate_damage, check_dodge};
+/// Combat tour par tour : écran, actions du joueur, tours ennemis et fin du combat
+pub struct CombatPlugin;
+
+impl Plugin for CombatPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Combat), setup_combat)
+            .add_systems(
+                Update,
+                (
+                    handle_combat,
+                    handle_enemy_turn,
+                    update_turn_order,
+                    update_health_bars,
+                    update_status_effects,
+                    update_combat_menu,
+                    select_target,
+                    update_enemy_cards,
+                )
+                    .run_if(in_state(GameState::Combat)),
+            )
+            .add_systems(OnExit(GameState::Combat), cleanup_combat);
+    }
+}
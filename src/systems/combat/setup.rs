@@ -1,25 +1,41 @@
 use crate::components::*;
+use crate::content::ContentRegistry;
+use crate::controls::KeyBindings;
 use crate::player::Player;
-use crate::resources::{CombatState, CurrentEnemy};
+use crate::resources::{CombatAction, CombatEnemy, CombatState};
 use bevy::prelude::*;
+use regles::combat::{EnemyFighter, PlayerFighter};
+
+/// Couleurs des boutons du menu d'actions
+pub const MENU_BUTTON_COLOR: Color = Color::rgb(0.15, 0.15, 0.15);
+pub const MENU_BUTTON_HOVER_COLOR: Color = Color::rgb(0.35, 0.35, 0.1);
+pub const MENU_BUTTON_DISABLED_COLOR: Color = Color::rgb(0.08, 0.08, 0.08);
 
 /// Configure l'interface de combat avec un layout 3 zones :
 /// - Gauche (30%) : Stats + barre de vie du joueur
 /// - Centre (40%) : Messages de combat et actions
-/// - Droite (30%) : Stats + barre de vie de l'ennemi
+/// - Droite (30%) : Une carte par ennemi (stats + barre de vie), cliquable pour le cibler
 pub fn setup_combat(
     mut commands: Commands,
     player_query: Query<&Player>,
-    current_enemy: Res<CurrentEnemy>,
     combat_state: Res<CombatState>,
+    registry: Res<ContentRegistry>,
+    bindings: Res<KeyBindings>,
 ) {
     let player = player_query.single();
 
-    let class_name = match player.class {
-        crate::player::PlayerClass::Warrior => "Guerrier",
-        crate::player::PlayerClass::Mage => "Magicien",
-        crate::player::PlayerClass::Assassin => "Assassin",
-        crate::player::PlayerClass::Executioner => "Bourreau",
+    let class_def = registry.class(&player.class);
+    let class_name = &class_def.name;
+
+    let initial_message = match &combat_state.enemy_turn {
+        Some((index, _)) => {
+            let enemy_name = &combat_state.enemies[*index].name;
+            format!(
+                "{} est plus rapide !\n\n--- TOUR ENNEMI ---\n\n{} se prepare...",
+                enemy_name, enemy_name
+            )
+        }
+        None => "Choisissez une action !".to_string(),
     };
 
     // Root container fullscreen
@@ -90,9 +106,10 @@ pub fn setup_combat(
                         })
                         .with_children(|hp_container| {
                             // Barre de vie remplie (calculée dynamiquement)
-                            let hp_percent =
-                                (combat_state.player_hp as f32 / player.stats.hp as f32 * 100.0)
-                                    .max(0.0);
+                            let hp_percent = (combat_state.combat.player.hp as f32
+                                / player.stats.hp as f32
+                                * 100.0)
+                                .max(0.0);
                             hp_container.spawn((
                                 NodeBundle {
                                     style: Style {
@@ -107,17 +124,26 @@ pub fn setup_combat(
                             ));
                         });
 
+                    // Effets de statut actifs du joueur
+                    player_panel.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                        )
+                        .with_style(Style {
+                            margin: UiRect::bottom(Val::Px(10.0)),
+                            ..default()
+                        }),
+                        StatusEffectsText::Player,
+                    ));
+
                     // Stats joueur (multi-lignes)
                     player_panel.spawn((
                         TextBundle::from_section(
-                            format!(
-                                "Points de vie: {} / {}\nAttaque: {}\nVitesse: {}\nCritique: {}%",
-                                combat_state.player_hp,
-                                player.stats.hp,
-                                player.stats.attack,
-                                player.stats.speed,
-                                player.stats.critical_chance
-                            ),
+                            player_stats_text(player, &combat_state.combat.player),
                             TextStyle {
                                 font_size: 20.0,
                                 color: Color::rgb(0.9, 0.9, 0.9),
@@ -154,15 +180,31 @@ pub fn setup_combat(
                             },
                         )
                         .with_style(Style {
-                            margin: UiRect::bottom(Val::Px(40.0)),
+                            margin: UiRect::bottom(Val::Px(20.0)),
                             ..default()
                         }),
                     );
 
+                    // Ordre des tours (rempli par update_turn_order)
+                    center_panel.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                        )
+                        .with_style(Style {
+                            margin: UiRect::bottom(Val::Px(20.0)),
+                            ..default()
+                        }),
+                        TurnOrderText,
+                    ));
+
                     // Message d'action (tour actuel + dernière action)
                     center_panel.spawn((
                         TextBundle::from_section(
-                            "Appuyez sur ESPACE pour attaquer !",
+                            initial_message,
                             TextStyle {
                                 font_size: 24.0,
                                 color: Color::rgb(1.0, 1.0, 0.2),
@@ -177,6 +219,33 @@ pub fn setup_combat(
                         ActionMessageText,
                     ));
 
+                    // Menu d'actions (clavier 1-5 ou clic)
+                    center_panel
+                        .spawn((
+                            NodeBundle {
+                                style: Style {
+                                    flex_direction: FlexDirection::Row,
+                                    flex_wrap: FlexWrap::Wrap,
+                                    justify_content: JustifyContent::Center,
+                                    margin: UiRect::bottom(Val::Px(30.0)),
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            CombatMenu,
+                        ))
+                        .with_children(|menu| {
+                            for action in CombatAction::ALL {
+                                // Le bouton Compétence porte le nom de la compétence de la classe
+                                let label = match action {
+                                    CombatAction::Skill => class_def.skill.name.as_str(),
+                                    _ => action.label(),
+                                };
+                                let key_hint = bindings.hint(action.input_action());
+                                spawn_action_button(menu, action, &key_hint, label);
+                            }
+                        });
+
                     // Log de combat (historique compact)
                     center_panel.spawn((
                         TextBundle::from_section(
@@ -192,7 +261,7 @@ pub fn setup_combat(
                     ));
                 });
 
-            // ===== PANNEAU DROIT : ENNEMI =====
+            // ===== PANNEAU DROIT : ENNEMIS =====
             parent
                 .spawn(NodeBundle {
                     style: Style {
@@ -208,76 +277,196 @@ pub fn setup_combat(
                     ..default()
                 })
                 .with_children(|enemy_panel| {
-                    // Titre ennemi
-                    enemy_panel.spawn(
-                        TextBundle::from_section(
-                            current_enemy.enemy_type.get_name(),
-                            TextStyle {
-                                font_size: 28.0,
-                                color: Color::rgb(1.0, 0.2, 0.2),
-                                ..default()
-                            },
-                        )
-                        .with_style(Style {
-                            margin: UiRect::bottom(Val::Px(15.0)),
-                            ..default()
-                        }),
-                    );
+                    for (index, (enemy, fighter)) in combat_state
+                        .enemies
+                        .iter()
+                        .zip(&combat_state.combat.enemies)
+                        .enumerate()
+                    {
+                        spawn_enemy_card(enemy_panel, index, enemy, fighter);
+                    }
+                });
+        });
+}
 
-                    // Barre de vie ennemi - Container
-                    enemy_panel
-                        .spawn(NodeBundle {
-                            style: Style {
-                                width: Val::Percent(100.0),
-                                height: Val::Px(30.0),
-                                margin: UiRect::bottom(Val::Px(10.0)),
-                                border: UiRect::all(Val::Px(2.0)),
-                                ..default()
-                            },
-                            background_color: Color::rgba(0.2, 0.2, 0.2, 0.8).into(),
-                            border_color: Color::rgb(1.0, 0.2, 0.2).into(),
+/// Carte d'un ennemi : nom, barre de vie, effets actifs et stats
+/// La bordure signale la cible (mise à jour par `update_enemy_cards`)
+fn spawn_enemy_card(
+    parent: &mut ChildBuilder,
+    index: usize,
+    enemy: &CombatEnemy,
+    fighter: &EnemyFighter,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::End,
+                    padding: UiRect::all(Val::Px(10.0)),
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                background_color: Color::NONE.into(),
+                border_color: Color::NONE.into(),
+                ..default()
+            },
+            EnemyCard(index),
+        ))
+        .with_children(|card| {
+            // Nom de l'ennemi
+            card.spawn(
+                TextBundle::from_section(
+                    &enemy.name,
+                    TextStyle {
+                        font_size: 24.0,
+                        color: Color::rgb(1.0, 0.2, 0.2),
+                        ..default()
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect::bottom(Val::Px(8.0)),
+                    ..default()
+                }),
+            );
+
+            // Barre de vie ennemi - Container
+            card.spawn(NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(20.0),
+                    margin: UiRect::bottom(Val::Px(6.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                background_color: Color::rgba(0.2, 0.2, 0.2, 0.8).into(),
+                border_color: Color::rgb(1.0, 0.2, 0.2).into(),
+                ..default()
+            })
+            .with_children(|hp_container| {
+                // Barre de vie remplie (calculée dynamiquement)
+                let hp_percent = (fighter.hp as f32 / fighter.stats.hp as f32 * 100.0).max(0.0);
+                hp_container.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(hp_percent),
+                            height: Val::Percent(100.0),
                             ..default()
-                        })
-                        .with_children(|hp_container| {
-                            // Barre de vie remplie (calculée dynamiquement)
-                            let hp_percent = (combat_state.enemy_hp as f32
-                                / current_enemy.stats.hp as f32
-                                * 100.0)
-                                .max(0.0);
-                            hp_container.spawn((
-                                NodeBundle {
-                                    style: Style {
-                                        width: Val::Percent(hp_percent),
-                                        height: Val::Percent(100.0),
-                                        ..default()
-                                    },
-                                    background_color: Color::rgb(1.0, 0.2, 0.2).into(),
-                                    ..default()
-                                },
-                                HealthBar::Enemy,
-                            ));
-                        });
+                        },
+                        background_color: Color::rgb(1.0, 0.2, 0.2).into(),
+                        ..default()
+                    },
+                    HealthBar::Enemy(index),
+                ));
+            });
 
-                    // Stats ennemi (multi-lignes, alignées à droite)
-                    enemy_panel.spawn((
-                        TextBundle::from_section(
-                            format!(
-                                "Points de vie: {} / {}\nAttaque: {}\nVitesse: {}\nCritique: {}%",
-                                combat_state.enemy_hp,
-                                current_enemy.stats.hp,
-                                current_enemy.stats.attack,
-                                current_enemy.stats.speed,
-                                current_enemy.stats.critical_chance
-                            ),
-                            TextStyle {
-                                font_size: 20.0,
-                                color: Color::rgb(0.9, 0.9, 0.9),
-                                ..default()
-                            },
-                        )
-                        .with_text_alignment(TextAlignment::Right),
-                        EnemyStatsText,
-                    ));
-                });
+            // Effets de statut actifs de l'ennemi
+            card.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                )
+                .with_text_alignment(TextAlignment::Right)
+                .with_style(Style {
+                    margin: UiRect::bottom(Val::Px(6.0)),
+                    ..default()
+                }),
+                StatusEffectsText::Enemy(index),
+            ));
+
+            // Stats ennemi (multi-lignes, alignées à droite)
+            card.spawn((
+                TextBundle::from_section(
+                    enemy_stats_text(fighter),
+                    TextStyle {
+                        font_size: 16.0,
+                        color: Color::rgb(0.9, 0.9, 0.9),
+                        ..default()
+                    },
+                )
+                .with_text_alignment(TextAlignment::Right),
+                EnemyStatsText(index),
+            ));
+        });
+}
+
+/// Texte des stats d'un ennemi en combat
+pub fn enemy_stats_text(fighter: &EnemyFighter) -> String {
+    format!(
+        "Points de vie: {} / {}\nAttaque: {}\nVitesse: {}\nCritique: {}%",
+        fighter.hp.max(0),
+        fighter.stats.hp,
+        fighter.stats.attack,
+        fighter.stats.speed,
+        fighter.stats.critical_chance
+    )
+}
+
+/// Texte des stats du joueur en combat : PV, stats, mana et état de la compétence
+/// La ligne de mana n'est affichée que pour les classes qui en ont
+pub fn player_stats_text(player: &Player, fighter: &PlayerFighter) -> String {
+    let skill = &fighter.skill;
+    let mut text = format!(
+        "Points de vie: {} / {}\nAttaque: {}\nVitesse: {}\nCritique: {}%",
+        fighter.hp.max(0),
+        player.stats.hp,
+        player.stats.attack,
+        player.stats.speed,
+        player.stats.critical_chance
+    );
+    if fighter.max_mana > 0 {
+        text.push_str(&format!("\nMana: {} / {}", fighter.mana, fighter.max_mana));
+    }
+
+    let skill_status = if fighter.level < skill.unlock_level {
+        format!("niveau {} requis", skill.unlock_level)
+    } else if fighter.skill_cooldown > 0 {
+        format!("recharge {} tour(s)", fighter.skill_cooldown)
+    } else if fighter.mana < skill.mana_cost {
+        format!("{} mana requis", skill.mana_cost)
+    } else {
+        "prête".to_string()
+    };
+    text.push_str(&format!("\n\n{}: {}", skill.name, skill_status));
+    text
+}
+
+/// Crée un bouton du menu d'actions avec son libellé et sa touche
+/// La couleur est ensuite gérée par `update_combat_menu` (survol, action indisponible)
+fn spawn_action_button(
+    parent: &mut ChildBuilder,
+    action: CombatAction,
+    key_hint: &str,
+    label: &str,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+                    margin: UiRect::all(Val::Px(4.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                background_color: MENU_BUTTON_COLOR.into(),
+                border_color: Color::rgb(1.0, 1.0, 0.2).into(),
+                ..default()
+            },
+            CombatActionButton(action),
+        ))
+        .with_children(|button| {
+            button.spawn(TextBundle::from_section(
+                format!("{}. {}", key_hint, label),
+                TextStyle {
+                    font_size: 18.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ));
         });
 }
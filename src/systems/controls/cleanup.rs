@@ -0,0 +1,20 @@
+use crate::components::ControlsUI;
+use crate::controls::{KeyBindings, save_bindings};
+use crate::resources::ControlsSelection;
+use bevy::prelude::*;
+
+/// Ferme l'écran des touches et enregistre les touches dans le fichier des touches
+pub fn cleanup_controls(
+    mut commands: Commands,
+    ui_query: Query<Entity, With<ControlsUI>>,
+    bindings: Res<KeyBindings>,
+) {
+    for entity in ui_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<ControlsSelection>();
+
+    if let Err(err) = save_bindings(&bindings) {
+        eprintln!("Échec de l'enregistrement des touches : {}", err);
+    }
+}
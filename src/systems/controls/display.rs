@@ -0,0 +1,69 @@
+use crate::components::{ControlsInfoText, ControlsListText};
+use crate::controls::{Action, KeyBindings};
+use crate::resources::ControlsSelection;
+use bevy::prelude::*;
+
+const SELECTED_COLOR: Color = Color::YELLOW;
+const WAITING_COLOR: Color = Color::rgb(1.0, 0.5, 0.2);
+const INFO_COLOR: Color = Color::rgb(0.6, 0.8, 1.0);
+
+/// Nombre d'actions dans la première colonne
+const FIRST_COLUMN_LEN: usize = Action::ALL.len().div_ceil(2);
+
+/// Réécrit la liste des actions quand la sélection ou les touches changent
+pub fn update_controls_ui(
+    selection: Res<ControlsSelection>,
+    bindings: Res<KeyBindings>,
+    mut info_query: Query<&mut Text, (With<ControlsInfoText>, Without<ControlsListText>)>,
+    mut list_query: Query<(&ControlsListText, &mut Text), Without<ControlsInfoText>>,
+) {
+    if !selection.is_changed() && !bindings.is_changed() {
+        return;
+    }
+
+    let style = |color: Color| TextStyle {
+        font_size: 18.0,
+        color,
+        ..default()
+    };
+
+    if let Ok(mut text) = info_query.get_single_mut() {
+        text.sections = vec![TextSection::new(
+            format!(
+                "Disposition de depart : {}\n{}",
+                bindings.preset.label(),
+                selection.message
+            ),
+            style(INFO_COLOR),
+        )];
+    }
+
+    for (column, mut text) in &mut list_query {
+        let actions = Action::ALL
+            .iter()
+            .enumerate()
+            .skip(column.0 * FIRST_COLUMN_LEN)
+            .take(FIRST_COLUMN_LEN);
+        text.sections = actions
+            .map(|(index, action)| {
+                let selected = index == selection.index;
+                let (keys, color) = if selected && selection.waiting {
+                    ("appuyez sur une touche...".to_string(), WAITING_COLOR)
+                } else if selected {
+                    (bindings.hint(*action), SELECTED_COLOR)
+                } else {
+                    (bindings.hint(*action), Color::WHITE)
+                };
+                TextSection::new(
+                    format!(
+                        "{} {} : {}\n",
+                        if selected { ">" } else { " " },
+                        action.label(),
+                        keys
+                    ),
+                    style(color),
+                )
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,67 @@
+use crate::controls::{Action, KeyBindings, KeyPreset, key_name};
+use crate::resources::ControlsSelection;
+use crate::states::GameState;
+use bevy::prelude::*;
+
+/// Gère l'écran des touches, avec des touches fixes (voir `CONTROLS_HINT`) :
+/// - Haut/Bas : change l'action sélectionnée
+/// - Entrée : la prochaine touche pressée devient la touche de l'action (Échap annule)
+/// - F1 / F2 : repart de la disposition AZERTY / QWERTY
+/// - Échap : retour à l'écran des sauvegardes (les touches sont enregistrées à la sortie)
+pub fn handle_controls_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut selection: ResMut<ControlsSelection>,
+    mut bindings: ResMut<KeyBindings>,
+) {
+    if selection.waiting {
+        let Some(key) = keyboard_input.get_just_pressed().next().copied() else {
+            return;
+        };
+        selection.waiting = false;
+        if key == KeyCode::Escape {
+            selection.message.clear();
+            return;
+        }
+        let action = Action::ALL[selection.index];
+        bindings.rebind(action, key);
+        let shared: Vec<&str> = Action::ALL
+            .iter()
+            .filter(|other| **other != action && other.shares_state_with(action))
+            .filter(|other| bindings.keys(**other).contains(&key))
+            .map(|other| other.label())
+            .collect();
+        selection.message = if shared.is_empty() {
+            format!("{} : {}", action.label(), key_name(key))
+        } else {
+            format!(
+                "{} : {} (aussi utilisee sur le meme ecran par : {})",
+                action.label(),
+                key_name(key),
+                shared.join(", ")
+            )
+        };
+        return;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        next_state.set(GameState::SaveSlots);
+    } else if keyboard_input.just_pressed(KeyCode::Up) {
+        selection.index = (selection.index + Action::ALL.len() - 1) % Action::ALL.len();
+    } else if keyboard_input.just_pressed(KeyCode::Down) {
+        selection.index = (selection.index + 1) % Action::ALL.len();
+    } else if keyboard_input.just_pressed(KeyCode::Return) {
+        selection.waiting = true;
+        selection.message.clear();
+    } else if keyboard_input.just_pressed(KeyCode::F1) {
+        apply_preset(KeyPreset::Azerty, &mut bindings, &mut selection);
+    } else if keyboard_input.just_pressed(KeyCode::F2) {
+        apply_preset(KeyPreset::Qwerty, &mut bindings, &mut selection);
+    }
+}
+
+/// Remplace toutes les touches par celles de la disposition
+fn apply_preset(preset: KeyPreset, bindings: &mut KeyBindings, selection: &mut ControlsSelection) {
+    *bindings = KeyBindings::from_preset(preset);
+    selection.message = format!("Disposition {} chargee", preset.label());
+}
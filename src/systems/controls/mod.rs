@@ -0,0 +1,28 @@
+mod cleanup;
+mod display;
+mod input;
+mod setup;
+
+use crate::states::GameState;
+use bevy::prelude::*;
+
+pub use cleanup::cleanup_controls;
+pub use display::update_controls_ui;
+pub use input::handle_controls_input;
+pub use setup::setup_controls;
+
+/// Écran des touches, ouvert depuis l'écran des sauvegardes
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Controls), setup_controls)
+            .add_systems(
+                Update,
+                (handle_controls_input, update_controls_ui)
+                    .chain()
+                    .run_if(in_state(GameState::Controls)),
+            )
+            .add_systems(OnExit(GameState::Controls), cleanup_controls);
+    }
+}
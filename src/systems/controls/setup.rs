@@ -0,0 +1,82 @@
+use crate::components::{ControlsInfoText, ControlsListText, ControlsUI};
+use crate::resources::ControlsSelection;
+use bevy::prelude::*;
+
+/// Touches fixes de l'écran, pour ne jamais se retrouver bloqué par une mauvaise configuration
+const CONTROLS_HINT: &str =
+    "HAUT/BAS : choisir | ENTREE : changer la touche | F1 : AZERTY | F2 : QWERTY | ECHAP : retour";
+
+/// Crée l'écran des touches ; la liste est remplie par `update_controls_ui`
+pub fn setup_controls(mut commands: Commands) {
+    commands.insert_resource(ControlsSelection::default());
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::all(Val::Px(20.0)),
+                    ..default()
+                },
+                background_color: Color::rgb(0.1, 0.1, 0.15).into(),
+                z_index: ZIndex::Global(2000),
+                ..default()
+            },
+            ControlsUI,
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+                TextBundle::from_section(
+                    "TOUCHES",
+                    TextStyle {
+                        font_size: 32.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                }),
+            );
+
+            parent.spawn((
+                TextBundle::from_section("", TextStyle::default()).with_style(Style {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                }),
+                ControlsInfoText,
+            ));
+
+            // Deux colonnes pour que toutes les actions tiennent dans la fenêtre
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_grow: 1.0,
+                        column_gap: Val::Px(40.0),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|columns| {
+                    for column in 0..2 {
+                        columns.spawn((
+                            TextBundle::from_section("", TextStyle::default()),
+                            ControlsListText(column),
+                        ));
+                    }
+                });
+
+            parent.spawn(TextBundle::from_section(
+                CONTROLS_HINT,
+                TextStyle {
+                    font_size: 16.0,
+                    color: Color::GRAY,
+                    ..default()
+                },
+            ));
+        });
+}
@@ -0,0 +1,10 @@
+use crate::components::InventoryUI;
+use crate::resources::InventorySelection;
+use bevy::prelude::*;
+
+pub fn cleanup_inventory(mut commands: Commands, ui_query: Query<Entity, With<InventoryUI>>) {
+    for entity in ui_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<InventorySelection>();
+}
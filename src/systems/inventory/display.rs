@@ -0,0 +1,174 @@
+use crate::components::{InventoryListText, InventoryPreviewText};
+use crate::inventory::{Inventory, InventoryEntry};
+use crate::player::Player;
+use crate::resources::InventorySelection;
+use bevy::prelude::*;
+use regles::consumable::ConsumableEffect;
+use regles::stats::Stats;
+
+const SELECTED_COLOR: Color = Color::YELLOW;
+const GAIN_COLOR: Color = Color::rgb(0.3, 0.9, 0.3);
+const LOSS_COLOR: Color = Color::rgb(0.9, 0.3, 0.3);
+
+/// Réécrit la liste et l'aperçu quand la sélection ou l'équipement change
+/// L'aperçu compare les stats actuelles à celles obtenues en validant la ligne sélectionnée
+pub fn update_inventory_ui(
+    selection: Res<InventorySelection>,
+    player_query: Query<(&Player, &Inventory)>,
+    mut list_query: Query<&mut Text, (With<InventoryListText>, Without<InventoryPreviewText>)>,
+    mut preview_query: Query<&mut Text, (With<InventoryPreviewText>, Without<InventoryListText>)>,
+) {
+    if !selection.is_changed() {
+        return;
+    }
+    let (player, inventory) = player_query.single();
+    let entries = inventory.entries();
+
+    if let Ok(mut text) = list_query.get_single_mut() {
+        text.sections = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let selected = index == selection.0;
+                let line = entry_line(inventory, *entry);
+                // Nom coloré selon la rareté, sauf pour la ligne sélectionnée
+                let color = match (entry, inventory.item(*entry)) {
+                    _ if selected => SELECTED_COLOR,
+                    (_, Some(item)) => item.rarity.color(),
+                    (InventoryEntry::Consumable(_), None) => Color::WHITE,
+                    _ => Color::GRAY,
+                };
+                let header = match (index, entry) {
+                    (0, _) => "=== Équipement ===\n",
+                    (_, InventoryEntry::Bag(0)) => "\n=== Sac ===\n",
+                    (_, InventoryEntry::Consumable(0)) => "\n=== Consommables ===\n",
+                    _ => "",
+                };
+                TextSection::new(
+                    format!("{}{} {}\n", header, if selected { ">" } else { " " }, line),
+                    TextStyle {
+                        font_size: 20.0,
+                        color,
+                        ..default()
+                    },
+                )
+            })
+            .collect();
+    }
+
+    if let Ok(mut text) = preview_query.get_single_mut() {
+        text.sections = preview_sections(player, inventory, entries[selection.0]);
+    }
+}
+
+/// Texte d'une ligne : emplacement et objet équipé, objet du sac et son emplacement,
+/// ou pile de consommables et son effet
+fn entry_line(inventory: &Inventory, entry: InventoryEntry) -> String {
+    if let InventoryEntry::Consumable(index) = entry {
+        let stack = &inventory.consumables[index];
+        return format!(
+            "{} x{} ({})",
+            stack.name,
+            stack.count,
+            stack.effect.description()
+        );
+    }
+    match (entry, inventory.item(entry)) {
+        (InventoryEntry::Equipped(slot), Some(item)) => format!(
+            "{} : {} ({})",
+            slot.label(),
+            item.display_name(),
+            item.stats.bonus_summary()
+        ),
+        (InventoryEntry::Equipped(slot), None) => format!("{} : (vide)", slot.label()),
+        (InventoryEntry::Bag(_) | InventoryEntry::Consumable(_), Some(item)) => format!(
+            "{} [{}] ({})",
+            item.display_name(),
+            item.slot.label(),
+            item.stats.bonus_summary()
+        ),
+        (InventoryEntry::Bag(_) | InventoryEntry::Consumable(_), None) => String::new(),
+    }
+}
+
+/// Aperçu « stat actuelle -> stat après », coloré selon le gain ou la perte
+fn preview_sections(
+    player: &Player,
+    inventory: &Inventory,
+    entry: InventoryEntry,
+) -> Vec<TextSection> {
+    let style = |color: Color| TextStyle {
+        font_size: 20.0,
+        color,
+        ..default()
+    };
+
+    if let InventoryEntry::Consumable(index) = entry {
+        let stack = &inventory.consumables[index];
+        let mut text = format!(
+            "Utiliser {} :\n\n{}",
+            stack.name,
+            stack.effect.description()
+        );
+        if let ConsumableEffect::Heal(amount) = stack.effect {
+            let after = (player.current_hp + amount).min(player.stats.hp);
+            text.push_str(&format!(
+                "\n\nPV : {} -> {} / {}",
+                player.current_hp, after, player.stats.hp
+            ));
+        }
+        return vec![TextSection::new(text, style(Color::WHITE))];
+    }
+
+    let Some(item) = inventory.item(entry) else {
+        return vec![TextSection::new("Emplacement vide", style(Color::GRAY))];
+    };
+    let title = match entry {
+        InventoryEntry::Equipped(_) => format!("Retirer {} :\n\n", item.display_name()),
+        InventoryEntry::Bag(_) => match inventory.equipped.get(&item.slot) {
+            Some(current) => format!(
+                "Équiper {} (remplace {}) :\n\n",
+                item.display_name(),
+                current.display_name()
+            ),
+            None => format!("Équiper {} :\n\n", item.display_name()),
+        },
+        InventoryEntry::Consumable(_) => String::new(),
+    };
+
+    let mut after_inventory = inventory.clone();
+    after_inventory.toggle(entry);
+    let mut after = player.base_stats;
+    after += after_inventory.bonus();
+
+    let mut sections = vec![TextSection::new(title, style(Color::WHITE))];
+    for (label, before, after, unit) in stat_rows(&player.stats, &after) {
+        let diff = after - before;
+        sections.push(TextSection::new(
+            format!("{} : {}{} -> {}{}", label, before, unit, after, unit),
+            style(Color::WHITE),
+        ));
+        let (diff_text, color) = match diff {
+            0 => (String::new(), Color::WHITE),
+            d if d > 0 => (format!(" ({:+}{})", d, unit), GAIN_COLOR),
+            d => (format!(" ({:+}{})", d, unit), LOSS_COLOR),
+        };
+        sections.push(TextSection::new(format!("{}\n", diff_text), style(color)));
+    }
+    sections
+}
+
+/// Lignes de l'aperçu : (libellé, avant, après, unité)
+fn stat_rows(before: &Stats, after: &Stats) -> [(&'static str, i32, i32, &'static str); 4] {
+    [
+        ("PV max", before.hp, after.hp, ""),
+        ("Attaque", before.attack, after.attack, ""),
+        ("Vitesse", before.speed, after.speed, ""),
+        (
+            "Critique",
+            before.critical_chance,
+            after.critical_chance,
+            "%",
+        ),
+    ]
+}
@@ -0,0 +1,65 @@
+use crate::controls::{Action, Controls};
+use crate::inventory::{Inventory, InventoryEntry};
+use crate::player::Player;
+use crate::resources::{GameLog, InventorySelection};
+use crate::states::GameState;
+use crate::systems::player::use_consumable_on_map;
+use bevy::prelude::*;
+
+/// Ouvre l'inventaire depuis la map avec la touche I (par défaut)
+pub fn open_inventory(controls: Controls, mut next_state: ResMut<NextState<GameState>>) {
+    if controls.just_pressed(Action::Inventory) {
+        next_state.set(GameState::Inventory);
+    }
+}
+
+/// Gère l'écran d'inventaire :
+/// - Haut/Bas (ou Z/S) : change la ligne sélectionnée
+/// - Entrée : équipe l'objet du sac sélectionné, retire l'objet de l'emplacement sélectionné
+///   ou utilise le consommable sélectionné (soins uniquement hors combat)
+/// - I ou Échap : retour à la map
+///
+/// Les stats finales du joueur sont recalculées à chaque changement d'équipement
+pub fn handle_inventory_input(
+    controls: Controls,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut selection: ResMut<InventorySelection>,
+    mut player_query: Query<(&mut Player, &mut Inventory)>,
+    mut game_log: ResMut<GameLog>,
+) {
+    if controls.just_pressed(Action::Inventory) || controls.just_pressed(Action::Cancel) {
+        next_state.set(GameState::Map);
+        return;
+    }
+
+    let (mut player, mut inventory) = player_query.single_mut();
+    let entries = inventory.entries();
+
+    if controls.just_pressed(Action::MoveUp) {
+        selection.0 = (selection.0 + entries.len() - 1) % entries.len();
+    } else if controls.just_pressed(Action::MoveDown) {
+        selection.0 = (selection.0 + 1) % entries.len();
+    } else if controls.just_pressed(Action::Confirm) {
+        let entry = entries[selection.0];
+        if let InventoryEntry::Consumable(index) = entry {
+            use_consumable_on_map(index, &mut player, &mut inventory, &mut game_log);
+            selection.0 = selection.0.min(inventory.entries().len() - 1);
+            selection.set_changed();
+            return;
+        }
+        let Some(name) = inventory.item(entry).map(|item| item.display_name()) else {
+            return;
+        };
+        let equipping = matches!(entry, InventoryEntry::Bag(_));
+        inventory.toggle(entry);
+        player.apply_equipment(&inventory);
+        game_log.add_message(if equipping {
+            format!("{} équipé", name)
+        } else {
+            format!("{} retiré", name)
+        });
+        // Le sac a changé : la sélection reste dans la liste
+        selection.0 = selection.0.min(inventory.entries().len() - 1);
+        selection.set_changed();
+    }
+}
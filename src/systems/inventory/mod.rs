@@ -0,0 +1,29 @@
+mod cleanup;
+mod display;
+mod input;
+mod setup;
+
+use crate::states::GameState;
+use bevy::prelude::*;
+
+pub use cleanup::cleanup_inventory;
+pub use display::update_inventory_ui;
+pub use input::{handle_inventory_input, open_inventory};
+pub use setup::setup_inventory;
+
+/// Écran d'inventaire et son ouverture depuis la map
+pub struct InventoryPlugin;
+
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, open_inventory.run_if(in_state(GameState::Map)))
+            .add_systems(OnEnter(GameState::Inventory), setup_inventory)
+            .add_systems(
+                Update,
+                (handle_inventory_input, update_inventory_ui)
+                    .chain()
+                    .run_if(in_state(GameState::Inventory)),
+            )
+            .add_systems(OnExit(GameState::Inventory), cleanup_inventory);
+    }
+}
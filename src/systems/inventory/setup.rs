@@ -0,0 +1,93 @@
+use crate::components::{InventoryListText, InventoryPreviewText, InventoryUI};
+use crate::controls::{Action, KeyBindings};
+use crate::resources::InventorySelection;
+use bevy::prelude::*;
+
+fn inventory_hint(bindings: &KeyBindings) -> String {
+    format!(
+        "{} / {} : choisir | {} : equiper / retirer / utiliser | {} ou {} : fermer",
+        bindings.hint(Action::MoveUp),
+        bindings.hint(Action::MoveDown),
+        bindings.hint(Action::Confirm),
+        bindings.hint(Action::Inventory),
+        bindings.hint(Action::Cancel)
+    )
+}
+
+/// Crée l'écran d'inventaire : emplacements d'équipement et sac à gauche,
+/// aperçu des stats après équipement à droite
+/// Les textes sont remplis par `update_inventory_ui`
+pub fn setup_inventory(mut commands: Commands, bindings: Res<KeyBindings>) {
+    commands.insert_resource(InventorySelection::default());
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::all(Val::Px(20.0)),
+                    ..default()
+                },
+                background_color: Color::rgb(0.1, 0.1, 0.15).into(),
+                z_index: ZIndex::Global(2000),
+                ..default()
+            },
+            InventoryUI,
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+                TextBundle::from_section(
+                    "INVENTAIRE",
+                    TextStyle {
+                        font_size: 32.0,
+                        color: Color::rgb(0.8, 0.8, 0.2),
+                        ..default()
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect::bottom(Val::Px(20.0)),
+                    ..default()
+                }),
+            );
+
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        flex_grow: 1.0,
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceAround,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        TextBundle::from_section("", TextStyle::default()).with_style(Style {
+                            width: Val::Percent(50.0),
+                            ..default()
+                        }),
+                        InventoryListText,
+                    ));
+                    parent.spawn((
+                        TextBundle::from_section("", TextStyle::default()).with_style(Style {
+                            width: Val::Percent(40.0),
+                            ..default()
+                        }),
+                        InventoryPreviewText,
+                    ));
+                });
+
+            parent.spawn(TextBundle::from_section(
+                inventory_hint(&bindings),
+                TextStyle {
+                    font_size: 16.0,
+                    color: Color::GRAY,
+                    ..default()
+                },
+            ));
+        });
+}
@@ -1,38 +1,68 @@
 use crate::components::Position;
+use crate::content::{ContentRegistry, DataLoadError};
 use crate::enemy::EnemyType;
 use crate::item::ItemType;
+use crate::systems::map::generator::Dungeon;
+use crate::systems::map::loader::load_maps;
 use bevy::prelude::Resource;
+use regles::grid::{Grid, Tile, is_walkable};
+use std::borrow::Cow;
 use std::collections::HashMap;
-
-/// Les différents types de tuiles qui composent la carte
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum Tile {
-    Wall,       // Mur infranchissable (bloque le déplacement)
-    Path,       // Chemin praticable (permet le déplacement)
-    Connection, // Point de connexion vers une autre map
-}
+use std::path::Path;
 
 /// Représente une carte du jeu
 /// Contient la grille de tuiles, les objets, les ennemis et les connexions
 #[derive(Resource, Clone, Default)]
 pub struct Map {
-    pub grid: Vec<Vec<Tile>>,
+    /// Nom du fichier sans extension (map_01), utilisé par les connexions et les quêtes
+    pub name: String,
+    pub grid: Grid,
     pub player_start: Position,
     pub width: usize,
     pub height: usize,
     pub connections: HashMap<Position, (usize, Position)>,
     pub items: Vec<(Position, ItemType)>,
     pub enemies: Vec<(Position, EnemyType)>,
+    /// Groupes d'ennemis (positions dans `enemies`) combattus ensemble
+    pub packs: Vec<Vec<Position>>,
+    /// Coffres et l'or qu'ils contiennent
+    pub chests: Vec<(Position, u32)>,
+    /// Objets vendus par le marchand de la map
+    pub shop: Vec<ItemType>,
 }
 
 impl Map {
     /// Vérifie si une position donnée est praticable (pas un mur, dans les limites)
     /// Utilisé pour la validation du déplacement du joueur
     pub fn is_walkable(&self, x: usize, y: usize) -> bool {
-        if y >= self.grid.len() || x >= self.grid[y].len() {
-            return false;
-        }
-        matches!(self.grid[y][x], Tile::Path | Tile::Connection)
+        is_walkable(&self.grid, x, y)
+    }
+
+    /// Vérifie si une position est un feu de camp
+    pub fn is_rest(&self, position: Position) -> bool {
+        self.tile_at(position) == Some(Tile::Rest)
+    }
+
+    /// Vérifie si une position est un marchand
+    pub fn is_merchant(&self, position: Position) -> bool {
+        self.tile_at(position) == Some(Tile::Merchant)
+    }
+
+    fn tile_at(&self, position: Position) -> Option<Tile> {
+        self.grid
+            .get(position.y)
+            .and_then(|row| row.get(position.x))
+            .copied()
+    }
+
+    /// Positions des ennemis engagés quand le joueur rencontre l'ennemi en `position` :
+    /// tout son groupe s'il en a un, sinon lui seul
+    pub fn encounter_at(&self, position: Position) -> Vec<Position> {
+        self.packs
+            .iter()
+            .find(|pack| pack.contains(&position))
+            .cloned()
+            .unwrap_or_else(|| vec![position])
     }
 }
 
@@ -42,171 +72,51 @@ impl Map {
 pub struct GameData {
     pub maps: Vec<Map>,
     pub current_map_index: usize,
+    /// Donjon dont les étages suivent les maps de assets/maps/
+    pub dungeon: Dungeon,
 }
 
 impl GameData {
-    /// Crée les données de jeu avec toutes les maps prédéfinies
-    pub fn new() -> Self {
-        let mut maps = Vec::new();
-
-        // Map 1 - Agrandie avec des ennemis
-        let layout1 = vec![
-            "WWWWWWWWWWWWWW",
-            "WP P P P P P W",
-            "W WWWW P WWW W",
-            "W P  P P   P W",
-            "W P WWWWWW P W",
-            "W P  P   P P C", // Connection at x=13, y=5
-            "W WWWP WWW P W",
-            "W P  P   P P W",
-            "W P P P  P P W",
-            "WWWWWWWWWWWWWW",
-        ];
-        let mut connections1 = HashMap::new();
-        connections1.insert(Position { x: 13, y: 5 }, (1, Position { x: 1, y: 5 }));
-        let items1 = vec![
-            (Position { x: 2, y: 3 }, ItemType::Katana),
-            (Position { x: 7, y: 7 }, ItemType::Armure),
-        ];
-        let enemies1 = vec![
-            (Position { x: 4, y: 1 }, EnemyType::SmallGoblin),
-            (Position { x: 8, y: 3 }, EnemyType::SmallGoblin),
-            (Position { x: 5, y: 5 }, EnemyType::MediumGoblin),
-            (Position { x: 10, y: 7 }, EnemyType::SmallGoblin),
-        ];
-
-        let grid1: Vec<Vec<Tile>> = layout1
-            .iter()
-            .map(|row| {
-                row.chars()
-                    .map(|c| match c {
-                        'W' => Tile::Wall,
-                        'C' => Tile::Connection,
-                        _ => Tile::Path,
-                    })
-                    .collect()
-            })
-            .collect();
-
-        let height1 = grid1.len();
-        let width1 = grid1.get(0).map_or(0, |row| row.len());
-
-        maps.push(Map {
-            grid: grid1,
-            player_start: Position { x: 1, y: 1 },
-            width: width1,
-            height: height1,
-            connections: connections1,
-            items: items1,
-            enemies: enemies1,
-        });
-
-        // Map 2 - Agrandie avec plus d'ennemis
-        let layout2 = vec![
-            "WWWWWWWWWWWWWW",
-            "W P P P P P PC",
-            "W W PPPPPPPP W", // Connection at x=13, y=2
-            "W P P  P   P W",
-            "W WWWP WWWWP W",
-            "C P  P     P W",
-            "W WWWWWWWW P W",
-            "W P    P P P W",
-            "W P PP P P P W",
-            "WWWWWWWWWWWWWW",
-        ];
-        let mut connections2 = HashMap::new();
-        connections2.insert(Position { x: 0, y: 5 }, (0, Position { x: 12, y: 5 }));
-        connections2.insert(Position { x: 13, y: 1 }, (2, Position { x: 1, y: 1}));
-        let items2 = vec![
-            (Position { x: 10, y: 2 }, ItemType::Gants),
-            (Position { x: 11, y: 7 }, ItemType::Pendentif),
-        ];
-        let enemies2 = vec![
-            (Position { x: 5, y: 2 }, EnemyType::MediumGoblin),
-            (Position { x: 8, y: 3 }, EnemyType::MediumGoblin),
-            (Position { x: 10, y: 5 }, EnemyType::LargeGoblin),
-            (Position { x: 6, y: 7 }, EnemyType::MediumGoblin),
-            (Position { x: 11, y: 8 }, EnemyType::Wolf),
-        ];
-
-        let grid2: Vec<Vec<Tile>> = layout2
-            .iter()
-            .map(|row| {
-                row.chars()
-                    .map(|c| match c {
-                        'W' => Tile::Wall,
-                        'C' => Tile::Connection,
-                        _ => Tile::Path,
-                    })
-                    .collect()
-            })
-            .collect();
-
-        let height2 = grid2.len();
-        let width2 = grid2.get(0).map_or(0, |row| row.len());
-
-        maps.push(Map {
-            grid: grid2,
-            player_start: Position { x: 1, y: 1 },
-            width: width2,
-            height: height2,
-            connections: connections2,
-            items: items2,
-            enemies: enemies2,
-        });
-
-        let layout3 = vec![
-            "WWWWWWWWWWWWWW",
-            "C P          W",
-            "WWWWWWWWWWWW W",
-            "W PP P P P P W",
-            "W WWWWWWWWWWWW",
-            "W WWWWWWWWWWWW",
-            "W            W",
-            "WWWWWWWWWWWW W",
-            "W P PP P P P W",
-            "WWWWWWWWWWWWWW",
-        ];
-
-        let mut connections3 = HashMap::new();
-        connections3.insert(Position { x: 0, y: 1 }, (1, Position { x: 12, y: 1 }));
-        let items3 = vec![];
-        let enemies3 = vec![
-            (Position { x: 11, y: 3 }, EnemyType::Drake),
-            (Position { x: 9, y: 6 }, EnemyType::DeathBird),
-            (Position { x: 3, y: 8 }, EnemyType::Snake),
-        ];
-
-        let grid3: Vec<Vec<Tile>> = layout3
-            .iter()
-            .map(|row| {
-                row.chars()
-                    .map(|c| match c {
-                        'W' => Tile::Wall,
-                        'C' => Tile::Connection,
-                        _ => Tile::Path,
-                    })
-                    .collect()
-            })
-            .collect();
-
-        let height3 = grid3.len();
-        let width3 = grid3.get(0).map_or(0, |row| row.len());
-
-        maps.push(Map {
-            grid: grid3,
-            player_start: Position { x: 1, y: 1 },
-            width: width3,
-            height: height3,
-            connections: connections3,
-            items: items3,
-            enemies: enemies3,
-        });
-
-        GameData {
+    /// Charge toutes les maps depuis les fichiers `.ron` du dossier donné, puis ajoute
+    /// les étages du donjon généré à leur suite (graine 0 jusqu'au début d'une partie)
+    /// Voir `loader::load_maps` pour le format et l'ordre des maps, `generator::Dungeon`
+    /// pour le donjon
+    pub fn load(dir: &Path, registry: &ContentRegistry) -> Result<Self, DataLoadError> {
+        let maps = load_maps(dir, registry)?;
+        let dungeon = Dungeon::new(&maps, &registry.dungeon, dir)?;
+        let mut game_data = GameData {
             maps,
             current_map_index: 0,
+            dungeon,
+        };
+        game_data.set_dungeon_seed(0);
+        Ok(game_data)
+    }
+
+    /// Remplace les étages du donjon par ceux de la graine donnée (nouvelle partie ou
+    /// chargement d'une sauvegarde)
+    pub fn set_dungeon_seed(&mut self, seed: u64) {
+        let floors = self.dungeon.floors(seed);
+        let first_floor = self.dungeon.first_floor();
+        let arrival = floors[0].player_start;
+        self.maps.truncate(first_floor);
+        self.maps.extend(floors);
+        self.maps[self.dungeon.entrance_index()]
+            .connections
+            .insert(self.dungeon.entrance(), (first_floor, arrival));
+        self.dungeon.seed = seed;
+    }
+
+    /// Map `index` telle qu'elle est avec le donjon de graine `seed` : les étages d'une autre
+    /// graine que l'actuelle sont générés à la demande (vérification d'une sauvegarde)
+    pub fn map_with_seed(&self, index: usize, seed: u64) -> Option<Cow<'_, Map>> {
+        let first_floor = self.dungeon.first_floor();
+        if index < first_floor || seed == self.dungeon.seed {
+            return self.maps.get(index).map(Cow::Borrowed);
         }
+        let mut floors = self.dungeon.floors(seed);
+        (index - first_floor < floors.len())
+            .then(|| Cow::Owned(floors.swap_remove(index - first_floor)))
     }
 
     /// Retourne une référence à la map actuellement active
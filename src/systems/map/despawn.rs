@@ -1,14 +1,15 @@
 use crate::components::MapTile;
 use crate::enemy::Enemy;
-use crate::item::Item;
+use crate::item::{Chest, Item};
 use bevy::prelude::*;
 
 /// Détruit toutes les entités de la map lors du changement d'état
-/// Nettoie les tuiles, objets et ennemis pour préparer le chargement de la nouvelle map
+/// Nettoie les tuiles, objets, coffres et ennemis pour préparer le chargement de la nouvelle map
 pub fn despawn_map(
     mut commands: Commands,
     map_tile_query: Query<Entity, With<MapTile>>,
     item_query: Query<Entity, With<Item>>,
+    chest_query: Query<Entity, With<Chest>>,
     enemy_query: Query<Entity, With<Enemy>>,
 ) {
     for entity in map_tile_query.iter() {
@@ -17,6 +18,9 @@ pub fn despawn_map(
     for entity in item_query.iter() {
         commands.entity(entity).despawn();
     }
+    for entity in chest_query.iter() {
+        commands.entity(entity).despawn();
+    }
     for entity in enemy_query.iter() {
         commands.entity(entity).despawn();
     }
@@ -0,0 +1,192 @@
+use crate::components::Position;
+use crate::content::DataLoadError;
+use crate::dungeon::DungeonDef;
+use crate::systems::map::Map;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use regles::dungeon::{
+    Cell, Floor, ITEMS_PER_FLOOR, MIN_FLOOR_SIZE, depth_band, enemy_count, generate_floor,
+};
+use regles::grid::Tile;
+use std::path::Path;
+
+/// Donjon généré à la suite des maps de assets/maps/ : sa définition, son entrée vérifiée au
+/// chargement et la graine des étages actuels
+#[derive(Debug, Clone)]
+pub struct Dungeon {
+    def: DungeonDef,
+    /// Map de l'entrée et case où le joueur revient en remontant du premier étage
+    entrance_index: usize,
+    exit: Position,
+    /// Index du premier étage dans les maps (nombre de maps de assets/maps/)
+    first_floor: usize,
+    /// Largeur et hauteur des étages, celles de la première map
+    size: (usize, usize),
+    pub seed: u64,
+}
+
+impl Dungeon {
+    /// Vérifie que la première map chargée depuis `dir` est assez grande pour les étages et que
+    /// l'entrée de dungeon.ron est une tuile C libre d'une des maps
+    /// (les étages sont générés ensuite par `GameData::set_dungeon_seed`)
+    pub fn new(maps: &[Map], def: &DungeonDef, dir: &Path) -> Result<Self, DataLoadError> {
+        let invalid = |message: String| DataLoadError::Invalid {
+            path: dir.to_path_buf(),
+            message: format!("dungeon.ron : {}", message),
+        };
+
+        let Some(first_map) = maps.first() else {
+            return Err(invalid(
+                "aucune map pour donner la taille des étages".to_string(),
+            ));
+        };
+        let size = (first_map.width, first_map.height);
+        if size.0 < MIN_FLOOR_SIZE || size.1 < MIN_FLOOR_SIZE {
+            return Err(invalid(format!(
+                "les étages ont la taille de {} ({} × {}), trop petite (minimum {} × {})",
+                first_map.name, size.0, size.1, MIN_FLOOR_SIZE, MIN_FLOOR_SIZE
+            )));
+        }
+
+        let entrance = &def.entrance;
+        let Some(entrance_index) = maps.iter().position(|map| map.name == entrance.map) else {
+            return Err(invalid(format!(
+                "l'entrée est sur la map \"{}\" qui n'existe pas",
+                entrance.map
+            )));
+        };
+        let entrance_map = &maps[entrance_index];
+        let at = entrance.at;
+        if entrance_map.grid.get(at.y).and_then(|row| row.get(at.x)) != Some(&Tile::Connection) {
+            return Err(invalid(format!(
+                "l'entrée ({}, {}) de {} n'est pas sur une tuile C",
+                at.x, at.y, entrance.map
+            )));
+        }
+        if entrance_map.connections.contains_key(&at) {
+            return Err(invalid(format!(
+                "l'entrée ({}, {}) de {} est déjà une connexion de la map",
+                at.x, at.y, entrance.map
+            )));
+        }
+        let is_free = |&(x, y): &Cell| {
+            entrance_map.is_walkable(x, y) && entrance_map.grid[y][x] != Tile::Connection
+        };
+        let Some(exit) = [
+            (at.x + 1, at.y),
+            (at.x, at.y + 1),
+            (at.x.wrapping_sub(1), at.y),
+            (at.x, at.y.wrapping_sub(1)),
+        ]
+        .into_iter()
+        .find(is_free) else {
+            return Err(invalid(format!(
+                "l'entrée ({}, {}) de {} n'a aucune case praticable à côté",
+                at.x, at.y, entrance.map
+            )));
+        };
+
+        Ok(Dungeon {
+            def: def.clone(),
+            entrance_index,
+            exit: position(exit),
+            first_floor: maps.len(),
+            size,
+            seed: 0,
+        })
+    }
+
+    pub fn first_floor(&self) -> usize {
+        self.first_floor
+    }
+
+    pub fn entrance_index(&self) -> usize {
+        self.entrance_index
+    }
+
+    /// Tuile C de la map d'entrée qui mène au premier étage
+    pub fn entrance(&self) -> Position {
+        self.def.entrance.at
+    }
+
+    /// Génère les étages de la graine donnée, de la taille de la première map
+    ///
+    /// Les étages s'appellent donjon_1, donjon_2… Le premier remonte vers la map d'entrée,
+    /// chaque étage est relié au suivant par ses escaliers. Ennemis et objets sont tirés dans
+    /// une tranche de plus en plus forte des listes de dungeon.ron. Tout est tiré d'un seul
+    /// générateur initialisé avec la graine : la même graine donne toujours le même donjon
+    pub fn floors(&self, seed: u64) -> Vec<Map> {
+        let def = &self.def;
+        let (width, height) = self.size;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let floors: Vec<Floor> = (1..=def.floors)
+            .map(|depth| generate_floor(width, height, depth < def.floors, &mut rng))
+            .collect();
+
+        let mut maps = Vec::with_capacity(floors.len());
+        for (offset, floor) in floors.iter().enumerate() {
+            let depth = offset + 1;
+            let index = self.first_floor + offset;
+            let mut map = Map {
+                name: format!("donjon_{}", depth),
+                grid: floor.grid.clone(),
+                player_start: position(floor.up.arrival),
+                width,
+                height,
+                ..Default::default()
+            };
+
+            let up_to = if offset == 0 {
+                (self.entrance_index, self.exit)
+            } else {
+                let above = floors[offset - 1]
+                    .down
+                    .expect("seul le dernier étage n'a pas d'escalier");
+                (index - 1, position(above.arrival))
+            };
+            map.connections.insert(position(floor.up.at), up_to);
+            if let Some(down) = floor.down {
+                map.connections.insert(
+                    position(down.at),
+                    (index + 1, position(floors[offset + 1].up.arrival)),
+                );
+            }
+
+            // Les ennemis évitent si possible la salle d'arrivée, pour ne pas attaquer le joueur
+            // dès l'escalier descendu
+            let mut cells = floor.free_cells();
+            cells.shuffle(&mut rng);
+            cells.sort_by_key(|cell| floor.rooms[0].contains(*cell));
+            let enemies = &def.enemies[depth_band(def.enemies.len(), depth, def.floors)];
+            for _ in 0..enemy_count(depth) {
+                let (Some(enemy), Some(cell)) = (enemies.choose(&mut rng), next_cell(&mut cells))
+                else {
+                    break;
+                };
+                map.enemies.push((position(cell), enemy.clone()));
+            }
+
+            let items = &def.items[depth_band(def.items.len(), depth, def.floors)];
+            for _ in 0..ITEMS_PER_FLOOR {
+                let (Some(item), Some(cell)) = (items.choose(&mut rng), next_cell(&mut cells))
+                else {
+                    break;
+                };
+                map.items.push((position(cell), item.clone()));
+            }
+
+            maps.push(map);
+        }
+        maps
+    }
+}
+
+/// Première case libre restante (les cases hors salle d'arrivée sont en tête)
+fn next_cell(cells: &mut Vec<Cell>) -> Option<Cell> {
+    (!cells.is_empty()).then(|| cells.remove(0))
+}
+
+fn position((x, y): Cell) -> Position {
+    Position { x, y }
+}
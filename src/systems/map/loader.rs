@@ -0,0 +1,418 @@
+use crate::components::Position;
+use crate::content::{ContentRegistry, DataLoadError, list_ron_files, parse_ron, read_file};
+use crate::enemy::EnemyType;
+use crate::item::ItemType;
+use crate::quest::Objective;
+use crate::systems::map::Map;
+use regles::grid::Tile;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Contenu brut d'un fichier de map tel qu'écrit par les designers
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MapFile {
+    layout: Vec<String>,
+    player_start: Position,
+    #[serde(default)]
+    connections: Vec<ConnectionDef>,
+    #[serde(default)]
+    items: Vec<ItemDef>,
+    #[serde(default)]
+    enemies: Vec<EnemyDef>,
+    /// Groupes d'ennemis : rencontrer l'un d'eux engage tout le groupe dans le même combat
+    #[serde(default)]
+    packs: Vec<PackDef>,
+    #[serde(default)]
+    chests: Vec<ChestDef>,
+    /// Stock du marchand (tuile M), par id d'objet
+    #[serde(default)]
+    shop: Vec<ItemType>,
+}
+
+/// Connexion vers une autre map, désignée par le nom de son fichier (sans extension)
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConnectionDef {
+    at: Position,
+    to_map: String,
+    to: Position,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ItemDef {
+    at: Position,
+    item: ItemType,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EnemyDef {
+    at: Position,
+    enemy: EnemyType,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackDef {
+    enemies: Vec<EnemyDef>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChestDef {
+    at: Position,
+    gold: u32,
+}
+
+/// Convertit un caractère du layout en tuile
+fn parse_tile(c: char) -> Option<Tile> {
+    match c {
+        'W' => Some(Tile::Wall),
+        'C' => Some(Tile::Connection),
+        'P' | ' ' => Some(Tile::Path),
+        'R' => Some(Tile::Rest),
+        'M' => Some(Tile::Merchant),
+        _ => None,
+    }
+}
+
+/// Charge toutes les maps `*.ron` d'un dossier, triées par nom de fichier
+/// La première map (ordre alphabétique) est la map de départ
+/// Les connexions référencent les autres maps par leur nom de fichier sans extension,
+/// les objets, ennemis et le stock du marchand par leur id dans le ContentRegistry
+pub fn load_maps(dir: &Path, registry: &ContentRegistry) -> Result<Vec<Map>, DataLoadError> {
+    let paths = list_ron_files(dir)?;
+
+    let mut files = Vec::new();
+    for path in &paths {
+        let source = read_file(path)?;
+        let file: MapFile = parse_ron(path, &source)?;
+        let grid = parse_layout(path, &source, &file.layout)?;
+        files.push((source, file, grid));
+    }
+
+    // Index de chaque map par nom de fichier pour résoudre les connexions
+    let indices: HashMap<String, usize> = paths
+        .iter()
+        .enumerate()
+        .map(|(index, path)| (map_name(path), index))
+        .collect();
+
+    let mut maps: Vec<Map> = Vec::new();
+    for (path, (source, file, grid)) in paths.iter().zip(files) {
+        maps.push(build_map(path, &source, file, grid, &indices, registry)?);
+    }
+
+    // Les destinations des connexions ne peuvent être vérifiées qu'une fois toutes les maps chargées
+    for (path, map) in paths.iter().zip(&maps) {
+        for (to_map, to_position) in map.connections.values() {
+            if !maps[*to_map].is_walkable(to_position.x, to_position.y) {
+                return Err(DataLoadError::Invalid {
+                    path: path.clone(),
+                    message: format!(
+                        "la connexion vers {} arrive en ({}, {}) qui n'est pas praticable",
+                        map_name(&paths[*to_map]),
+                        to_position.x,
+                        to_position.y
+                    ),
+                });
+            }
+        }
+    }
+
+    // Les objectifs ReachMap des quêtes désignent une map par son nom de fichier
+    for quest in &registry.quests {
+        if let Objective::ReachMap(name) = &quest.objective
+            && !indices.contains_key(name)
+        {
+            return Err(DataLoadError::Invalid {
+                path: dir.to_path_buf(),
+                message: format!(
+                    "la quête \"{}\" demande d'atteindre la map \"{}\" qui n'existe pas",
+                    quest.id, name
+                ),
+            });
+        }
+    }
+
+    Ok(maps)
+}
+
+/// Convertit les lignes du layout en grille de tuiles
+/// Les erreurs (caractère inconnu, ligne de mauvaise longueur) pointent vers
+/// la ligne et la colonne exactes du fichier source
+fn parse_layout(
+    path: &Path,
+    source: &str,
+    rows: &[String],
+) -> Result<Vec<Vec<Tile>>, DataLoadError> {
+    let error = |y: usize, x: usize, message: String| match locate_layout_row(source, y) {
+        Some((line, column)) => DataLoadError::Parse {
+            path: path.to_path_buf(),
+            line,
+            column: column + x,
+            message,
+        },
+        None => DataLoadError::Invalid {
+            path: path.to_path_buf(),
+            message,
+        },
+    };
+
+    let Some(width) = rows
+        .first()
+        .map(|row| row.chars().count())
+        .filter(|&w| w > 0)
+    else {
+        return Err(DataLoadError::Invalid {
+            path: path.to_path_buf(),
+            message: "le layout est vide".to_string(),
+        });
+    };
+
+    let mut grid = Vec::with_capacity(rows.len());
+    for (y, row) in rows.iter().enumerate() {
+        let mut tiles = Vec::with_capacity(width);
+        for (x, c) in row.chars().enumerate() {
+            let tile = parse_tile(c).ok_or_else(|| {
+                error(
+                    y,
+                    x,
+                    format!("tuile inconnue '{}' (attendu W, C, P, R, M ou espace)", c),
+                )
+            })?;
+            tiles.push(tile);
+        }
+        if tiles.len() != width {
+            return Err(error(
+                y,
+                0,
+                format!(
+                    "la ligne y={} fait {} tuiles au lieu de {}",
+                    y,
+                    tiles.len(),
+                    width
+                ),
+            ));
+        }
+        grid.push(tiles);
+    }
+
+    Ok(grid)
+}
+
+/// Retrouve la position (ligne, colonne du premier caractère) de la ligne `y` du layout
+/// en parcourant les chaînes qui suivent la clé `layout` dans le fichier source
+fn locate_layout_row(source: &str, y: usize) -> Option<(usize, usize)> {
+    let mut in_layout = false;
+    let mut row_index = 0;
+    for (line_index, line) in source.lines().enumerate() {
+        let code = line.split("//").next().unwrap_or("");
+        if !in_layout {
+            in_layout = code.trim_start().starts_with("layout");
+            if !in_layout {
+                continue;
+            }
+        }
+        for (byte_index, _) in code.match_indices('"').step_by(2) {
+            if row_index == y {
+                let column = code[..=byte_index].chars().count() + 1;
+                return Some((line_index + 1, column));
+            }
+            row_index += 1;
+        }
+        if code.contains(']') {
+            return None;
+        }
+    }
+    None
+}
+
+/// Nom d'une map = nom du fichier sans extension (ex: "map_01")
+fn map_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Construit une Map à partir d'un fichier déjà parsé en vérifiant sa cohérence
+fn build_map(
+    path: &Path,
+    source: &str,
+    file: MapFile,
+    grid: Vec<Vec<Tile>>,
+    indices: &HashMap<String, usize>,
+    registry: &ContentRegistry,
+) -> Result<Map, DataLoadError> {
+    let mut map = Map {
+        name: map_name(path),
+        height: grid.len(),
+        width: grid[0].len(),
+        grid,
+        player_start: file.player_start,
+        ..Default::default()
+    };
+
+    let invalid = |message: String| DataLoadError::Invalid {
+        path: path.to_path_buf(),
+        message,
+    };
+
+    if !map.is_walkable(map.player_start.x, map.player_start.y) {
+        return Err(invalid(format!(
+            "player_start ({}, {}) n'est pas praticable",
+            map.player_start.x, map.player_start.y
+        )));
+    }
+
+    for connection in file.connections {
+        let Some(&to_map) = indices.get(&connection.to_map) else {
+            return Err(DataLoadError::at(
+                path,
+                source,
+                &format!("\"{}\"", connection.to_map),
+                format!("connexion vers une map inconnue \"{}\"", connection.to_map),
+            ));
+        };
+        if map
+            .grid
+            .get(connection.at.y)
+            .and_then(|row| row.get(connection.at.x))
+            != Some(&Tile::Connection)
+        {
+            return Err(invalid(format!(
+                "la connexion en ({}, {}) n'est pas sur une tuile C",
+                connection.at.x, connection.at.y
+            )));
+        }
+        map.connections
+            .insert(connection.at, (to_map, connection.to));
+    }
+
+    for item in file.items {
+        if !registry.has_item(&item.item) {
+            return Err(DataLoadError::at(
+                path,
+                source,
+                &format!("\"{}\"", item.item.0),
+                format!("objet inconnu \"{}\"", item.item.0),
+            ));
+        }
+        if !map.is_walkable(item.at.x, item.at.y) {
+            return Err(invalid(format!(
+                "l'objet {} en ({}, {}) est dans un mur",
+                item.item.0, item.at.x, item.at.y
+            )));
+        }
+        map.items.push((item.at, item.item));
+    }
+
+    // Les coffres ouverts sont mémorisés avec les objets collectés : leur position doit être libre
+    for chest in file.chests {
+        if !map.is_walkable(chest.at.x, chest.at.y) {
+            return Err(invalid(format!(
+                "le coffre en ({}, {}) est dans un mur",
+                chest.at.x, chest.at.y
+            )));
+        }
+        if map.items.iter().any(|(position, _)| *position == chest.at) {
+            return Err(invalid(format!(
+                "le coffre en ({}, {}) est sur un objet",
+                chest.at.x, chest.at.y
+            )));
+        }
+        map.chests.push((chest.at, chest.gold));
+    }
+
+    for item_type in &file.shop {
+        if !registry.has_item(item_type) {
+            return Err(DataLoadError::at(
+                path,
+                source,
+                &format!("\"{}\"", item_type.0),
+                format!(
+                    "objet inconnu \"{}\" dans le stock du marchand",
+                    item_type.0
+                ),
+            ));
+        }
+        if registry.item(item_type).price == 0 {
+            return Err(DataLoadError::at(
+                path,
+                source,
+                &format!("\"{}\"", item_type.0),
+                format!("l'objet \"{}\" n'a pas de prix (price)", item_type.0),
+            ));
+        }
+    }
+    let has_merchant = map
+        .grid
+        .iter()
+        .flatten()
+        .any(|tile| *tile == Tile::Merchant);
+    if has_merchant && file.shop.is_empty() {
+        return Err(invalid(
+            "la map a un marchand (M) mais pas de stock (shop)".to_string(),
+        ));
+    }
+    if !has_merchant && !file.shop.is_empty() {
+        return Err(invalid(
+            "la map a un stock (shop) mais pas de marchand (M)".to_string(),
+        ));
+    }
+    map.shop = file.shop;
+
+    // Les ennemis seuls puis ceux des groupes, tous placés sur la map de la même façon
+    let pack_sizes: Vec<usize> = file.packs.iter().map(|pack| pack.enemies.len()).collect();
+    let all_enemies = file
+        .enemies
+        .into_iter()
+        .chain(file.packs.into_iter().flat_map(|pack| pack.enemies));
+
+    for enemy in all_enemies {
+        if !registry.has_enemy(&enemy.enemy) {
+            return Err(DataLoadError::at(
+                path,
+                source,
+                &format!("\"{}\"", enemy.enemy.0),
+                format!("ennemi inconnu \"{}\"", enemy.enemy.0),
+            ));
+        }
+        if !map.is_walkable(enemy.at.x, enemy.at.y) {
+            return Err(invalid(format!(
+                "l'ennemi {} en ({}, {}) est dans un mur",
+                enemy.enemy.0, enemy.at.x, enemy.at.y
+            )));
+        }
+        // Les ennemis vaincus sont identifiés par leur position : elle doit être unique
+        if map
+            .enemies
+            .iter()
+            .any(|(position, _)| *position == enemy.at)
+        {
+            return Err(invalid(format!(
+                "plusieurs ennemis en ({}, {})",
+                enemy.at.x, enemy.at.y
+            )));
+        }
+        map.enemies.push((enemy.at, enemy.enemy));
+    }
+
+    let mut pack_start = map.enemies.len() - pack_sizes.iter().sum::<usize>();
+    for size in pack_sizes {
+        if size == 0 {
+            return Err(invalid("un groupe d'ennemis est vide".to_string()));
+        }
+        let pack = map.enemies[pack_start..pack_start + size]
+            .iter()
+            .map(|(position, _)| *position)
+            .collect();
+        map.packs.push(pack);
+        pack_start += size;
+    }
+
+    Ok(map)
+}
@@ -1,13 +1,28 @@
 mod data;
 mod despawn;
+mod generator;
+mod loader;
 mod spawn;
 mod transition;
 
+use crate::states::GameState;
+use bevy::prelude::*;
+
 pub use data::GameData;
 pub use despawn::despawn_map;
+pub use generator::Dungeon;
 pub use spawn::spawn_map;
 pub use transition::map_transition;
 
-// Réexporter pour usage futur
-#[allow(unused_imports)]
-pub use data::{Map, Tile};
+pub use data::Map;
+
+/// Affichage de la map actuelle et changements de map
+pub struct MapPlugin;
+
+impl Plugin for MapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Map), spawn_map)
+            .add_systems(OnExit(GameState::Map), despawn_map)
+            .add_systems(OnEnter(GameState::MapTransition), map_transition);
+    }
+}
@@ -1,15 +1,23 @@
 use crate::assets::ImageAssets;
 use crate::components::{MapTile, Position};
 use crate::config::{TERMINAL_WIDTH, TILE_SIZE};
+use crate::content::ContentRegistry;
 use crate::enemy::Enemy;
-use crate::item::Item;
+use crate::item::{Chest, Item};
 use crate::resources::{CollectedItems, DefeatedEnemies};
-use crate::systems::map::{GameData, Tile};
+use crate::systems::map::GameData;
 use bevy::prelude::*;
+use regles::grid::Tile;
+
+/// Couleur appliquée à la texture de chemin pour les feux de camp
+const REST_TILE_COLOR: Color = Color::rgb(1.0, 0.55, 0.2);
+
+/// Couleur appliquée à la texture de chemin pour les marchands
+const MERCHANT_TILE_COLOR: Color = Color::rgb(0.4, 0.7, 1.0);
 
 /// Génère tous les éléments visuels de la map actuelle :
-/// - Les tuiles (murs et chemins)
-/// - Les objets collectables (en vérifiant qu'ils n'ont pas déjà été collectés)
+/// - Les tuiles (murs, chemins, feux de camp et marchands)
+/// - Les objets collectables et les coffres (en vérifiant qu'ils n'ont pas déjà été collectés)
 /// - Les ennemis (en vérifiant qu'ils n'ont pas déjà été vaincus)
 ///
 /// Z-ordering : Tiles (0.0) → Items (0.5) → Ennemis (0.7) → Joueur (1.0)
@@ -17,6 +25,7 @@ pub fn spawn_map(
     mut commands: Commands,
     game_data: Res<GameData>,
     image_assets: Res<ImageAssets>,
+    registry: Res<ContentRegistry>,
     collected_items: Res<CollectedItems>,
     defeated_enemies: Res<DefeatedEnemies>,
 ) {
@@ -32,7 +41,12 @@ pub fn spawn_map(
             let texture = match tile_type {
                 Tile::Path => image_assets.path_tile.clone(),
                 Tile::Wall => image_assets.wall_tile.clone(),
-                Tile::Connection => image_assets.path_tile.clone(),
+                Tile::Connection | Tile::Rest | Tile::Merchant => image_assets.path_tile.clone(),
+            };
+            let color = match tile_type {
+                Tile::Rest => REST_TILE_COLOR,
+                Tile::Merchant => MERCHANT_TILE_COLOR,
+                _ => Color::WHITE,
             };
 
             let position = Vec2::new(
@@ -45,6 +59,7 @@ pub fn spawn_map(
                     texture,
                     transform: Transform::from_translation(position.extend(0.0)),
                     sprite: Sprite {
+                        color,
                         custom_size: Some(Vec2::new(TILE_SIZE, TILE_SIZE)),
                         ..default()
                     },
@@ -82,8 +97,37 @@ pub fn spawn_map(
                 ..default()
             },
             Item {
-                item_type: *item_type,
+                item_type: item_type.clone(),
+            },
+            *pos,
+        ));
+    }
+
+    // Spawn coffres seulement s'ils n'ont pas été ouverts
+    for (pos, gold) in &game_map.chests {
+        if collected_items
+            .items
+            .contains(&(game_data.current_map_index, *pos))
+        {
+            continue;
+        }
+
+        let chest_translation = Vec2::new(
+            pos.x as f32 * TILE_SIZE - (game_map.width as f32 * TILE_SIZE / 2.0) + x_offset,
+            pos.y as f32 * TILE_SIZE - (game_map.height as f32 * TILE_SIZE / 2.0),
+        );
+
+        commands.spawn((
+            SpriteBundle {
+                texture: image_assets.chest.clone(),
+                transform: Transform::from_translation(chest_translation.extend(0.5)),
+                sprite: Sprite {
+                    custom_size: Some(Vec2::new(TILE_SIZE, TILE_SIZE)),
+                    ..default()
+                },
+                ..default()
             },
+            Chest { gold: *gold },
             *pos,
         ));
     }
@@ -113,7 +157,7 @@ pub fn spawn_map(
                 },
                 ..default()
             },
-            Enemy::new(*enemy_type),
+            Enemy::new(enemy_type.clone(), &registry),
             *pos,
         ));
     }
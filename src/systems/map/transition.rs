@@ -1,6 +1,29 @@
+use crate::components::Position;
+use crate::inventory::Inventory;
+use crate::player::Player;
+use crate::resources::SaveSlot;
 use crate::states::GameState;
+use crate::systems::save::{GameLoaded, GameProgress};
 use bevy::prelude::*;
 
-pub fn map_transition(mut next_state: ResMut<NextState<GameState>>) {
+/// Changement de map (ou chargement avec F9) : sauvegarde automatique dans l'emplacement
+/// actif, le joueur étant déjà placé sur la nouvelle map, puis reconstruction de la map
+/// par `spawn_map`
+/// Après un chargement, la sauvegarde qui vient d'être lue n'est pas réécrite
+pub fn map_transition(
+    player_query: Query<(&Player, &Inventory, &Position)>,
+    mut progress: GameProgress,
+    save_slot: Res<SaveSlot>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut game_loaded: EventReader<GameLoaded>,
+) {
+    let loaded = game_loaded.read().count() > 0;
+    if !loaded && let Ok((player, inventory, position)) = player_query.get_single() {
+        let message = match progress.save(save_slot.0, player, inventory, *position) {
+            Ok(()) => format!("Sauvegarde automatique (emplacement {})", save_slot.0 + 1),
+            Err(err) => format!("Échec de la sauvegarde automatique : {}", err),
+        };
+        progress.game_log.add_message(message);
+    }
     next_state.set(GameState::Map);
 }
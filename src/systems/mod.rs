@@ -1,6 +1,13 @@
 pub mod camera;
 pub mod class_selection;
 pub mod combat;
+pub mod controls;
+pub mod inventory;
 pub mod map;
 pub mod player;
+pub mod progress;
+pub mod save;
+pub mod save_slots;
+pub mod shop;
+pub mod summary;
 pub mod ui;
@@ -0,0 +1,34 @@
+use crate::components::Position;
+use crate::events::ChestOpened;
+use crate::item::Chest;
+use crate::player::Player;
+use crate::systems::map::GameData;
+use bevy::prelude::*;
+
+/// Ouvre le coffre sur lequel se trouve le joueur : son or est ajouté au joueur, `ChestOpened`
+/// est émis et l'entité du coffre détruite ; le coffre est noté comme collecté par les
+/// écouteurs de `progress`, pour ne plus réapparaître
+pub fn check_chest(
+    mut commands: Commands,
+    mut player_query: Query<(&Position, &mut Player)>,
+    chest_query: Query<(Entity, &Position, &Chest)>,
+    mut chest_opened: EventWriter<ChestOpened>,
+    game_data: Res<GameData>,
+) {
+    let Ok((player_pos, mut player)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    for (chest_entity, chest_pos, chest) in chest_query.iter() {
+        if player_pos == chest_pos {
+            player.gold += chest.gold;
+            chest_opened.send(ChestOpened {
+                map_index: game_data.current_map_index,
+                position: *chest_pos,
+                gold: chest.gold,
+                total_gold: player.gold,
+            });
+            commands.entity(chest_entity).despawn();
+        }
+    }
+}
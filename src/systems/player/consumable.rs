@@ -0,0 +1,53 @@
+use crate::controls::{Action, Controls};
+use crate::inventory::Inventory;
+use crate::player::Player;
+use crate::resources::GameLog;
+use bevy::prelude::*;
+use regles::consumable::ConsumableEffect;
+
+/// Boit la première potion de soin de l'inventaire avec la touche P (par défaut)
+pub fn use_potion_hotkey(
+    controls: Controls,
+    mut player_query: Query<(&mut Player, &mut Inventory)>,
+    mut game_log: ResMut<GameLog>,
+) {
+    if !controls.just_pressed(Action::UsePotion) {
+        return;
+    }
+    let (mut player, mut inventory) = player_query.single_mut();
+
+    match inventory
+        .consumables
+        .iter()
+        .position(|stack| stack.effect.usable_on_map())
+    {
+        Some(index) => use_consumable_on_map(index, &mut player, &mut inventory, &mut game_log),
+        None => game_log.add_message("Aucune potion de soin".to_string()),
+    }
+}
+
+/// Utilise le consommable `index` hors combat (seuls les soins s'utilisent sur la map)
+/// Rien n'est consommé si l'objet ne sert qu'en combat ou si les PV sont déjà au maximum
+pub fn use_consumable_on_map(
+    index: usize,
+    player: &mut Player,
+    inventory: &mut Inventory,
+    game_log: &mut GameLog,
+) {
+    let stack = &inventory.consumables[index];
+    let ConsumableEffect::Heal(amount) = stack.effect else {
+        game_log.add_message(format!("{} : utilisable en combat uniquement", stack.name));
+        return;
+    };
+    if player.current_hp >= player.stats.hp {
+        game_log.add_message("PV déjà au maximum".to_string());
+        return;
+    }
+
+    let healed = player.heal(amount);
+    let (name, left) = inventory.consume(index);
+    game_log.add_message(format!(
+        "{} : PV +{} ({} / {}), reste {}",
+        name, healed, player.current_hp, player.stats.hp, left
+    ));
+}
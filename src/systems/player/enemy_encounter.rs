@@ -1,49 +1,94 @@
 use crate::components::Position;
+use crate::content::ContentRegistry;
 use crate::enemy::Enemy;
+use crate::events::CombatStarted;
 use crate::player::Player;
-use crate::resources::{CombatState, CurrentEnemy, GameLog};
+use crate::resources::{CombatEnemy, CombatState};
 use crate::states::GameState;
+use crate::systems::map::GameData;
 use bevy::prelude::*;
+use regles::combat::{Combat, EnemyFighter};
+use regles::progression::scaled_stats;
 
 /// Vérifie si le joueur est sur la même case qu'un ennemi
 /// Si oui :
-/// - Sauvegarde les données de l'ennemi dans CurrentEnemy
-/// - Initialise l'état du combat (CombatState)
-/// - Ajoute un message au log
+/// - Engage l'ennemi et, s'il fait partie d'un groupe, les autres membres encore en vie,
+///   avec des stats adaptées au niveau du joueur
+/// - Initialise l'état du combat (CombatState) et désigne le premier à jouer
+/// - Émet `CombatStarted`
 /// - Change l'état du jeu vers Combat
 pub fn check_enemy_encounter(
     mut commands: Commands,
     player_query: Query<(&Position, &Player)>,
     enemy_query: Query<(Entity, &Position, &Enemy)>,
     mut next_state: ResMut<NextState<GameState>>,
-    mut game_log: ResMut<GameLog>,
+    mut combat_started: EventWriter<CombatStarted>,
+    game_data: Res<GameData>,
+    registry: Res<ContentRegistry>,
 ) {
-    if let Ok((player_pos, player_data)) = player_query.get_single() {
-        for (enemy_entity, enemy_pos, enemy_data) in enemy_query.iter() {
-            if player_pos == enemy_pos {
-                game_log.add_message(format!(
-                    "Combat contre {} !",
-                    enemy_data.enemy_type.get_name()
-                ));
+    let Ok((player_pos, player_data)) = player_query.get_single() else {
+        return;
+    };
+    if !enemy_query
+        .iter()
+        .any(|(_, enemy_pos, _)| enemy_pos == player_pos)
+    {
+        return;
+    }
 
-                commands.insert_resource(CurrentEnemy {
-                    entity: enemy_entity,
-                    position: *enemy_pos,
-                    enemy_type: enemy_data.enemy_type,
-                    hp: enemy_data.stats.hp,
-                    stats: enemy_data.stats,
-                });
+    // Les membres du groupe déjà vaincus n'ont pas été spawnés : seuls les vivants sont engagés
+    let encounter = game_data.get_current_map().encounter_at(*player_pos);
+    let mut engaged: Vec<(Entity, &Position, &Enemy)> = enemy_query
+        .iter()
+        .filter(|(_, enemy_pos, _)| encounter.contains(enemy_pos))
+        .collect();
+    engaged.sort_by_key(|(_, enemy_pos, _)| {
+        encounter.iter().position(|position| position == *enemy_pos)
+    });
 
-                commands.insert_resource(CombatState {
-                    player_hp: player_data.stats.hp,
-                    enemy_hp: enemy_data.stats.hp,
-                    combat_log: Vec::new(),
-                    is_player_turn: true,
-                });
+    let (enemies, fighters): (Vec<CombatEnemy>, Vec<EnemyFighter>) = engaged
+        .iter()
+        .map(|(entity, enemy_pos, enemy_data)| {
+            let enemy_def = registry.enemy(&enemy_data.enemy_type);
+            let name = &enemy_def.name;
+            // Numérote les ennemis du même type pour les distinguer (Petit Gobelin 1, 2, ...)
+            let same_type: Vec<_> = engaged
+                .iter()
+                .filter(|(_, _, other)| other.enemy_type == enemy_data.enemy_type)
+                .collect();
+            let name = if same_type.len() > 1 {
+                let number = same_type
+                    .iter()
+                    .position(|(other, _, _)| other == entity)
+                    .unwrap_or(0)
+                    + 1;
+                format!("{} {}", name, number)
+            } else {
+                name.clone()
+            };
+            let enemy = CombatEnemy {
+                entity: *entity,
+                position: **enemy_pos,
+                enemy_type: enemy_data.enemy_type.clone(),
+                name,
+            };
+            let fighter = enemy_def.fighter(scaled_stats(&enemy_data.stats, player_data.level));
+            (enemy, fighter)
+        })
+        .unzip();
 
-                next_state.set(GameState::Combat);
-                break;
-            }
-        }
-    }
+    combat_started.send(CombatStarted {
+        enemies: enemies.clone(),
+    });
+
+    // Les PV perdus lors des combats précédents ne sont pas rendus ; le plus rapide commence
+    let player_fighter = registry.class(&player_data.class).fighter(
+        player_data.stats,
+        player_data.current_hp,
+        player_data.level,
+    );
+    let combat = Combat::new(player_fighter, fighters, rand::random());
+    commands.insert_resource(CombatState::new(combat, enemies));
+
+    next_state.set(GameState::Combat);
 }
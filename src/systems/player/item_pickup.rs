@@ -1,62 +1,124 @@
 use crate::components::Position;
-use crate::item::{self, Item, ItemType};
+use crate::content::{ContentRegistry, ItemDef};
+use crate::events::{ItemPickedUp, LootDropped};
+use crate::inventory::{Inventory, InventoryItem};
+use crate::item::Item;
+use crate::loot::Rarity;
 use crate::player::Player;
-use crate::resources::{CollectedItems, GameLog};
+use crate::resources::GameLog;
 use crate::systems::map::GameData;
 use bevy::prelude::*;
+use regles::stats::Stats;
 
 /// Vérifie si le joueur est sur la même case qu'un objet
-/// Si oui :
-/// - Applique les bonus de stats de l'objet au joueur
-/// - Ajoute des messages au log de jeu
-/// - Marque l'objet comme collecté dans CollectedItems
-/// - Détruit l'entité de l'objet
+/// Si oui, émet `ItemPickedUp` et détruit l'entité de l'objet ; l'objet est donné au joueur
+/// par `receive_picked_up_items`, et noté comme collecté par les écouteurs de `progress`
 pub fn check_item_pickup(
     mut commands: Commands,
-    mut player_query: Query<(&Position, &mut Player)>,
+    player_query: Query<&Position, With<Player>>,
     item_query: Query<(Entity, &Position, &Item)>,
-    mut collected_items: ResMut<CollectedItems>,
+    mut item_picked_up: EventWriter<ItemPickedUp>,
     game_data: Res<GameData>,
-    mut game_log: ResMut<GameLog>,
 ) {
-    if let Ok((player_pos, mut player_data)) = player_query.get_single_mut() {
-        for (item_entity, item_pos, item_data) in item_query.iter() {
-            if player_pos == item_pos {
-                let item_stats = item::get_stats_for_item(item_data.item_type);
-
-                let item_name = match item_data.item_type {
-                    ItemType::Armure => "Armure",
-                    ItemType::Katana => "Katana",
-                    ItemType::Gants => "Gants",
-                    ItemType::Pendentif => "Pendentif",
-                };
-                game_log.add_message(format!("Objet ramassé : {}", item_name));
-
-                let mut stat_messages = Vec::new();
-                if item_stats.hp > 0 {
-                    stat_messages.push(format!("HP +{}", item_stats.hp));
-                }
-                if item_stats.attack > 0 {
-                    stat_messages.push(format!("ATK +{}", item_stats.attack));
-                }
-                if item_stats.speed > 0 {
-                    stat_messages.push(format!("SPD +{}", item_stats.speed));
-                }
-                if item_stats.critical_chance > 0 {
-                    stat_messages.push(format!("CRIT +{}%", item_stats.critical_chance));
-                }
-                if !stat_messages.is_empty() {
-                    game_log.add_message(stat_messages.join(", "));
-                }
+    let Ok(player_pos) = player_query.get_single() else {
+        return;
+    };
+    for (item_entity, item_pos, item_data) in item_query.iter() {
+        if player_pos == item_pos {
+            item_picked_up.send(ItemPickedUp {
+                map_index: game_data.current_map_index,
+                position: *item_pos,
+                item_type: item_data.item_type.clone(),
+            });
+            commands.entity(item_entity).despawn();
+        }
+    }
+}
 
-                player_data.apply_item_stats(&item_stats);
+/// Écoute `ItemPickedUp` : donne l'objet ramassé au joueur (voir `receive_item`), avec sa
+/// rareté commune, et l'annonce dans le log
+pub fn receive_picked_up_items(
+    mut item_picked_up: EventReader<ItemPickedUp>,
+    mut player_query: Query<(&mut Player, &mut Inventory)>,
+    mut game_log: ResMut<GameLog>,
+    registry: Res<ContentRegistry>,
+) {
+    let Ok((mut player_data, mut inventory)) = player_query.get_single_mut() else {
+        return;
+    };
+    for event in item_picked_up.read() {
+        let item_def = registry.item(&event.item_type);
+        game_log.add_message(format!("Objet ramassé : {}", item_def.name));
+        receive_item(
+            item_def,
+            Rarity::Common,
+            Stats::default(),
+            &mut player_data,
+            &mut inventory,
+            &mut game_log,
+        );
+    }
+}
 
-                collected_items
-                    .items
-                    .insert((game_data.current_map_index, *item_pos));
+/// Écoute `LootDropped` : donne le butin d'un combat gagné au joueur, avec sa rareté et ses
+/// affixes, et l'annonce dans le log
+pub fn receive_loot(
+    mut loot_dropped: EventReader<LootDropped>,
+    mut player_query: Query<(&mut Player, &mut Inventory)>,
+    mut game_log: ResMut<GameLog>,
+    registry: Res<ContentRegistry>,
+) {
+    let Ok((mut player_data, mut inventory)) = player_query.get_single_mut() else {
+        return;
+    };
+    for event in loot_dropped.read() {
+        let item_def = registry.item(&event.item_type);
+        game_log.add_message(format!(
+            "Butin de {} : {}",
+            event.enemy_name,
+            event.rarity.item_name(&item_def.name)
+        ));
+        receive_item(
+            item_def,
+            event.rarity,
+            event.affixes,
+            &mut player_data,
+            &mut inventory,
+            &mut game_log,
+        );
+    }
+}
 
-                commands.entity(item_entity).despawn();
-            }
+/// Donne un objet au joueur (objet ramassé ou butin de combat)
+/// - Équipement : rangé dans l'inventaire, équipé directement si son emplacement est libre
+/// - Consommable : ajouté à sa pile dans l'inventaire
+pub fn receive_item(
+    item_def: &ItemDef,
+    rarity: Rarity,
+    affixes: Stats,
+    player: &mut Player,
+    inventory: &mut Inventory,
+    game_log: &mut GameLog,
+) {
+    if let Some(item) = InventoryItem::from_def(item_def, rarity, affixes) {
+        let bonus = item.stats.bonus_summary();
+        if !bonus.is_empty() {
+            game_log.add_message(bonus);
+        }
+        let slot = item.slot;
+        if inventory.add(item) {
+            player.apply_equipment(inventory);
+            game_log.add_message(format!("Équipé ({})", slot.label()));
+        } else {
+            game_log.add_message(format!(
+                "{} déjà occupé : ajouté à l'inventaire (I)",
+                slot.label()
+            ));
         }
     }
+
+    if let Some(effect) = item_def.consumable {
+        let count = inventory.add_consumable(item_def, effect);
+        game_log.add_message(format!("Ajouté aux consommables (x{})", count));
+    }
 }
@@ -0,0 +1,20 @@
+use crate::components::Position;
+use crate::player::Player;
+use crate::states::GameState;
+use crate::systems::map::GameData;
+use bevy::prelude::*;
+
+/// Ouvre la boutique quand le joueur arrive sur la tuile du marchand
+pub fn check_merchant_tile(
+    player_query: Query<&Position, (With<Player>, Changed<Position>)>,
+    game_data: Res<GameData>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Ok(player_pos) = player_query.get_single() else {
+        return;
+    };
+
+    if game_data.get_current_map().is_merchant(*player_pos) {
+        next_state.set(GameState::Shop);
+    }
+}
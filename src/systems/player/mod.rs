@@ -1,9 +1,56 @@
+mod chest;
+mod consumable;
 mod enemy_encounter;
 mod item_pickup;
+mod merchant;
 mod movement;
+mod quest;
+mod rest;
 mod transform;
 
+use crate::states::GameState;
+use crate::systems::progress::log_combat_outcomes;
+use bevy::prelude::*;
+
+pub use chest::check_chest;
+pub use consumable::{use_consumable_on_map, use_potion_hotkey};
 pub use enemy_encounter::check_enemy_encounter;
-pub use item_pickup::check_item_pickup;
+pub use item_pickup::{check_item_pickup, receive_item, receive_loot, receive_picked_up_items};
+pub use merchant::check_merchant_tile;
 pub use movement::move_player;
+pub use quest::{check_victory, update_quests};
+pub use rest::check_rest_tile;
 pub use transform::update_transform;
+
+/// Joueur sur la map : déplacement et interactions avec les cases (objets, coffres, feux de
+/// camp, marchands, ennemis), consommables et quêtes
+pub struct PlayerPlugin;
+
+impl Plugin for PlayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                move_player,
+                update_transform,
+                check_item_pickup,
+                check_chest,
+                check_rest_tile.after(move_player),
+                check_merchant_tile,
+                use_potion_hotkey,
+                update_quests,
+                check_victory.after(update_quests),
+                check_enemy_encounter,
+            )
+                .run_if(in_state(GameState::Map)),
+        )
+        // Le butin est annoncé après les récompenses du combat
+        .add_systems(
+            PostUpdate,
+            (
+                receive_picked_up_items,
+                receive_loot.after(log_combat_outcomes),
+            ),
+        );
+    }
+}
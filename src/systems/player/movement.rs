@@ -1,44 +1,54 @@
-use crate::components::Position;
+use crate::components::{Position, PreviousPosition};
+use crate::controls::{Action, Controls};
+use crate::events::PlayerMoved;
 use crate::player::Player;
 use crate::states::GameState;
 use crate::systems::map::GameData;
 use bevy::prelude::*;
 
-/// Gère le déplacement du joueur avec les touches Z/Q/S/D ou flèches directionnelles
+/// Gère le déplacement du joueur avec les touches de déplacement (Z/Q/S/D ou flèches par défaut)
+/// Le y des maps augmente vers le haut de l'écran
 /// Vérifie que la nouvelle position est praticable avant de déplacer le joueur
 /// Détecte les connexions entre maps et déclenche une transition si nécessaire
+/// Émet `PlayerMoved` avec la case atteinte (la map atteinte est notée pour les quêtes par
+/// `record_visited_maps`)
 pub fn move_player(
-    keyboard_input: Res<Input<KeyCode>>,
-    mut player_query: Query<&mut Position, With<Player>>,
+    controls: Controls,
+    mut player_query: Query<(&mut Position, &mut PreviousPosition), With<Player>>,
     mut game_data: ResMut<GameData>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut player_moved: EventWriter<PlayerMoved>,
 ) {
-    let mut player_pos = player_query.single_mut();
-    let mut new_pos = player_pos.clone();
+    let (mut player_pos, mut previous_pos) = player_query.single_mut();
+    let mut new_pos = *player_pos;
 
     let game_map = game_data.get_current_map();
 
-    if keyboard_input.just_pressed(KeyCode::S) || keyboard_input.just_pressed(KeyCode::Up) {
-        new_pos.y = new_pos.y.saturating_sub(1);
-    } else if keyboard_input.just_pressed(KeyCode::Z) || keyboard_input.just_pressed(KeyCode::Down)
-    {
+    if controls.just_pressed(Action::MoveUp) {
         new_pos.y = (new_pos.y + 1).min(game_map.height - 1);
-    } else if keyboard_input.just_pressed(KeyCode::Q) || keyboard_input.just_pressed(KeyCode::Left)
-    {
+    } else if controls.just_pressed(Action::MoveDown) {
+        new_pos.y = new_pos.y.saturating_sub(1);
+    } else if controls.just_pressed(Action::MoveLeft) {
         new_pos.x = new_pos.x.saturating_sub(1);
-    } else if keyboard_input.just_pressed(KeyCode::D) || keyboard_input.just_pressed(KeyCode::Right)
-    {
+    } else if controls.just_pressed(Action::MoveRight) {
         new_pos.x = (new_pos.x + 1).min(game_map.width - 1);
     }
 
-    if game_map.is_walkable(new_pos.x, new_pos.y) {
+    if new_pos != *player_pos && game_map.is_walkable(new_pos.x, new_pos.y) {
+        previous_pos.0 = *player_pos;
         *player_pos = new_pos;
 
         let connection = game_map.connections.get(&new_pos).cloned();
         if let Some((to_map_index, to_position)) = connection {
             game_data.current_map_index = to_map_index;
             *player_pos = to_position;
+            previous_pos.0 = to_position;
             next_state.set(GameState::MapTransition);
         }
+        player_moved.send(PlayerMoved {
+            map_index: game_data.current_map_index,
+            position: *player_pos,
+            changed_map: connection.is_some(),
+        });
     }
 }
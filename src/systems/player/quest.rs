@@ -0,0 +1,68 @@
+use crate::content::ContentRegistry;
+use crate::inventory::Inventory;
+use crate::loot::Rarity;
+use crate::player::Player;
+use crate::resources::{GameLog, QuestLog};
+use crate::states::GameState;
+use crate::systems::player::receive_item;
+use bevy::prelude::*;
+use regles::stats::Stats;
+
+/// Récompense les quêtes dont l'objectif vient d'être rempli
+/// Une quête terminée en combat est récompensée au retour sur la map
+/// L'XP fait monter de niveau avec la croissance de la classe, sans amélioration au choix
+/// (celles-ci ne se choisissent qu'après un combat)
+pub fn update_quests(
+    mut player_query: Query<(&mut Player, &mut Inventory)>,
+    mut quest_log: ResMut<QuestLog>,
+    mut game_log: ResMut<GameLog>,
+    registry: Res<ContentRegistry>,
+) {
+    let Ok((mut player, mut inventory)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    for quest in &registry.quests {
+        if quest_log.completed.contains(&quest.id) || !quest_log.is_done(quest) {
+            continue;
+        }
+        quest_log.completed.insert(quest.id.clone());
+        game_log.add_message(format!("Quête terminée : {} !", quest.name));
+
+        let reward = &quest.reward;
+        if reward.xp > 0 || reward.gold > 0 {
+            player.gold += reward.gold;
+            game_log.add_message(format!("+{} XP, +{} or", reward.xp, reward.gold));
+        }
+        if reward.xp > 0 {
+            let growth = registry.class(&player.class).growth;
+            let levels = player.gain_xp(reward.xp, &growth);
+            if levels > 0 {
+                game_log.add_message(format!("Niveau {} atteint !", player.level));
+            }
+        }
+        for item_type in &reward.items {
+            let item_def = registry.item(item_type);
+            game_log.add_message(format!("Récompense : {}", item_def.name));
+            receive_item(
+                item_def,
+                Rarity::Common,
+                Stats::default(),
+                &mut player,
+                &mut inventory,
+                &mut game_log,
+            );
+        }
+    }
+}
+
+/// Termine la partie quand la condition de victoire (victory.ron) est remplie
+pub fn check_victory(
+    quest_log: Res<QuestLog>,
+    registry: Res<ContentRegistry>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if quest_log.is_changed() && registry.victory.is_met(&quest_log) {
+        next_state.set(GameState::Victory);
+    }
+}
@@ -0,0 +1,31 @@
+use crate::events::PlayerMoved;
+use crate::player::Player;
+use crate::resources::GameLog;
+use crate::systems::map::GameData;
+use bevy::prelude::*;
+
+/// Rend tous les PV du joueur quand il arrive sur un feu de camp en se déplaçant
+/// (pas quand une fuite le ramène sur la case du feu de camp)
+pub fn check_rest_tile(
+    mut player_moved: EventReader<PlayerMoved>,
+    mut player_query: Query<&mut Player>,
+    game_data: Res<GameData>,
+    mut game_log: ResMut<GameLog>,
+) {
+    let Ok(mut player) = player_query.get_single_mut() else {
+        return;
+    };
+
+    for event in player_moved.read() {
+        if !game_data.maps[event.map_index].is_rest(event.position) {
+            continue;
+        }
+        let max_hp = player.stats.hp;
+        if player.heal(max_hp) > 0 {
+            game_log.add_message(format!(
+                "Vous vous reposez au feu de camp : PV {} / {}",
+                player.current_hp, player.stats.hp
+            ));
+        }
+    }
+}
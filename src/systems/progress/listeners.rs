@@ -0,0 +1,142 @@
+use crate::events::{
+    ChestOpened, CombatEnded, CombatRewards, CombatStarted, EnemyDefeated, EnemyFled, ItemPickedUp,
+    LevelUp, PlayerDied, PlayerFled, PlayerMoved, UpgradeChosen,
+};
+use crate::resources::{CollectedItems, CombatEnemy, DefeatedEnemies, GameLog, QuestLog, RunStats};
+use crate::systems::map::GameData;
+use bevy::prelude::*;
+
+/// Messages du log pour les coffres ouverts, le début d'un combat, les ennemis vaincus et la
+/// défaite (l'objet ramassé est annoncé avec son détail par `receive_picked_up_items`)
+pub fn log_gameplay_events(
+    mut chest_opened: EventReader<ChestOpened>,
+    mut combat_started: EventReader<CombatStarted>,
+    mut enemy_defeated: EventReader<EnemyDefeated>,
+    mut player_died: EventReader<PlayerDied>,
+    mut game_log: ResMut<GameLog>,
+) {
+    for event in chest_opened.read() {
+        game_log.add_message(format!(
+            "Coffre ouvert : +{} or ({} or)",
+            event.gold, event.total_gold
+        ));
+    }
+    for event in combat_started.read() {
+        game_log.add_message(format!("Combat contre {} !", names(&event.enemies)));
+    }
+    for event in enemy_defeated.read() {
+        game_log.add_message(format!("{} vaincu !", event.enemy.name));
+    }
+    for event in player_died.read() {
+        game_log.add_message(format!("Défaite face à {}", names(&event.enemies)));
+    }
+}
+
+/// Messages du log pour les fuites et les récompenses de fin de combat (le butin est annoncé
+/// avec son détail par `receive_loot`)
+pub fn log_combat_outcomes(
+    mut player_fled: EventReader<PlayerFled>,
+    mut enemy_fled: EventReader<EnemyFled>,
+    mut rewards: EventReader<CombatRewards>,
+    mut level_up: EventReader<LevelUp>,
+    mut upgrade_chosen: EventReader<UpgradeChosen>,
+    mut game_log: ResMut<GameLog>,
+) {
+    for event in player_fled.read() {
+        game_log.add_message(format!("Fuite face à {} !", names(&event.enemies)));
+    }
+    for event in enemy_fled.read() {
+        game_log.add_message(format!("{} s'est enfui !", event.enemy.name));
+    }
+    for event in rewards.read() {
+        if event.xp > 0 {
+            game_log.add_message(format!("+{} XP", event.xp));
+        }
+        if event.gold > 0 {
+            game_log.add_message(format!("+{} or ({} or)", event.gold, event.total_gold));
+        }
+    }
+    for event in level_up.read() {
+        game_log.add_message(format!(
+            "Niveau {} atteint ! PV restaurés ({} / {})",
+            event.level, event.hp, event.max_hp
+        ));
+        if let Some(skill) = &event.unlocked_skill {
+            game_log.add_message(format!("Compétence débloquée : {}", skill));
+        }
+    }
+    for event in upgrade_chosen.read() {
+        game_log.add_message(format!("{} !", event.bonus.bonus_summary()));
+    }
+}
+
+/// Objets ramassés : ils ne réapparaissent plus sur la map et comptent pour les quêtes et
+/// le bilan
+pub fn record_picked_up_items(
+    mut item_picked_up: EventReader<ItemPickedUp>,
+    mut collected_items: ResMut<CollectedItems>,
+    mut quest_log: ResMut<QuestLog>,
+    mut run_stats: ResMut<RunStats>,
+) {
+    for event in item_picked_up.read() {
+        collected_items
+            .items
+            .insert((event.map_index, event.position));
+        quest_log.record_item(&event.item_type);
+        run_stats.items_collected += 1;
+    }
+}
+
+/// Coffres ouverts : ils ne réapparaissent plus sur la map (mémorisés avec les objets
+/// collectés)
+pub fn record_opened_chests(
+    mut chest_opened: EventReader<ChestOpened>,
+    mut collected_items: ResMut<CollectedItems>,
+) {
+    for event in chest_opened.read() {
+        collected_items
+            .items
+            .insert((event.map_index, event.position));
+    }
+}
+
+/// Ennemis vaincus : ils ne réapparaissent plus sur la map et comptent pour les quêtes
+pub fn record_defeated_enemies(
+    mut enemy_defeated: EventReader<EnemyDefeated>,
+    mut defeated_enemies: ResMut<DefeatedEnemies>,
+    mut quest_log: ResMut<QuestLog>,
+) {
+    for event in enemy_defeated.read() {
+        defeated_enemies
+            .enemies
+            .insert((event.map_index, event.enemy.position));
+        quest_log.record_defeat(&event.enemy.enemy_type);
+    }
+}
+
+/// Maps atteintes par une connexion : elles comptent pour les quêtes
+pub fn record_visited_maps(
+    mut player_moved: EventReader<PlayerMoved>,
+    game_data: Res<GameData>,
+    mut quest_log: ResMut<QuestLog>,
+) {
+    for event in player_moved.read().filter(|event| event.changed_map) {
+        quest_log.record_map(&game_data.maps[event.map_index].name);
+    }
+}
+
+/// Tours joués et dégâts infligés à chaque combat, pour le bilan
+pub fn record_combat_stats(
+    mut combat_ended: EventReader<CombatEnded>,
+    mut run_stats: ResMut<RunStats>,
+) {
+    for event in combat_ended.read() {
+        run_stats.turns += event.player_turns;
+        run_stats.damage_dealt += event.damage_dealt;
+    }
+}
+
+fn names(enemies: &[CombatEnemy]) -> String {
+    let names: Vec<&str> = enemies.iter().map(|enemy| enemy.name.as_str()).collect();
+    names.join(", ")
+}
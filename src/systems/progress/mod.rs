@@ -0,0 +1,53 @@
+mod listeners;
+
+use crate::events::{
+    ChestOpened, CombatEnded, CombatRewards, CombatStarted, EnemyDefeated, EnemyFled, ItemPickedUp,
+    LevelUp, LootDropped, PlayerDied, PlayerFled, PlayerMoved, UpgradeChosen,
+};
+use crate::resources::{CollectedItems, DefeatedEnemies, GameLog, QuestLog, RunStats};
+use bevy::prelude::*;
+
+pub use listeners::{
+    log_combat_outcomes, log_gameplay_events, record_combat_stats, record_defeated_enemies,
+    record_opened_chests, record_picked_up_items, record_visited_maps,
+};
+
+/// Progression de la partie : ressources de progression, événements de jeu (voir `events`)
+/// et écouteurs qui tiennent à jour le log, les objets collectés, les ennemis vaincus,
+/// les quêtes et le bilan
+pub struct ProgressPlugin;
+
+impl Plugin for ProgressPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CollectedItems>()
+            .init_resource::<DefeatedEnemies>()
+            .init_resource::<QuestLog>()
+            .init_resource::<RunStats>()
+            .init_resource::<GameLog>()
+            .add_event::<PlayerMoved>()
+            .add_event::<ItemPickedUp>()
+            .add_event::<ChestOpened>()
+            .add_event::<CombatStarted>()
+            .add_event::<CombatEnded>()
+            .add_event::<EnemyDefeated>()
+            .add_event::<PlayerDied>()
+            .add_event::<PlayerFled>()
+            .add_event::<EnemyFled>()
+            .add_event::<CombatRewards>()
+            .add_event::<LevelUp>()
+            .add_event::<UpgradeChosen>()
+            .add_event::<LootDropped>()
+            .add_systems(
+                PostUpdate,
+                (
+                    // Ordre fixe des messages d'une même frame (ennemis vaincus, puis fuite)
+                    (log_gameplay_events, log_combat_outcomes).chain(),
+                    record_picked_up_items,
+                    record_opened_chests,
+                    record_defeated_enemies,
+                    record_visited_maps,
+                    record_combat_stats,
+                ),
+            );
+    }
+}
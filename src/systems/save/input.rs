@@ -0,0 +1,69 @@
+use crate::assets::ImageAssets;
+use crate::components::Position;
+use crate::content::ContentRegistry;
+use crate::controls::{Action, Controls};
+use crate::inventory::Inventory;
+use crate::player::Player;
+use crate::resources::SaveSlot;
+use crate::states::GameState;
+use crate::systems::save::GameLoaded;
+use crate::systems::save::progress::GameProgress;
+use bevy::prelude::*;
+
+/// F5 (par défaut) sur la map : sauvegarde la partie dans l'emplacement actif
+pub fn save_game(
+    controls: Controls,
+    player_query: Query<(&Player, &Inventory, &Position)>,
+    mut progress: GameProgress,
+    save_slot: Res<SaveSlot>,
+) {
+    if !controls.just_pressed(Action::QuickSave) {
+        return;
+    }
+    let (player, inventory, position) = player_query.single();
+    let message = match progress.save(save_slot.0, player, inventory, *position) {
+        Ok(()) => format!(
+            "Partie sauvegardée ({} pour charger)",
+            controls.hint(Action::QuickLoad)
+        ),
+        Err(err) => format!("Échec de la sauvegarde : {}", err),
+    };
+    progress.game_log.add_message(message);
+}
+
+/// F9 (par défaut) sur la map : recharge la sauvegarde de l'emplacement actif
+/// La map est quittée puis reconstruite (via MapTransition) avec l'état sauvegardé
+#[allow(clippy::too_many_arguments)]
+pub fn load_game(
+    mut commands: Commands,
+    controls: Controls,
+    player_query: Query<Entity, With<Player>>,
+    mut progress: GameProgress,
+    save_slot: Res<SaveSlot>,
+    registry: Res<ContentRegistry>,
+    image_assets: Res<ImageAssets>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut game_loaded: EventWriter<GameLoaded>,
+) {
+    if !controls.just_pressed(Action::QuickLoad) {
+        return;
+    }
+    match progress.load(
+        save_slot.0,
+        &mut commands,
+        player_query.iter(),
+        &registry,
+        &image_assets,
+    ) {
+        Ok(()) => {
+            progress.game_log.add_message("Partie chargée".to_string());
+            game_loaded.send(GameLoaded);
+            next_state.set(GameState::MapTransition);
+        }
+        Err(err) => {
+            progress
+                .game_log
+                .add_message(format!("Échec du chargement : {}", err));
+        }
+    }
+}
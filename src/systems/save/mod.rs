@@ -0,0 +1,25 @@
+mod input;
+mod progress;
+
+use crate::states::GameState;
+use bevy::prelude::*;
+
+pub use input::{load_game, save_game};
+pub use progress::GameProgress;
+
+/// La partie vient d'être rechargée (F9) : la transition qui reconstruit la map ne doit pas
+/// réécrire la sauvegarde qui vient d'être lue
+#[derive(Event)]
+pub struct GameLoaded;
+
+/// Sauvegarde et chargement rapides depuis la map
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<GameLoaded>().add_systems(
+            Update,
+            (save_game, load_game).run_if(in_state(GameState::Map)),
+        );
+    }
+}
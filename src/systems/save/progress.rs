@@ -0,0 +1,101 @@
+use crate::assets::ImageAssets;
+use crate::components::Position;
+use crate::content::ContentRegistry;
+use crate::inventory::Inventory;
+use crate::player::Player;
+use crate::resources::{CollectedItems, DefeatedEnemies, GameLog, QuestLog, RunStats, SaveDir};
+use crate::save::{SAVE_VERSION, SaveData, SaveError, now_secs, read_save, write_save};
+use crate::systems::class_selection::spawn_player_entity;
+use crate::systems::map::GameData;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Resources de progression de la partie, écrites dans la sauvegarde et remplacées au chargement
+#[derive(SystemParam)]
+pub struct GameProgress<'w> {
+    pub game_data: ResMut<'w, GameData>,
+    pub collected_items: ResMut<'w, CollectedItems>,
+    pub defeated_enemies: ResMut<'w, DefeatedEnemies>,
+    pub game_log: ResMut<'w, GameLog>,
+    pub quest_log: ResMut<'w, QuestLog>,
+    pub run_stats: ResMut<'w, RunStats>,
+    pub time: Res<'w, Time>,
+    pub save_dir: Res<'w, SaveDir>,
+}
+
+impl GameProgress<'_> {
+    /// Écrit l'état de la partie dans l'emplacement de sauvegarde `slot`
+    pub fn save(
+        &self,
+        slot: usize,
+        player: &Player,
+        inventory: &Inventory,
+        position: Position,
+    ) -> Result<(), SaveError> {
+        let play_time = self
+            .time
+            .elapsed()
+            .saturating_sub(self.run_stats.started_at);
+        let save = SaveData {
+            version: SAVE_VERSION,
+            player: player.clone(),
+            inventory: inventory.clone(),
+            map_index: self.game_data.current_map_index,
+            position,
+            dungeon_seed: self.game_data.dungeon.seed,
+            collected_items: self.collected_items.clone(),
+            defeated_enemies: self.defeated_enemies.clone(),
+            game_log: self.game_log.clone(),
+            quest_log: self.quest_log.clone(),
+            run_stats: self.run_stats.clone(),
+            play_time_secs: play_time.as_secs(),
+            saved_at: now_secs(),
+        };
+        write_save(&self.save_dir.slot_path(slot)?, &save)
+    }
+
+    /// Lit la sauvegarde de l'emplacement `slot` et remplace l'état de la partie :
+    /// resources de progression et joueur (les entités `players` sont remplacées par le joueur sauvegardé)
+    /// La map est reconstruite par `spawn_map` à la prochaine entrée dans l'état Map
+    /// En cas d'erreur, la partie en cours n'est pas modifiée
+    pub fn load(
+        &mut self,
+        slot: usize,
+        commands: &mut Commands,
+        players: impl Iterator<Item = Entity>,
+        registry: &ContentRegistry,
+        image_assets: &ImageAssets,
+    ) -> Result<(), SaveError> {
+        let save = read_save(&self.save_dir.slot_path(slot)?, registry, &self.game_data)?;
+
+        for entity in players {
+            commands.entity(entity).despawn_recursive();
+        }
+
+        self.game_data.set_dungeon_seed(save.dungeon_seed);
+        self.game_data.current_map_index = save.map_index;
+        *self.collected_items = save.collected_items;
+        *self.defeated_enemies = save.defeated_enemies;
+        *self.game_log = save.game_log;
+        *self.quest_log = save.quest_log;
+        *self.run_stats = save.run_stats;
+        self.run_stats.started_at = self
+            .time
+            .elapsed()
+            .saturating_sub(Duration::from_secs(save.play_time_secs));
+
+        // Les stats finales ne sont pas reprises telles quelles : base + équipement
+        let mut player = save.player;
+        player.apply_equipment(&save.inventory);
+        spawn_player_entity(
+            commands,
+            player,
+            save.inventory,
+            save.position,
+            self.game_data.get_current_map(),
+            image_assets,
+        );
+        Ok(())
+    }
+}
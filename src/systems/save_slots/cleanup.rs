@@ -0,0 +1,10 @@
+use crate::components::SaveSlotsUI;
+use crate::resources::SlotSelection;
+use bevy::prelude::*;
+
+pub fn cleanup_save_slots(mut commands: Commands, ui_query: Query<Entity, With<SaveSlotsUI>>) {
+    for entity in ui_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<SlotSelection>();
+}
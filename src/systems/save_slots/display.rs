@@ -0,0 +1,72 @@
+use crate::components::SaveSlotsListText;
+use crate::config::SAVE_SLOTS;
+use crate::content::ContentRegistry;
+use crate::resources::{SaveDir, SlotSelection};
+use crate::save::{SaveError, format_date, format_play_time, read_save};
+use crate::systems::map::GameData;
+use bevy::prelude::*;
+
+const SELECTED_COLOR: Color = Color::YELLOW;
+const EMPTY_COLOR: Color = Color::GRAY;
+const INVALID_COLOR: Color = Color::rgb(0.9, 0.3, 0.3);
+
+/// Réécrit la liste des emplacements quand la sélection change ou après une suppression
+/// Chaque sauvegarde est relue pour afficher classe, map, temps de jeu et date
+pub fn update_save_slots_ui(
+    selection: Res<SlotSelection>,
+    mut list_query: Query<&mut Text, With<SaveSlotsListText>>,
+    game_data: Res<GameData>,
+    registry: Res<ContentRegistry>,
+    save_dir: Res<SaveDir>,
+) {
+    if !selection.is_changed() {
+        return;
+    }
+    let Ok(mut text) = list_query.get_single_mut() else {
+        return;
+    };
+
+    let style = |color: Color| TextStyle {
+        font_size: 22.0,
+        color,
+        ..default()
+    };
+
+    let mut sections: Vec<TextSection> = (0..SAVE_SLOTS)
+        .map(|slot| {
+            let (summary, color) = match save_dir
+                .slot_path(slot)
+                .and_then(|path| read_save(&path, &registry, &game_data))
+            {
+                Ok(save) => (
+                    format!(
+                        "{} niv. {} - {} - {} - {}",
+                        registry.class(&save.player.class).name,
+                        save.player.level,
+                        game_data.maps[save.map_index].name,
+                        format_play_time(save.play_time_secs),
+                        format_date(save.saved_at)
+                    ),
+                    Color::WHITE,
+                ),
+                Err(SaveError::Missing { .. }) => ("(vide)".to_string(), EMPTY_COLOR),
+                Err(err) => (format!("invalide : {}", err), INVALID_COLOR),
+            };
+            let selected = slot == selection.index;
+            TextSection::new(
+                format!(
+                    "{} Emplacement {} : {}\n",
+                    if selected { ">" } else { " " },
+                    slot + 1,
+                    summary
+                ),
+                style(if selected { SELECTED_COLOR } else { color }),
+            )
+        })
+        .collect();
+    sections.push(TextSection::new(
+        format!("\n{}", selection.message),
+        style(Color::rgb(0.6, 0.8, 1.0)),
+    ));
+    text.sections = sections;
+}
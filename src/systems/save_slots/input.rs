@@ -0,0 +1,78 @@
+use crate::assets::ImageAssets;
+use crate::config::SAVE_SLOTS;
+use crate::content::ContentRegistry;
+use crate::controls::{Action, Controls};
+use crate::player::Player;
+use crate::resources::{SaveSlot, SlotSelection};
+use crate::save::delete_save;
+use crate::states::GameState;
+use crate::systems::save::GameProgress;
+use bevy::prelude::*;
+
+/// Gère l'écran des sauvegardes (touches par défaut, voir `controls::KeyPreset`) :
+/// - Haut/Bas (ou Z/S) : change l'emplacement sélectionné
+/// - Entrée : reprend la partie de l'emplacement, ou en commence une nouvelle s'il est vide
+/// - N : nouvelle partie dans l'emplacement (la sauvegarde existante sera écrasée)
+/// - Suppr : efface la sauvegarde de l'emplacement
+/// - T : ouvre l'écran des touches
+#[allow(clippy::too_many_arguments)]
+pub fn handle_save_slots_input(
+    mut commands: Commands,
+    controls: Controls,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut selection: ResMut<SlotSelection>,
+    mut save_slot: ResMut<SaveSlot>,
+    player_query: Query<Entity, With<Player>>,
+    mut progress: GameProgress,
+    registry: Res<ContentRegistry>,
+    image_assets: Res<ImageAssets>,
+) {
+    if controls.just_pressed(Action::Controls) {
+        next_state.set(GameState::Controls);
+    } else if controls.just_pressed(Action::MoveUp) {
+        selection.index = (selection.index + SAVE_SLOTS - 1) % SAVE_SLOTS;
+        selection.message.clear();
+    } else if controls.just_pressed(Action::MoveDown) {
+        selection.index = (selection.index + 1) % SAVE_SLOTS;
+        selection.message.clear();
+    } else if controls.just_pressed(Action::Confirm) {
+        let slot = selection.index;
+        let is_empty = progress
+            .save_dir
+            .slot_path(slot)
+            .is_ok_and(|path| !path.is_file());
+        save_slot.0 = slot;
+        if is_empty {
+            next_state.set(GameState::ClassSelection);
+            return;
+        }
+        match progress.load(
+            slot,
+            &mut commands,
+            player_query.iter(),
+            &registry,
+            &image_assets,
+        ) {
+            Ok(()) => {
+                progress
+                    .game_log
+                    .add_message(format!("Partie chargée (emplacement {})", slot + 1));
+                next_state.set(GameState::Map);
+            }
+            Err(err) => selection.message = format!("Echec du chargement : {}", err),
+        }
+    } else if controls.just_pressed(Action::NewGame) {
+        save_slot.0 = selection.index;
+        next_state.set(GameState::ClassSelection);
+    } else if controls.just_pressed(Action::DeleteSave) {
+        let slot = selection.index;
+        selection.message = match progress
+            .save_dir
+            .slot_path(slot)
+            .and_then(|path| delete_save(&path))
+        {
+            Ok(()) => format!("Emplacement {} efface", slot + 1),
+            Err(err) => format!("Echec de la suppression : {}", err),
+        };
+    }
+}
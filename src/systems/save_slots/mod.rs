@@ -0,0 +1,34 @@
+mod cleanup;
+mod display;
+mod input;
+mod setup;
+
+use crate::assets::load_assets;
+use crate::states::GameState;
+use crate::systems::class_selection::reset_game_state;
+use bevy::prelude::*;
+
+pub use cleanup::cleanup_save_slots;
+pub use display::update_save_slots_ui;
+pub use input::handle_save_slots_input;
+pub use setup::setup_save_slots;
+
+/// Écran des sauvegardes, au lancement et après une partie : chargement des textures et
+/// remise à zéro de la partie précédente à l'entrée
+pub struct SaveSlotsPlugin;
+
+impl Plugin for SaveSlotsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(GameState::SaveSlots),
+            (load_assets, reset_game_state, setup_save_slots),
+        )
+        .add_systems(
+            Update,
+            (handle_save_slots_input, update_save_slots_ui)
+                .chain()
+                .run_if(in_state(GameState::SaveSlots)),
+        )
+        .add_systems(OnExit(GameState::SaveSlots), cleanup_save_slots);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::components::{SaveSlotsListText, SaveSlotsUI};
+use crate::controls::{Action, KeyBindings};
+use crate::resources::{SaveSlot, SlotSelection};
+use bevy::prelude::*;
+
+fn save_slots_hint(bindings: &KeyBindings) -> String {
+    format!(
+        "{} / {} : choisir | {} : continuer | {} : nouvelle partie | {} : effacer | {} : touches",
+        bindings.hint(Action::MoveUp),
+        bindings.hint(Action::MoveDown),
+        bindings.hint(Action::Confirm),
+        bindings.hint(Action::NewGame),
+        bindings.hint(Action::DeleteSave),
+        bindings.hint(Action::Controls)
+    )
+}
+
+/// Crée l'écran des sauvegardes affiché au lancement du jeu, sélection sur l'emplacement actif
+/// La liste est remplie par `update_save_slots_ui`
+pub fn setup_save_slots(
+    mut commands: Commands,
+    save_slot: Res<SaveSlot>,
+    bindings: Res<KeyBindings>,
+) {
+    commands.insert_resource(SlotSelection {
+        index: save_slot.0,
+        ..default()
+    });
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: Color::rgb(0.1, 0.1, 0.15).into(),
+                z_index: ZIndex::Global(2000),
+                ..default()
+            },
+            SaveSlotsUI,
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+                TextBundle::from_section(
+                    "SAUVEGARDES",
+                    TextStyle {
+                        font_size: 40.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                }),
+            );
+
+            parent.spawn((
+                TextBundle::from_section("", TextStyle::default()).with_style(Style {
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                }),
+                SaveSlotsListText,
+            ));
+
+            parent.spawn(TextBundle::from_section(
+                save_slots_hint(&bindings),
+                TextStyle {
+                    font_size: 16.0,
+                    color: Color::GRAY,
+                    ..default()
+                },
+            ));
+        });
+}
@@ -0,0 +1,10 @@
+use crate::components::ShopUI;
+use crate::resources::ShopSelection;
+use bevy::prelude::*;
+
+pub fn cleanup_shop(mut commands: Commands, ui_query: Query<Entity, With<ShopUI>>) {
+    for entity in ui_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<ShopSelection>();
+}
@@ -0,0 +1,113 @@
+use crate::components::{ShopDetailText, ShopListText};
+use crate::content::ContentRegistry;
+use crate::inventory::Inventory;
+use crate::player::Player;
+use crate::resources::ShopSelection;
+use crate::shop::{ShopEntry, sell_price, shop_entries};
+use crate::systems::map::GameData;
+use bevy::prelude::*;
+
+const SELECTED_COLOR: Color = Color::YELLOW;
+const GOLD_COLOR: Color = Color::rgb(1.0, 0.85, 0.2);
+
+/// Réécrit la liste et le détail quand la sélection change ou après un achat ou une vente
+pub fn update_shop_ui(
+    selection: Res<ShopSelection>,
+    player_query: Query<(&Player, &Inventory)>,
+    mut list_query: Query<&mut Text, (With<ShopListText>, Without<ShopDetailText>)>,
+    mut detail_query: Query<&mut Text, (With<ShopDetailText>, Without<ShopListText>)>,
+    game_data: Res<GameData>,
+    registry: Res<ContentRegistry>,
+) {
+    if !selection.is_changed() {
+        return;
+    }
+    let (player, inventory) = player_query.single();
+    let stock = &game_data.get_current_map().shop;
+    let entries = shop_entries(stock, inventory);
+
+    let style = |color: Color| TextStyle {
+        font_size: 20.0,
+        color,
+        ..default()
+    };
+
+    if let Ok(mut text) = list_query.get_single_mut() {
+        text.sections = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let selected = index == selection.index;
+                let (line, color) = match *entry {
+                    ShopEntry::Buy(i) => {
+                        let item_def = registry.item(&stock[i]);
+                        (
+                            format!("{} - {} or", item_def.name, item_def.price),
+                            Color::WHITE,
+                        )
+                    }
+                    ShopEntry::Sell(i) => {
+                        let item = &inventory.bag[i];
+                        (
+                            format!(
+                                "{} - {} or",
+                                item.display_name(),
+                                sell_price(item, &registry)
+                            ),
+                            item.rarity.color(),
+                        )
+                    }
+                };
+                let header = match entry {
+                    ShopEntry::Buy(0) => "=== Acheter ===\n",
+                    ShopEntry::Sell(0) => "\n=== Vendre (sac) ===\n",
+                    _ => "",
+                };
+                TextSection::new(
+                    format!("{}{} {}\n", header, if selected { ">" } else { " " }, line),
+                    style(if selected { SELECTED_COLOR } else { color }),
+                )
+            })
+            .collect();
+    }
+
+    if let Ok(mut text) = detail_query.get_single_mut() {
+        let detail = match entries[selection.index] {
+            ShopEntry::Buy(i) => {
+                let item_def = registry.item(&stock[i]);
+                let mut effects = vec![item_def.stats.bonus_summary()];
+                if let Some(effect) = item_def.consumable {
+                    effects.push(effect.description());
+                }
+                if let Some(slot) = item_def.slot {
+                    effects.push(format!("Emplacement : {}", slot.label()));
+                }
+                effects.retain(|effect| !effect.is_empty());
+                format!(
+                    "{}\n{}\n\nPrix : {} or",
+                    item_def.name,
+                    effects.join("\n"),
+                    item_def.price
+                )
+            }
+            ShopEntry::Sell(i) => {
+                let item = &inventory.bag[i];
+                format!(
+                    "{}\n{}\nEmplacement : {}\n\nRevente : {} or",
+                    item.display_name(),
+                    item.stats.bonus_summary(),
+                    item.slot.label(),
+                    sell_price(item, &registry)
+                )
+            }
+        };
+        text.sections = vec![
+            TextSection::new(format!("Or : {}\n\n", player.gold), style(GOLD_COLOR)),
+            TextSection::new(detail, style(Color::WHITE)),
+            TextSection::new(
+                format!("\n\n{}", selection.message),
+                style(Color::rgb(0.6, 0.8, 1.0)),
+            ),
+        ];
+    }
+}
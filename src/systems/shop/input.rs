@@ -0,0 +1,77 @@
+use crate::content::ContentRegistry;
+use crate::controls::{Action, Controls};
+use crate::inventory::Inventory;
+use crate::loot::Rarity;
+use crate::player::Player;
+use crate::resources::{GameLog, ShopSelection};
+use crate::shop::{ShopEntry, sell_price, shop_entries};
+use crate::states::GameState;
+use crate::systems::map::GameData;
+use crate::systems::player::receive_item;
+use bevy::prelude::*;
+use regles::stats::Stats;
+
+/// Gère l'écran du marchand :
+/// - Haut/Bas (ou Z/S) : change la ligne sélectionnée
+/// - Entrée : achète l'objet du stock sélectionné (équipé si l'emplacement est libre,
+///   rangé avec les consommables s'il n'a pas d'emplacement) ou vend l'objet du sac sélectionné
+/// - Échap : retour à la map
+pub fn handle_shop_input(
+    controls: Controls,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut selection: ResMut<ShopSelection>,
+    mut player_query: Query<(&mut Player, &mut Inventory)>,
+    mut game_log: ResMut<GameLog>,
+    game_data: Res<GameData>,
+    registry: Res<ContentRegistry>,
+) {
+    if controls.just_pressed(Action::Cancel) {
+        next_state.set(GameState::Map);
+        return;
+    }
+
+    let (mut player, mut inventory) = player_query.single_mut();
+    let stock = &game_data.get_current_map().shop;
+    let entries = shop_entries(stock, &inventory);
+
+    if controls.just_pressed(Action::MoveUp) {
+        selection.index = (selection.index + entries.len() - 1) % entries.len();
+    } else if controls.just_pressed(Action::MoveDown) {
+        selection.index = (selection.index + 1) % entries.len();
+    } else if controls.just_pressed(Action::Confirm) {
+        selection.message = match entries[selection.index] {
+            ShopEntry::Buy(index) => {
+                let item_def = registry.item(&stock[index]);
+                if player.gold < item_def.price {
+                    format!("Pas assez d'or pour {}", item_def.name)
+                } else {
+                    player.gold -= item_def.price;
+                    game_log.add_message(format!(
+                        "Achat : {} (-{} or)",
+                        item_def.name, item_def.price
+                    ));
+                    receive_item(
+                        item_def,
+                        Rarity::Common,
+                        Stats::default(),
+                        &mut player,
+                        &mut inventory,
+                        &mut game_log,
+                    );
+                    format!("{} acheté", item_def.name)
+                }
+            }
+            ShopEntry::Sell(index) => {
+                let item = inventory.bag.remove(index);
+                let price = sell_price(&item, &registry);
+                player.gold += price;
+                game_log.add_message(format!("Vente : {} (+{} or)", item.display_name(), price));
+                format!("{} vendu", item.display_name())
+            }
+        };
+        // Le sac a changé : la sélection reste dans la liste
+        selection.index = selection
+            .index
+            .min(shop_entries(stock, &inventory).len() - 1);
+    }
+}
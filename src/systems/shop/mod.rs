@@ -0,0 +1,28 @@
+mod cleanup;
+mod display;
+mod input;
+mod setup;
+
+use crate::states::GameState;
+use bevy::prelude::*;
+
+pub use cleanup::cleanup_shop;
+pub use display::update_shop_ui;
+pub use input::handle_shop_input;
+pub use setup::setup_shop;
+
+/// Boutique du marchand de la map
+pub struct ShopPlugin;
+
+impl Plugin for ShopPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Shop), setup_shop)
+            .add_systems(
+                Update,
+                (handle_shop_input, update_shop_ui)
+                    .chain()
+                    .run_if(in_state(GameState::Shop)),
+            )
+            .add_systems(OnExit(GameState::Shop), cleanup_shop);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::components::{ShopDetailText, ShopListText, ShopUI};
+use crate::controls::{Action, KeyBindings};
+use crate::resources::ShopSelection;
+use bevy::prelude::*;
+
+fn shop_hint(bindings: &KeyBindings) -> String {
+    format!(
+        "{} / {} : choisir | {} : acheter / vendre | {} : quitter",
+        bindings.hint(Action::MoveUp),
+        bindings.hint(Action::MoveDown),
+        bindings.hint(Action::Confirm),
+        bindings.hint(Action::Cancel)
+    )
+}
+
+/// Crée l'écran du marchand : stock et sac à gauche, détail de l'objet sélectionné à droite
+/// Les textes sont remplis par `update_shop_ui`
+pub fn setup_shop(mut commands: Commands, bindings: Res<KeyBindings>) {
+    commands.insert_resource(ShopSelection::default());
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::all(Val::Px(20.0)),
+                    ..default()
+                },
+                background_color: Color::rgb(0.12, 0.1, 0.08).into(),
+                z_index: ZIndex::Global(2000),
+                ..default()
+            },
+            ShopUI,
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+                TextBundle::from_section(
+                    "MARCHAND",
+                    TextStyle {
+                        font_size: 32.0,
+                        color: Color::rgb(0.8, 0.8, 0.2),
+                        ..default()
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect::bottom(Val::Px(20.0)),
+                    ..default()
+                }),
+            );
+
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        flex_grow: 1.0,
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceAround,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        TextBundle::from_section("", TextStyle::default()).with_style(Style {
+                            width: Val::Percent(50.0),
+                            ..default()
+                        }),
+                        ShopListText,
+                    ));
+                    parent.spawn((
+                        TextBundle::from_section("", TextStyle::default()).with_style(Style {
+                            width: Val::Percent(40.0),
+                            ..default()
+                        }),
+                        ShopDetailText,
+                    ));
+                });
+
+            parent.spawn(TextBundle::from_section(
+                shop_hint(&bindings),
+                TextStyle {
+                    font_size: 16.0,
+                    color: Color::GRAY,
+                    ..default()
+                },
+            ));
+        });
+}
@@ -0,0 +1,8 @@
+use crate::components::SummaryUI;
+use bevy::prelude::*;
+
+pub fn cleanup_summary(mut commands: Commands, ui_query: Query<Entity, With<SummaryUI>>) {
+    for entity in ui_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,19 @@
+use crate::controls::{Action, Controls};
+use crate::states::GameState;
+use bevy::app::AppExit;
+use bevy::prelude::*;
+
+/// Gère les touches de l'écran de bilan
+/// - R : recommencer (retour à l'écran des sauvegardes, qui réinitialise la progression)
+/// - Échap : quitte le jeu
+pub fn handle_summary_input(
+    controls: Controls,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    if controls.just_pressed(Action::Restart) {
+        next_state.set(GameState::SaveSlots);
+    } else if controls.just_pressed(Action::Cancel) {
+        exit.send(AppExit);
+    }
+}
@@ -0,0 +1,27 @@
+mod cleanup;
+mod input;
+mod setup;
+
+use crate::states::GameState;
+use bevy::prelude::*;
+
+pub use cleanup::cleanup_summary;
+pub use input::handle_summary_input;
+pub use setup::setup_summary;
+
+/// Écran de bilan après une victoire ou une défaite
+pub struct SummaryPlugin;
+
+impl Plugin for SummaryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Victory), setup_summary)
+            .add_systems(OnEnter(GameState::Defeat), setup_summary)
+            .add_systems(
+                Update,
+                handle_summary_input
+                    .run_if(in_state(GameState::Victory).or_else(in_state(GameState::Defeat))),
+            )
+            .add_systems(OnExit(GameState::Victory), cleanup_summary)
+            .add_systems(OnExit(GameState::Defeat), cleanup_summary);
+    }
+}
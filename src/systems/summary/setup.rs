@@ -0,0 +1,110 @@
+use crate::components::SummaryUI;
+use crate::content::ContentRegistry;
+use crate::controls::{Action, KeyBindings};
+use crate::player::Player;
+use crate::resources::{DefeatedEnemies, RunStats};
+use crate::save::format_play_time;
+use crate::states::GameState;
+use crate::systems::map::GameData;
+use bevy::prelude::*;
+
+fn summary_hint(bindings: &KeyBindings) -> String {
+    format!(
+        "{} : recommencer | {} : quitter",
+        bindings.hint(Action::Restart),
+        bindings.hint(Action::Cancel)
+    )
+}
+
+/// Crée l'écran de bilan de fin de partie, après une victoire ou une défaite
+/// Fixe la fin du temps de jeu
+#[allow(clippy::too_many_arguments)]
+pub fn setup_summary(
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    time: Res<Time>,
+    mut run_stats: ResMut<RunStats>,
+    player_query: Query<&Player>,
+    defeated_enemies: Res<DefeatedEnemies>,
+    registry: Res<ContentRegistry>,
+    game_data: Res<GameData>,
+    bindings: Res<KeyBindings>,
+) {
+    run_stats.finished_at = time.elapsed();
+
+    let (title, title_color) = match state.get() {
+        GameState::Victory => ("VICTOIRE !", Color::rgb(1.0, 0.85, 0.2)),
+        _ => ("DEFAITE...", Color::rgb(0.9, 0.2, 0.2)),
+    };
+
+    let player = player_query.single();
+    let summary = format!(
+        "Classe : {} (niveau {})\nTours joues : {}\nEnnemis vaincus : {}\nObjets ramasses : {}\nDegats infliges : {}\nTemps de jeu : {}\nGraine du donjon : {}",
+        registry.class(&player.class).name,
+        player.level,
+        run_stats.turns,
+        defeated_enemies.enemies.len(),
+        run_stats.items_collected,
+        run_stats.damage_dealt,
+        format_play_time(run_stats.play_time().as_secs()),
+        game_data.dungeon.seed
+    );
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: Color::rgb(0.1, 0.1, 0.15).into(),
+                z_index: ZIndex::Global(2000),
+                ..default()
+            },
+            SummaryUI,
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+                TextBundle::from_section(
+                    title,
+                    TextStyle {
+                        font_size: 48.0,
+                        color: title_color,
+                        ..default()
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                }),
+            );
+
+            parent.spawn(
+                TextBundle::from_section(
+                    summary,
+                    TextStyle {
+                        font_size: 24.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                }),
+            );
+
+            parent.spawn(TextBundle::from_section(
+                summary_hint(&bindings),
+                TextStyle {
+                    font_size: 16.0,
+                    color: Color::GRAY,
+                    ..default()
+                },
+            ));
+        });
+}
@@ -1,15 +1,21 @@
-use crate::components::{InfoTerminal, LogText, StatsText};
+use crate::components::{InfoTerminal, LogText, QuestText, StatsText};
 use crate::config::TERMINAL_WIDTH;
-use crate::player::{Player, PlayerClass};
-use crate::resources::GameLog;
+use crate::content::ContentRegistry;
+use crate::player::Player;
+use crate::quest::{Objective, QuestDef};
+use crate::resources::{GameLog, QuestLog};
+use crate::systems::map::GameData;
 use bevy::prelude::*;
 
 /// Crée l'UI du terminal d'information sur le côté droit de l'écran
-/// Affiche les statistiques du joueur et les événements récents
+/// Affiche les statistiques du joueur, les quêtes actives et les événements récents
 pub fn setup_info_terminal(
     mut commands: Commands,
     player_query: Query<&Player>,
     mut game_log: ResMut<GameLog>,
+    quest_log: Res<QuestLog>,
+    registry: Res<ContentRegistry>,
+    game_data: Res<GameData>,
 ) {
     let player = player_query.single();
 
@@ -67,14 +73,20 @@ pub fn setup_info_terminal(
                 }),
             );
 
-            let class_name = get_class_name(player.class);
+            let class_name = &registry.class(&player.class).name;
             let stats_text = format!(
-                "Classe: {}\nHP: {}\nAttaque: {}\nVitesse: {}\nCritique: {}%",
+                "Classe: {}\nNiveau: {} (XP {} / {})\nHP: {} / {}\nAttaque: {}\nVitesse: {}\nCritique: {}%\nOr: {}\nDonjon: graine {}",
                 class_name,
+                player.level,
+                player.xp,
+                player.xp_to_next_level(),
+                player.current_hp,
                 player.stats.hp,
                 player.stats.attack,
                 player.stats.speed,
-                player.stats.critical_chance
+                player.stats.critical_chance,
+                player.gold,
+                game_data.dungeon.seed
             );
 
             parent.spawn((
@@ -93,6 +105,38 @@ pub fn setup_info_terminal(
                 StatsText,
             ));
 
+            // Section Quêtes
+            parent.spawn(
+                TextBundle::from_section(
+                    "=== Quêtes ===",
+                    TextStyle {
+                        font_size: 18.0,
+                        color: Color::rgb(0.8, 0.8, 0.2),
+                        ..default()
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect::bottom(Val::Px(5.0)),
+                    ..default()
+                }),
+            );
+
+            parent.spawn((
+                TextBundle::from_section(
+                    quest_text(&quest_log, &registry),
+                    TextStyle {
+                        font_size: 14.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect::bottom(Val::Px(15.0)),
+                    ..default()
+                }),
+                QuestText,
+            ));
+
             // Section Log
             parent.spawn(
                 TextBundle::from_section(
@@ -124,29 +168,48 @@ pub fn setup_info_terminal(
 }
 
 /// Met à jour en temps réel le contenu du terminal d'information
-/// Synchronise les stats du joueur et le log de jeu
+/// Synchronise les stats du joueur, les quêtes et le log de jeu
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn update_info_terminal(
     player_query: Query<&Player>,
-    mut stats_text_query: Query<&mut Text, (With<StatsText>, Without<LogText>)>,
-    mut log_text_query: Query<&mut Text, (With<LogText>, Without<StatsText>)>,
+    mut stats_text_query: Query<&mut Text, (With<StatsText>, Without<LogText>, Without<QuestText>)>,
+    mut log_text_query: Query<&mut Text, (With<LogText>, Without<StatsText>, Without<QuestText>)>,
+    mut quest_text_query: Query<&mut Text, (With<QuestText>, Without<StatsText>, Without<LogText>)>,
     game_log: Res<GameLog>,
+    quest_log: Res<QuestLog>,
+    registry: Res<ContentRegistry>,
+    game_data: Res<GameData>,
 ) {
     // Mettre à jour les stats du joueur
-    if let Ok(player) = player_query.get_single() {
-        if let Ok(mut text) = stats_text_query.get_single_mut() {
-            let class_name = get_class_name(player.class);
-            let new_stats = format!(
-                "Classe: {}\nHP: {}\nAttaque: {}\nVitesse: {}\nCritique: {}%",
-                class_name,
-                player.stats.hp,
-                player.stats.attack,
-                player.stats.speed,
-                player.stats.critical_chance
-            );
+    if let Ok(player) = player_query.get_single()
+        && let Ok(mut text) = stats_text_query.get_single_mut()
+    {
+        let class_name = &registry.class(&player.class).name;
+        let new_stats = format!(
+            "Classe: {}\nNiveau: {} (XP {} / {})\nHP: {} / {}\nAttaque: {}\nVitesse: {}\nCritique: {}%\nOr: {}\nDonjon: graine {}",
+            class_name,
+            player.level,
+            player.xp,
+            player.xp_to_next_level(),
+            player.current_hp,
+            player.stats.hp,
+            player.stats.attack,
+            player.stats.speed,
+            player.stats.critical_chance,
+            player.gold,
+            game_data.dungeon.seed
+        );
+
+        if text.sections[0].value != new_stats {
+            text.sections[0].value = new_stats;
+        }
+    }
 
-            if text.sections[0].value != new_stats {
-                text.sections[0].value = new_stats;
-            }
+    // Mettre à jour les quêtes
+    if let Ok(mut text) = quest_text_query.get_single_mut() {
+        let new_quests = quest_text(&quest_log, &registry);
+        if text.sections[0].value != new_quests {
+            text.sections[0].value = new_quests;
         }
     }
 
@@ -164,6 +227,46 @@ pub fn update_info_terminal(
     }
 }
 
+/// Quêtes non terminées avec leur objectif et leur progression
+fn quest_text(quest_log: &QuestLog, registry: &ContentRegistry) -> String {
+    let lines: Vec<String> = registry
+        .quests
+        .iter()
+        .filter(|quest| !quest_log.completed.contains(&quest.id))
+        .map(|quest| {
+            let (current, target) = quest_log.progress(quest);
+            format!(
+                "{} ({}/{})\n  {}",
+                quest.name,
+                current,
+                target,
+                objective_text(quest, registry)
+            )
+        })
+        .collect();
+    if lines.is_empty() {
+        "Aucune quête en cours".to_string()
+    } else {
+        lines.join("\n")
+    }
+}
+
+fn objective_text(quest: &QuestDef, registry: &ContentRegistry) -> String {
+    match &quest.objective {
+        Objective::Defeat { enemy, count } => {
+            format!("Vaincre {} x{}", registry.enemy(enemy).name, count)
+        }
+        Objective::Collect(items) => {
+            let names: Vec<&str> = items
+                .iter()
+                .map(|item| registry.item(item).name.as_str())
+                .collect();
+            format!("Trouver : {}", names.join(", "))
+        }
+        Objective::ReachMap(name) => format!("Atteindre {}", name),
+    }
+}
+
 pub fn cleanup_info_terminal(
     mut commands: Commands,
     terminal_query: Query<Entity, With<InfoTerminal>>,
@@ -172,12 +275,3 @@ pub fn cleanup_info_terminal(
         commands.entity(entity).despawn_recursive();
     }
 }
-
-fn get_class_name(class: PlayerClass) -> &'static str {
-    match class {
-        PlayerClass::Warrior => "Guerrier",
-        PlayerClass::Mage => "Magicien",
-        PlayerClass::Assassin => "Assassin",
-        PlayerClass::Executioner => "Bourreau",
-    }
-}
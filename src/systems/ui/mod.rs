@@ -1,3 +1,20 @@
 mod info_terminal;
 
+use crate::states::GameState;
+use bevy::prelude::*;
+
 pub use info_terminal::{cleanup_info_terminal, setup_info_terminal, update_info_terminal};
+
+/// Terminal d'informations affiché à côté de la map
+pub struct UiPlugin;
+
+impl Plugin for UiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Map), setup_info_terminal)
+            .add_systems(
+                Update,
+                update_info_terminal.run_if(in_state(GameState::Map)),
+            )
+            .add_systems(OnExit(GameState::Map), cleanup_info_terminal);
+    }
+}
@@ -0,0 +1,163 @@
+//! Donjon généré à partir de la graine de la partie
+
+use jeu_projet::content::ContentRegistry;
+use jeu_projet::load_data;
+use jeu_projet::systems::map::{Dungeon, GameData, Map};
+use regles::dungeon::{MIN_FLOOR_SIZE, is_connected};
+use regles::grid::Tile;
+use std::path::Path;
+
+/// Graine des tests : le donjon d'une partie dépend de la graine tirée au début de la partie
+const SEED: u64 = 1789;
+
+fn load() -> (ContentRegistry, GameData) {
+    let (registry, mut game_data) =
+        load_data(&Path::new(env!("CARGO_MANIFEST_DIR")).join("assets"))
+            .expect("données du jeu invalides");
+    game_data.set_dungeon_seed(SEED);
+    (registry, game_data)
+}
+
+fn floors(game_data: &GameData) -> Vec<&Map> {
+    game_data
+        .maps
+        .iter()
+        .filter(|map| map.name.starts_with("donjon_"))
+        .collect()
+}
+
+/// Contenu comparable d'une map (les HashMap sont triées pour ne pas dépendre de leur ordre)
+fn snapshot(map: &Map) -> String {
+    let mut connections: Vec<_> = map.connections.iter().collect();
+    connections.sort_by_key(|(at, _)| (at.y, at.x));
+    format!(
+        "{} {:?} {:?} {:?} {:?} {:?}",
+        map.name, map.grid, map.player_start, connections, map.items, map.enemies
+    )
+}
+
+fn snapshots(game_data: &GameData) -> Vec<String> {
+    floors(game_data).into_iter().map(snapshot).collect()
+}
+
+#[test]
+fn same_seed_gives_same_dungeon() {
+    let (registry, first) = load();
+    let (_, second) = load();
+    assert_eq!(floors(&first).len(), registry.dungeon.floors);
+    assert_eq!(snapshots(&first), snapshots(&second));
+}
+
+#[test]
+fn other_seed_gives_other_dungeon() {
+    let (_, mut game_data) = load();
+    let first = snapshots(&game_data);
+
+    game_data.set_dungeon_seed(SEED + 1);
+    assert_eq!(game_data.dungeon.seed, SEED + 1);
+    assert_eq!(floors(&game_data).len(), first.len());
+    assert_ne!(snapshots(&game_data), first);
+
+    // Revenir à la graine redonne le même donjon, sans étages en trop
+    game_data.set_dungeon_seed(SEED);
+    assert_eq!(snapshots(&game_data), first);
+}
+
+#[test]
+fn map_with_seed_generates_the_floors_of_another_seed() {
+    let (_, game_data) = load();
+    let (_, mut other) = load();
+    other.set_dungeon_seed(SEED + 1);
+
+    let index = game_data.dungeon.first_floor();
+    let generated = game_data.map_with_seed(index, SEED + 1).unwrap();
+    assert_eq!(snapshot(&generated), snapshot(&other.maps[index]));
+    assert_eq!(
+        snapshot(&game_data.map_with_seed(index, SEED).unwrap()),
+        snapshot(&game_data.maps[index])
+    );
+    assert!(
+        game_data
+            .map_with_seed(game_data.maps.len(), SEED + 1)
+            .is_none()
+    );
+}
+
+#[test]
+fn floors_are_connected_and_linked_by_stairs() {
+    let (registry, game_data) = load();
+    let entrance = &registry.dungeon.entrance;
+    let entrance_map = game_data
+        .maps
+        .iter()
+        .find(|map| map.name == entrance.map)
+        .unwrap();
+    let (first_floor, arrival) = entrance_map.connections[&entrance.at];
+    assert_eq!(game_data.maps[first_floor].name, "donjon_1");
+    assert_eq!(arrival, game_data.maps[first_floor].player_start);
+
+    for map in floors(&game_data) {
+        assert!(is_connected(&map.grid), "{} a des cases isolées", map.name);
+        assert!(map.is_walkable(map.player_start.x, map.player_start.y));
+        for (at, enemy) in &map.enemies {
+            assert!(map.is_walkable(at.x, at.y), "{:?} dans un mur", enemy);
+        }
+        for (at, item) in &map.items {
+            assert!(map.is_walkable(at.x, at.y), "{:?} dans un mur", item);
+        }
+        for (to_map, to) in map.connections.values() {
+            assert!(game_data.maps[*to_map].is_walkable(to.x, to.y));
+        }
+    }
+
+    // Chaque étage a un escalier vers le précédent (ou l'entrée) et, sauf le dernier, vers
+    // le suivant
+    let floors = floors(&game_data);
+    for (depth, map) in floors.iter().enumerate() {
+        let expected = if depth + 1 == floors.len() { 1 } else { 2 };
+        assert_eq!(map.connections.len(), expected, "{}", map.name);
+    }
+}
+
+#[test]
+fn deeper_floors_have_stronger_enemies() {
+    let (registry, game_data) = load();
+    let xp = |map: &Map| -> Vec<u32> {
+        map.enemies
+            .iter()
+            .map(|(_, enemy)| registry.enemy(enemy).xp)
+            .collect()
+    };
+
+    let floors = floors(&game_data);
+    for pair in floors.windows(2) {
+        let (upper, lower) = (xp(pair[0]), xp(pair[1]));
+        assert!(
+            lower.len() > upper.len(),
+            "{} a moins d'ennemis",
+            pair[1].name
+        );
+        assert!(
+            upper.iter().max() <= lower.iter().min(),
+            "{} a des ennemis plus forts que {}",
+            pair[0].name,
+            pair[1].name
+        );
+    }
+}
+
+#[test]
+fn first_map_too_small_for_the_floors_is_a_load_error() {
+    let (registry, _) = load();
+    let size = MIN_FLOOR_SIZE - 1;
+    let small = Map {
+        name: "petite".to_string(),
+        grid: vec![vec![Tile::Path; size]; size],
+        width: size,
+        height: size,
+        ..Default::default()
+    };
+
+    let err = Dungeon::new(&[small], &registry.dungeon, Path::new("maps")).unwrap_err();
+    assert!(err.to_string().contains("trop petite"), "{}", err);
+}
@@ -0,0 +1,204 @@
+//! Application du jeu sans fenêtre pour les tests d'intégration
+//!
+//! `GameHarness` construit l'application de `main.rs` (`jeu_projet::GamePlugin`) avec les
+//! `MinimalPlugins`, simule les touches dans `Input<KeyCode>` et fait avancer les frames
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use jeu_projet::components::Position;
+use jeu_projet::controls::{Action, KeyBindings};
+use jeu_projet::enemy::Enemy;
+use jeu_projet::player::Player;
+use jeu_projet::resources::{CollectedItems, CombatState, DefeatedEnemies, SaveDir};
+use jeu_projet::states::GameState;
+use jeu_projet::{GamePlugin, load_data};
+use std::path::{Path, PathBuf};
+use std::sync::Once;
+use std::time::Duration;
+
+/// Durée d'une frame simulée : le temps avance de la même façon à chaque frame
+const FRAME: Duration = Duration::from_millis(100);
+
+/// Nombre maximal de frames d'un combat avant d'abandonner le test
+const MAX_COMBAT_FRAMES: usize = 2_000;
+
+pub struct GameHarness {
+    pub app: App,
+}
+
+impl GameHarness {
+    /// Application arrêtée sur l'écran des sauvegardes, avec les touches par défaut et un
+    /// dossier de sauvegardes vide, propre au test
+    pub fn new() -> Self {
+        use_test_data_dir();
+        let save_dir = test_save_dir();
+
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let (registry, game_data) =
+            load_data(&manifest_dir.join("assets")).expect("données du jeu invalides");
+
+        let mut app = App::new();
+        // Les textures et polices sont chargées sans être affichées
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>()
+            .init_asset::<Font>()
+            .init_resource::<Input<KeyCode>>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+            .insert_resource(registry)
+            .insert_resource(game_data)
+            .insert_resource(KeyBindings::default())
+            .add_plugins(GamePlugin)
+            .insert_resource(SaveDir(Some(save_dir)));
+
+        let mut harness = GameHarness { app };
+        harness.step(1);
+        harness
+    }
+
+    /// Nouvelle partie dans le premier emplacement avec la N-ième classe du registre
+    /// (1 = Guerrier)
+    pub fn new_game(class_number: usize) -> Self {
+        let mut harness = GameHarness::new();
+        harness.press_action(Action::NewGame);
+        assert_eq!(harness.state(), GameState::ClassSelection);
+        harness.press_action(Action::CLASS_SLOTS[class_number - 1]);
+        assert_eq!(harness.state(), GameState::Map);
+        harness
+    }
+
+    /// Fait avancer l'application de `frames` frames
+    pub fn step(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.app.update();
+        }
+    }
+
+    /// Appuie sur une touche pendant une frame, puis laisse deux frames aux conséquences
+    /// (rencontre d'un ennemi, changement d'état, transition de map)
+    pub fn press(&mut self, key: KeyCode) {
+        self.app.world.resource_mut::<Input<KeyCode>>().press(key);
+        self.step(1);
+        let mut input = self.app.world.resource_mut::<Input<KeyCode>>();
+        input.release(key);
+        input.clear();
+        self.step(2);
+    }
+
+    /// Appuie sur la première touche associée à l'action
+    pub fn press_action(&mut self, action: Action) {
+        let key = self.app.world.resource::<KeyBindings>().keys(action)[0];
+        self.press(key);
+    }
+
+    /// Enchaîne les déplacements, en s'arrêtant si l'un d'eux quitte la map
+    pub fn walk(&mut self, moves: &[Action]) {
+        for action in moves {
+            assert_eq!(self.state(), GameState::Map, "déplacement hors de la map");
+            self.press_action(*action);
+        }
+    }
+
+    /// Attaque jusqu'à la fin du combat, choisit les améliorations de niveau (HP) et
+    /// retourne sur la map ; échoue en cas de défaite
+    pub fn win_combat(&mut self) {
+        assert_eq!(self.state(), GameState::Combat, "aucun combat en cours");
+        for _ in 0..MAX_COMBAT_FRAMES {
+            if self.state() != GameState::Combat {
+                return;
+            }
+            let combat_state = self.app.world.resource::<CombatState>();
+            assert!(!combat_state.combat.is_lost(), "combat perdu");
+            if combat_state.combat.is_won() && !combat_state.is_player_turn {
+                if combat_state.pending_upgrades > 0 {
+                    self.press_action(Action::ChooseHp);
+                } else {
+                    self.press_action(Action::Confirm);
+                }
+            } else if combat_state.is_player_turn {
+                self.press_action(Action::Attack);
+            } else {
+                // Pause du tour ennemi
+                self.step(1);
+            }
+        }
+        panic!("combat non terminé après {} frames", MAX_COMBAT_FRAMES);
+    }
+
+    pub fn state(&self) -> GameState {
+        *self.app.world.resource::<State<GameState>>().get()
+    }
+
+    pub fn player_position(&mut self) -> Position {
+        *self
+            .app
+            .world
+            .query_filtered::<&Position, With<Player>>()
+            .single(&self.app.world)
+    }
+
+    pub fn player(&mut self) -> Player {
+        self.app
+            .world
+            .query::<&Player>()
+            .single(&self.app.world)
+            .clone()
+    }
+
+    pub fn current_map_index(&self) -> usize {
+        self.app
+            .world
+            .resource::<jeu_projet::systems::map::GameData>()
+            .current_map_index
+    }
+
+    /// Un ennemi est affiché en `position` sur la map actuelle
+    pub fn enemy_at(&mut self, position: Position) -> bool {
+        self.app
+            .world
+            .query_filtered::<&Position, With<Enemy>>()
+            .iter(&self.app.world)
+            .any(|enemy_position| *enemy_position == position)
+    }
+
+    pub fn defeated_enemies(&self) -> &DefeatedEnemies {
+        self.app.world.resource::<DefeatedEnemies>()
+    }
+
+    pub fn collected_items(&self) -> &CollectedItems {
+        self.app.world.resource::<CollectedItems>()
+    }
+}
+
+/// Les fichiers du jeu (touches) sont écrits dans un dossier temporaire du dossier target/,
+/// vidé une fois par exécution des tests, et non dans celui du joueur
+fn use_test_data_dir() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("donnees");
+        let _ = std::fs::remove_dir_all(&dir);
+        // SAFETY: appelé avant la création de l'application, à l'abri de `Once` : aucun autre
+        // thread des tests ne lit l'environnement pendant la modification
+        unsafe {
+            std::env::set_var("XDG_DATA_HOME", &dir);
+            std::env::set_var("APPDATA", &dir);
+            std::env::set_var("HOME", &dir);
+        }
+    });
+}
+
+/// Dossier de sauvegardes du test en cours, nommé d'après le test et vidé à sa création : les
+/// tests lancés en parallèle ne lisent ni n'écrasent jamais les sauvegardes des autres
+fn test_save_dir() -> PathBuf {
+    let thread = std::thread::current();
+    let test_name = thread.name().unwrap_or("main").replace("::", "_");
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .join("sauvegardes")
+        .join(test_name);
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Raccourci pour les positions des maps
+pub fn pos(x: usize, y: usize) -> Position {
+    Position { x, y }
+}
@@ -0,0 +1,332 @@
+//! Parcours complets du jeu, joués au clavier sur l'application sans fenêtre
+
+mod harness;
+
+use bevy::prelude::*;
+use harness::{GameHarness, pos};
+use jeu_projet::components::{ActionMessageText, Position};
+use jeu_projet::content::ContentRegistry;
+use jeu_projet::controls::Action::{MoveDown, MoveLeft, MoveRight, MoveUp};
+use jeu_projet::controls::{Action, KeyBindings};
+use jeu_projet::dungeon::DungeonSeedChoice;
+use jeu_projet::enemy::EnemyType;
+use jeu_projet::events::{ChestOpened, CombatRewards, CombatStarted, EnemyDefeated, ItemPickedUp};
+use jeu_projet::inventory::Inventory;
+use jeu_projet::item::ItemType;
+use jeu_projet::resources::{CombatState, GameLog, QuestLog, RunStats};
+use jeu_projet::states::GameState;
+use jeu_projet::systems::map::GameData;
+use regles::grid::Tile;
+
+#[test]
+fn launch_shows_save_slots_then_class_selection() {
+    let mut game = GameHarness::new();
+    assert_eq!(game.state(), GameState::SaveSlots);
+
+    game.press_action(Action::NewGame);
+    assert_eq!(game.state(), GameState::ClassSelection);
+
+    game.press_action(Action::Cancel);
+    assert_eq!(game.state(), GameState::SaveSlots);
+}
+
+#[test]
+fn classes_are_chosen_with_their_bound_keys() {
+    let mut game = GameHarness::new();
+    game.app
+        .world
+        .resource_mut::<KeyBindings>()
+        .rebind(Action::ChooseClass2, KeyCode::K);
+    game.press_action(Action::NewGame);
+
+    // L'ancienne touche ne choisit plus la classe
+    game.press(KeyCode::Key2);
+    assert_eq!(game.state(), GameState::ClassSelection);
+    game.press(KeyCode::K);
+    assert_eq!(game.state(), GameState::Map);
+    let second_class = game.app.world.resource::<ContentRegistry>().classes[1]
+        .id
+        .clone();
+    assert_eq!(game.player().class, second_class);
+}
+
+#[test]
+fn new_game_starts_on_first_map() {
+    let mut game = GameHarness::new_game(1);
+    assert_eq!(game.current_map_index(), 0);
+    assert_eq!(game.player_position(), pos(1, 1));
+}
+
+#[test]
+fn walls_block_movement() {
+    let mut game = GameHarness::new_game(1);
+    game.walk(&[MoveDown, MoveLeft]);
+    assert_eq!(game.player_position(), pos(1, 1));
+
+    game.walk(&[MoveRight]);
+    assert_eq!(game.player_position(), pos(2, 1));
+}
+
+#[test]
+fn quick_load_does_not_rewrite_the_save() {
+    let mut game = GameHarness::new_game(1);
+    game.walk(&[MoveRight]);
+    game.press_action(Action::QuickSave);
+    game.walk(&[MoveRight]);
+
+    game.press_action(Action::QuickLoad);
+    assert_eq!(game.state(), GameState::Map);
+    assert_eq!(game.player_position(), pos(2, 1));
+    let log = &game.app.world.resource::<GameLog>().messages;
+    let loaded = log.iter().rposition(|message| message == "Partie chargée");
+    let after_load = &log[loaded.expect("chargement absent du log") + 1..];
+    assert!(
+        !after_load
+            .iter()
+            .any(|message| message.starts_with("Sauvegarde automatique")),
+        "{:?}",
+        after_load
+    );
+}
+
+#[test]
+fn dungeon_seed_is_chosen_at_new_game_and_restored_on_load() {
+    let mut game = GameHarness::new();
+    game.app.insert_resource(DungeonSeedChoice(Some(42)));
+    game.press_action(Action::NewGame);
+    game.press_action(Action::ChooseClass1);
+    assert_eq!(game.state(), GameState::Map);
+    assert_eq!(game.app.world.resource::<GameData>().dungeon.seed, 42);
+
+    game.press_action(Action::QuickSave);
+    game.app
+        .world
+        .resource_mut::<GameData>()
+        .set_dungeon_seed(7);
+    game.press_action(Action::QuickLoad);
+    assert_eq!(game.app.world.resource::<GameData>().dungeon.seed, 42);
+}
+
+#[test]
+fn fleeing_back_onto_a_campfire_does_not_heal() {
+    let mut game = GameHarness::new_game(1);
+    // Feu de camp juste avant le gobelin en (4, 1)
+    game.app.world.resource_mut::<GameData>().maps[0].grid[1][3] = Tile::Rest;
+    game.walk(&[MoveRight, MoveRight, MoveRight]);
+    assert_eq!(game.state(), GameState::Combat);
+
+    let max_hp = game.player().stats.hp;
+    game.app
+        .world
+        .resource_mut::<CombatState>()
+        .combat
+        .player
+        .hp = max_hp / 2;
+    for _ in 0..200 {
+        if game.state() != GameState::Combat {
+            break;
+        }
+        if game.app.world.resource::<CombatState>().is_player_turn {
+            game.press_action(Action::Flee);
+        } else {
+            game.step(1);
+        }
+    }
+    assert_eq!(game.state(), GameState::Map);
+    assert_eq!(game.player_position(), pos(3, 1));
+    assert!(game.player().current_hp <= max_hp / 2);
+
+    // Revenir sur le feu de camp en se déplaçant soigne toujours
+    game.walk(&[MoveLeft, MoveRight]);
+    assert_eq!(game.player().current_hp, max_hp);
+}
+
+#[test]
+fn skill_protection_is_announced() {
+    // Assassin : Pas de l'ombre, attaque puis esquive garantie de la riposte
+    let mut game = GameHarness::new_game(3);
+    game.walk(&[MoveRight, MoveRight, MoveRight]);
+    assert_eq!(game.state(), GameState::Combat);
+    while !game.app.world.resource::<CombatState>().is_player_turn {
+        game.step(1);
+    }
+    game.app
+        .world
+        .resource_mut::<CombatState>()
+        .combat
+        .player
+        .level = 2;
+    game.press_action(Action::Skill);
+
+    let message = game
+        .app
+        .world
+        .query_filtered::<&Text, With<ActionMessageText>>()
+        .single(&game.app.world)
+        .sections
+        .iter()
+        .map(|section| section.value.as_str())
+        .collect::<String>();
+    assert!(message.contains("Pas de l'ombre !"), "{}", message);
+    assert!(
+        message.contains("La prochaine attaque ennemie sera esquivee"),
+        "{}",
+        message
+    );
+}
+
+#[test]
+fn combat_items_are_chosen_with_their_bound_keys() {
+    let mut game = GameHarness::new_game(1);
+    let registry = game.app.world.resource::<ContentRegistry>().clone();
+    let potion = registry.item(&ItemType("Potion".to_string()));
+    let mut inventory = game
+        .app
+        .world
+        .query::<&mut Inventory>()
+        .single_mut(&mut game.app.world);
+    inventory.add_consumable(potion, potion.consumable.unwrap());
+    game.app
+        .world
+        .resource_mut::<KeyBindings>()
+        .rebind(Action::UseItem1, KeyCode::J);
+
+    game.walk(&[MoveRight, MoveRight, MoveRight]);
+    assert_eq!(game.state(), GameState::Combat);
+    while !game.app.world.resource::<CombatState>().is_player_turn {
+        game.step(1);
+    }
+    game.press_action(Action::UseItem);
+    assert!(game.app.world.resource::<CombatState>().choosing_item);
+
+    // L'ancienne touche ne choisit plus l'objet
+    game.press(KeyCode::Numpad1);
+    assert!(game.app.world.resource::<CombatState>().choosing_item);
+    game.press(KeyCode::J);
+    assert!(!game.app.world.resource::<CombatState>().choosing_item);
+    let consumables = game
+        .app
+        .world
+        .query::<&Inventory>()
+        .single(&game.app.world)
+        .consumables
+        .len();
+    assert_eq!(consumables, 0);
+}
+
+#[test]
+fn picked_up_item_is_collected_once() {
+    let mut game = GameHarness::new_game(1);
+    game.walk(&[MoveUp, MoveUp, MoveRight]);
+    assert_eq!(game.player_position(), pos(2, 3));
+    assert!(game.collected_items().items.contains(&(0, pos(2, 3))));
+
+    // Aller-retour sur la case : l'objet n'est plus sur la map
+    game.walk(&[MoveLeft, MoveRight]);
+    assert_eq!(game.collected_items().items.len(), 1);
+}
+
+#[test]
+fn defeated_enemy_does_not_respawn_after_map_change() {
+    let mut game = GameHarness::new_game(1);
+    assert!(game.enemy_at(pos(4, 1)));
+
+    game.walk(&[MoveRight, MoveRight, MoveRight]);
+    assert_eq!(game.state(), GameState::Combat);
+    game.win_combat();
+    assert_eq!(game.state(), GameState::Map);
+    assert_eq!(game.player_position(), pos(4, 1));
+    assert!(game.defeated_enemies().enemies.contains(&(0, pos(4, 1))));
+    assert!(!game.enemy_at(pos(4, 1)));
+    // Le combat terminé compte dans le bilan
+    let run_stats = game.app.world.resource::<RunStats>();
+    assert!(run_stats.turns > 0 && run_stats.damage_dealt > 0);
+
+    // Vers la connexion de la map 2 en (13, 5)
+    game.walk(&[MoveRight; 8]);
+    game.walk(&[MoveUp; 4]);
+    game.walk(&[MoveRight]);
+    assert_eq!(game.state(), GameState::Map);
+    assert_eq!(game.current_map_index(), 1);
+    assert_eq!(game.player_position(), pos(1, 5));
+    let second_map = game.app.world.resource::<GameData>().maps[1].name.clone();
+    assert!(
+        game.app
+            .world
+            .resource::<QuestLog>()
+            .visited_maps
+            .contains(&second_map)
+    );
+
+    // Retour sur la map 1, puis sur la case de l'ennemi vaincu
+    game.walk(&[MoveLeft]);
+    assert_eq!(game.current_map_index(), 0);
+    assert_eq!(game.player_position(), pos(12, 5));
+    assert!(!game.enemy_at(pos(4, 1)));
+    assert!(game.enemy_at(pos(8, 3)));
+
+    game.walk(&[MoveDown; 4]);
+    game.walk(&[MoveLeft; 8]);
+    assert_eq!(game.player_position(), pos(4, 1));
+    assert_eq!(game.state(), GameState::Map);
+}
+
+/// Nombre d'événements reçus par un abonné ajouté par le test
+#[derive(Resource, Default)]
+struct Received {
+    combats: usize,
+    defeated: Vec<Position>,
+    items: Vec<Position>,
+    chests: Vec<Position>,
+    xp: u32,
+}
+
+fn listen(
+    mut combat_started: EventReader<CombatStarted>,
+    mut enemy_defeated: EventReader<EnemyDefeated>,
+    mut item_picked_up: EventReader<ItemPickedUp>,
+    mut chest_opened: EventReader<ChestOpened>,
+    mut rewards: EventReader<CombatRewards>,
+    mut received: ResMut<Received>,
+) {
+    received.combats += combat_started.read().count();
+    for event in enemy_defeated.read() {
+        received.defeated.push(event.enemy.position);
+    }
+    for event in item_picked_up.read() {
+        received.items.push(event.position);
+    }
+    for event in chest_opened.read() {
+        received.chests.push(event.position);
+    }
+    for event in rewards.read() {
+        received.xp += event.xp;
+    }
+}
+
+#[test]
+fn gameplay_events_reach_new_subscribers() {
+    let mut game = GameHarness::new_game(1);
+    game.app
+        .init_resource::<Received>()
+        .add_systems(PostUpdate, listen);
+
+    game.walk(&[MoveRight, MoveRight, MoveRight]);
+    game.win_combat();
+    game.walk(&[MoveLeft, MoveLeft, MoveLeft, MoveUp, MoveUp, MoveRight]);
+    game.walk(&[MoveLeft, MoveUp, MoveUp, MoveUp, MoveUp, MoveUp]);
+    assert!(game.collected_items().items.contains(&(0, pos(1, 8))));
+
+    let received = game.app.world.resource::<Received>();
+    assert_eq!(received.combats, 1);
+    assert_eq!(received.defeated, vec![pos(4, 1)]);
+    assert_eq!(received.items, vec![pos(2, 3)]);
+    assert_eq!(received.chests, vec![pos(1, 8)]);
+    let registry = game.app.world.resource::<ContentRegistry>();
+    let xp = registry.enemy(&EnemyType("SmallGoblin".to_string())).xp;
+    assert_eq!(received.xp, xp);
+
+    // Les récompenses sont écrites dans le log par les écouteurs de `progress`
+    let log = &game.app.world.resource::<GameLog>().messages;
+    assert!(log.contains(&format!("+{} XP", xp)), "{:?}", log);
+}
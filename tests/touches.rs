@@ -0,0 +1,30 @@
+//! Touches par défaut des dispositions de clavier
+
+use jeu_projet::controls::{Action, KeyBindings, KeyPreset};
+
+#[test]
+fn presets_never_bind_a_key_to_two_actions_of_the_same_state() {
+    for preset in [KeyPreset::Azerty, KeyPreset::Qwerty] {
+        let bindings = KeyBindings::from_preset(preset);
+        for (index, action) in Action::ALL.iter().enumerate() {
+            for other in &Action::ALL[index + 1..] {
+                if !action.shares_state_with(*other) {
+                    continue;
+                }
+                let shared: Vec<_> = bindings
+                    .keys(*action)
+                    .iter()
+                    .filter(|key| bindings.keys(*other).contains(key))
+                    .collect();
+                assert!(
+                    shared.is_empty(),
+                    "{}: {:?} et {:?} partagent {:?}",
+                    preset.label(),
+                    action,
+                    other,
+                    shared
+                );
+            }
+        }
+    }
+}